@@ -0,0 +1,44 @@
+use calendar_tui::calendar::store::convert_event;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use objc2::rc::Retained;
+use objc2_event_kit::{EKEvent, EKEventStore};
+use objc2_foundation::{NSArray, NSDate, NSString};
+
+/// Build `n` unsaved events; creating them does not require calendar access.
+fn ek_events(store: &EKEventStore, n: usize) -> Retained<NSArray<EKEvent>> {
+    let events: Vec<Retained<EKEvent>> = (0..n)
+        .map(|i| {
+            let event = unsafe { EKEvent::eventWithEventStore(store) };
+            let title = NSString::from_str(&format!("Event {}", i));
+            let start = NSDate::dateWithTimeIntervalSinceReferenceDate(i as f64 * 3600.0);
+            let end = NSDate::dateWithTimeIntervalSinceReferenceDate(i as f64 * 3600.0 + 1800.0);
+            let location = NSString::from_str("Conference Room");
+            unsafe {
+                event.setTitle(Some(&title));
+                event.setStartDate(Some(&start));
+                event.setEndDate(Some(&end));
+                event.setLocation(Some(&location));
+            }
+            event
+        })
+        .collect();
+    NSArray::from_retained_slice(&events)
+}
+
+fn conversion(c: &mut Criterion) {
+    let store = unsafe { EKEventStore::new() };
+    let mut group = c.benchmark_group("convert_event");
+    for n in [100, 1_000] {
+        let array = ek_events(&store, n);
+        group.bench_with_input(BenchmarkId::from_parameter(n), &array, |b, array| {
+            b.iter(|| {
+                let converted: Vec<_> = array.iter().filter_map(|ev| convert_event(&ev)).collect();
+                black_box(converted)
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, conversion);
+criterion_main!(benches);
@@ -0,0 +1,66 @@
+use std::collections::HashSet;
+
+use calendar_tui::calendar::{CalendarEvent, Reminder};
+use calendar_tui::layout::{self, DayItems, DaySection};
+use chrono::{Duration, Local, TimeZone};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// `n` events spread over March 2025, every tenth one all-day.
+fn events(n: usize) -> Vec<CalendarEvent> {
+    let base = Local.with_ymd_and_hms(2025, 3, 1, 8, 0, 0).unwrap();
+    (0..n)
+        .map(|i| {
+            let start = base + Duration::minutes((i as i64 * 97) % (30 * 24 * 60));
+            let end = start + Duration::minutes(45);
+            CalendarEvent::builder(format!("ev-{}", i), format!("Event {}", i), start, end)
+                .all_day(i % 10 == 0)
+                .build()
+        })
+        .collect()
+}
+
+fn reminders(n: usize) -> Vec<Reminder> {
+    (0..n)
+        .map(|i| Reminder::builder(format!("rem-{}", i), format!("Reminder {}", i)).build())
+        .collect()
+}
+
+fn day_list(c: &mut Criterion) {
+    let events = events(40);
+    let reminders = reminders(15);
+    let pinned: HashSet<String> = ["ev-3".to_string(), "ev-20".to_string()].into();
+    let items = DayItems {
+        events: &events,
+        reminders: &reminders,
+        pinned: &pinned,
+        now: None,
+        min_break: None,
+        sections: &DaySection::DEFAULT_ORDER,
+    };
+    let len = layout::day_list_len(&items);
+
+    c.bench_function("day_list_len", |b| b.iter(|| layout::day_list_len(black_box(&items))));
+
+    // Scrolling through every row is what j/k navigation does repeatedly
+    c.bench_function("day_action_at/all_rows", |b| {
+        b.iter(|| {
+            for row in 0..len {
+                black_box(layout::day_action_at(&items, black_box(row)));
+            }
+        })
+    });
+}
+
+fn month_aggregation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("month_event_counts");
+    for n in [1_000, 5_000] {
+        let events = events(n);
+        group.bench_function(n.to_string(), |b| {
+            b.iter(|| layout::month_event_counts(black_box(&events), 2025, 3))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, day_list, month_aggregation);
+criterion_main!(benches);
@@ -0,0 +1,80 @@
+//! Named actions shared by key bindings, the command line and help.
+
+use crate::app::App;
+
+/// A built-in action with its normal-mode key.
+pub struct Action {
+    /// Name accepted on the command line, e.g. `:new-event`.
+    pub name: &'static str,
+    pub key: char,
+    pub description: &'static str,
+    run: fn(&mut App),
+}
+
+impl Action {
+    pub fn run(&self, app: &mut App) {
+        (self.run)(app)
+    }
+}
+
+/// Actions bound to a single key in every view. Keys that depend on the
+/// view or on other state, and motions, are handled where keys are read.
+pub const ACTIONS: &[Action] = &[
+    Action { name: "quit", key: 'q', description: "Quit", run: |app| app.running = false },
+    Action { name: "today", key: 't', description: "Jump to today", run: App::go_to_today },
+    Action { name: "refresh", key: 'r', description: "Refresh this view", run: App::refresh_view },
+    Action { name: "sync", key: 'R', description: "Sync all accounts and reload", run: App::refresh_all },
+    Action { name: "new-event", key: 'n', description: "Create new event", run: App::open_event_form },
+    Action {
+        name: "new-event-soon",
+        key: 'N',
+        description: "New event an hour from now",
+        run: App::open_event_form_in_an_hour,
+    },
+    Action { name: "calendars", key: 'c', description: "Show/hide calendars", run: App::open_calendar_picker },
+    Action { name: "delete", key: 'd', description: "Delete selected event", run: App::delete_selected_event },
+    Action { name: "yank", key: 'y', description: "Yank selected event", run: App::yank_selected_event },
+    Action {
+        name: "paste",
+        key: 'D',
+        description: "Copy the yanked event to this day",
+        run: App::duplicate_yanked_event,
+    },
+    Action { name: "cut", key: 'x', description: "Pick up event to move it", run: App::cut_selected_event },
+    Action { name: "later", key: '=', description: "Nudge event 15m later", run: |app| app.nudge_selected_event(15) },
+    Action {
+        name: "earlier",
+        key: '-',
+        description: "Nudge event 15m earlier",
+        run: |app| app.nudge_selected_event(-15),
+    },
+    Action {
+        name: "hour-later",
+        key: '+',
+        description: "Nudge event an hour later",
+        run: |app| app.nudge_selected_event(60),
+    },
+    Action {
+        name: "hour-earlier",
+        key: '_',
+        description: "Nudge event an hour earlier",
+        run: |app| app.nudge_selected_event(-60),
+    },
+    Action {
+        name: "toggle-reminder",
+        key: ' ',
+        description: "Toggle reminder completion",
+        run: App::toggle_day_reminder,
+    },
+    Action { name: "undo", key: 'u', description: "Undo the last :shift", run: App::undo },
+    Action { name: "help", key: '?', description: "Keybindings", run: |app| app.show_help = true },
+    Action { name: "command", key: ':', description: "Command line", run: App::open_command_line },
+];
+
+pub fn by_key(key: char) -> Option<&'static Action> {
+    ACTIONS.iter().find(|a| a.key == key)
+}
+
+pub fn by_name(name: &str) -> Option<&'static Action> {
+    ACTIONS.iter().find(|a| a.name == name)
+}
@@ -0,0 +1,128 @@
+//! The coming days as text, printed by `calendar-tui agenda` for scripts
+//! and status lines.
+
+use chrono::{DateTime, Duration, Local, NaiveDate, Timelike};
+use color_eyre::Result;
+use serde::Serialize;
+
+use crate::calendar::time::format_time;
+use crate::calendar::{CalendarEvent, Reminder};
+
+/// Most days an agenda covers, a year.
+pub const MAX_DAYS: u32 = 366;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ItemKind {
+    Event,
+    Reminder,
+}
+
+/// One event or due reminder on one day. Events spanning several days get
+/// an item for each.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AgendaItem {
+    pub date: NaiveDate,
+    pub kind: ItemKind,
+    pub title: String,
+    /// "09:00 - 10:00", "All day", or the due time of a reminder.
+    pub time: String,
+    pub start: Option<DateTime<Local>>,
+    pub end: Option<DateTime<Local>>,
+    pub all_day: bool,
+    pub calendar: String,
+    pub location: Option<String>,
+}
+
+/// Items from a start date on, by day and then by time.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Agenda {
+    pub items: Vec<AgendaItem>,
+}
+
+impl Agenda {
+    /// Events occurring and incomplete reminders due in the `days` days
+    /// from `from`, up to [`MAX_DAYS`] and the last representable date.
+    pub fn build(from: NaiveDate, days: u32, events: &[CalendarEvent], reminders: &[Reminder]) -> Self {
+        let mut items = Vec::new();
+        for offset in 0..days.min(MAX_DAYS) {
+            let Some(date) = from.checked_add_signed(Duration::days(offset as i64)) else {
+                break;
+            };
+            let mut on_day: Vec<&CalendarEvent> = events.iter().filter(|e| e.occurs_on(date)).collect();
+            on_day.sort_by_key(|e| (!e.is_all_day, e.start));
+            items.extend(on_day.into_iter().map(|e| AgendaItem {
+                date,
+                kind: ItemKind::Event,
+                title: e.title.clone(),
+                time: e.time_range_on(date),
+                start: Some(e.start),
+                end: Some(e.end),
+                all_day: e.is_all_day,
+                calendar: e.calendar_name.clone(),
+                location: e.location.clone(),
+            }));
+            let mut due: Vec<&Reminder> = reminders
+                .iter()
+                .filter(|r| !r.is_completed && r.due_date.is_some_and(|d| d.date_naive() == date))
+                .collect();
+            due.sort_by_key(|r| r.due_date);
+            items.extend(due.into_iter().map(|r| AgendaItem {
+                date,
+                kind: ItemKind::Reminder,
+                title: r.title.clone(),
+                time: due_time(r),
+                start: r.due_date,
+                end: None,
+                all_day: false,
+                calendar: r.calendar_name.clone(),
+                location: None,
+            }));
+        }
+        Self { items }
+    }
+
+    /// Day headings with the items indented below.
+    pub fn to_text(&self) -> String {
+        let mut out = String::new();
+        let mut day = None;
+        for item in &self.items {
+            if day != Some(item.date) {
+                if day.is_some() {
+                    out.push('\n');
+                }
+                out.push_str(&format!("{}\n", item.date.format("%a %b %-d")));
+                day = Some(item.date);
+            }
+            let title = match item.kind {
+                ItemKind::Event => item.title.clone(),
+                ItemKind::Reminder => format!("[ ] {}", item.title),
+            };
+            out.push_str(&format!("  {:<15} {} \u{00b7} {}\n", item.time, title, item.calendar));
+        }
+        if self.items.is_empty() {
+            out.push_str("Nothing scheduled\n");
+        }
+        out
+    }
+
+    /// One line per item with its date, for `grep`, `head -1` and tmux.
+    pub fn to_plain(&self) -> String {
+        self.items
+            .iter()
+            .map(|item| format!("{} {} {}\n", item.date, item.time, item.title))
+            .collect()
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(&self.items)?)
+    }
+}
+
+/// "Due", or "Due 17:00" when the reminder has a time of day.
+fn due_time(reminder: &Reminder) -> String {
+    match reminder.due_date {
+        Some(due) if due.hour() != 0 || due.minute() != 0 => format!("Due {}", format_time(due)),
+        _ => "Due".to_string(),
+    }
+}
@@ -0,0 +1,47 @@
+//! In-app alarms: events whose start time passes while the app is open,
+//! and snoozed alerts coming due again.
+
+use std::collections::HashSet;
+
+use chrono::{DateTime, Local};
+
+use crate::calendar::CalendarEvent;
+
+/// Start alerts already raised, and snoozed ones waiting to return.
+#[derive(Debug, Clone)]
+pub struct Alerts {
+    /// Identifier and start of every event alerted so far.
+    seen: HashSet<(String, i64)>,
+    snoozed: Vec<(CalendarEvent, DateTime<Local>)>,
+    /// Events starting up to this time have been considered.
+    checked_until: DateTime<Local>,
+}
+
+impl Alerts {
+    /// Start watching from `now`; events that already started are not alerted.
+    pub fn new(now: DateTime<Local>) -> Self {
+        Self { seen: HashSet::new(), snoozed: Vec::new(), checked_until: now }
+    }
+
+    /// Events among `events` that started since the last check, followed by
+    /// snoozed alerts due again. All-day and declined events never alert.
+    pub fn due(&mut self, events: &[CalendarEvent], now: DateTime<Local>) -> Vec<CalendarEvent> {
+        let since = self.checked_until;
+        self.checked_until = now;
+        let mut due: Vec<CalendarEvent> = events
+            .iter()
+            .filter(|ev| !ev.is_all_day && !ev.declined && ev.start > since && ev.start <= now)
+            .filter(|ev| self.seen.insert((ev.id.clone(), ev.start.timestamp())))
+            .cloned()
+            .collect();
+        let (ready, waiting): (Vec<_>, Vec<_>) = self.snoozed.drain(..).partition(|(_, until)| *until <= now);
+        self.snoozed = waiting;
+        due.extend(ready.into_iter().map(|(ev, _)| ev));
+        due
+    }
+
+    /// Raise the alert for `ev` again at `until`.
+    pub fn snooze(&mut self, ev: CalendarEvent, until: DateTime<Local>) {
+        self.snoozed.push((ev, until));
+    }
+}
@@ -1,12 +1,93 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
 
-use chrono::{Datelike, Local, NaiveDate};
-use color_eyre::Result;
+use chrono::{DateTime, Datelike, Local, NaiveDate, NaiveTime};
+use color_eyre::eyre::{eyre, Report, Result};
+use ratatui::buffer::Buffer;
+use serde::{Deserialize, Serialize};
 
-use crate::calendar::{CalendarEvent, CalendarInfo, Reminder, Store};
+use crate::calendar::backend::{day_range, month_range, week_range};
+use crate::calendar::time::{format_time, snap_up};
+use crate::calendar::{
+    ics, Access, Backend, CalendarEvent, CalendarInfo, EventQuery, NewEvent, ParticipantStatus, Permissions, Reminder,
+    Reschedule, Store, TimeFormat, Zone,
+};
+use crate::calendar::prefetch::{Prefetcher, Request};
+use crate::action;
+use crate::agenda::{self, Agenda};
+use crate::alerts::Alerts;
+use crate::availability;
+use crate::cache::MonthCache;
+use crate::clipboard;
+use crate::command::{self, Command, History};
+use crate::components::command_line::CommandLineState;
+use crate::components::error_popup::ErrorState;
 use crate::components::event_form::{EventFormState, FormField};
+use crate::components::event_review::{EventReviewState, ReviewAction};
+use crate::components::week_view::Lane;
+use crate::components::reminder_form::{ReminderField, ReminderFormState};
+use crate::config::{Config, Feedback, Filter, IdleMode, WeekendMode};
+use crate::health::{self, Health};
+use crate::job::{Finish, Job, Step};
+use crate::layout::{self, DayItems};
+use crate::maintenance;
+use crate::natural;
+use crate::opener;
+use crate::shift;
+use crate::snapshot::{self, SnapshotFormat};
+use crate::state::ViewState;
+use crate::theme::{self, Theme};
+use crate::toast::Toasts;
+pub use crate::layout::DayAction;
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+/// Bounds for the month grid width in the month layout.
+const MIN_MONTH_WIDTH: u16 = 24;
+const MAX_MONTH_WIDTH: u16 = 70;
+
+/// Largest count accepted before a motion, e.g. `999j`.
+const MAX_COUNT: u32 = 999;
+
+/// How long the status bar stays inverted for `feedback = "flash"`.
+const FLASH_DURATION: Duration = Duration::from_millis(150);
+
+/// Longest a full refresh shows its spinner while waiting for a sync.
+pub const SYNC_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Time spent on a running job per frame before the screen is redrawn.
+const JOB_SLICE: Duration = Duration::from_millis(50);
+
+/// How often the store is checked for events starting, with start alerts on.
+const ALERT_POLL: Duration = Duration::from_secs(10);
+
+/// Title of the all-day event created by `:focus`.
+const FOCUS_DAY_TITLE: &str = "Focus day";
+
+/// How far back `:cleanup` looks for stale events.
+const CLEANUP_LOOKBACK_DAYS: i64 = 365;
+/// Days on either side of today scanned by `:duplicates`.
+const DUPLICATE_WINDOW_DAYS: i64 = 90;
+/// Shortest free slot offered as office hours.
+const OFFICE_HOURS_MIN_MINUTES: u32 = 60;
+
+/// An all-day busy block proposed by `:focus`, with the meetings it shadows.
+#[derive(Debug, Clone)]
+pub struct FocusDay {
+    pub date: NaiveDate,
+    pub calendar: CalendarInfo,
+    pub conflicts: Vec<CalendarEvent>,
+}
+
+/// A newly created event with attendees, waiting to be exported as an invite.
+#[derive(Debug, Clone)]
+pub struct Invite {
+    pub event: NewEvent,
+    pub attendees: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
 pub enum ViewMode {
     Month,
     Week,
@@ -17,18 +98,12 @@ pub enum ViewMode {
 pub enum InputMode {
     Normal,
     Form,
+    ReminderForm,
+    Command,
     #[allow(dead_code)]
     Reminders,
 }
 
-/// Identifies what kind of item is at a given scroll position in the day view.
-#[derive(Debug, Clone, Copy)]
-pub enum DayAction {
-    None,
-    Event(usize),
-    Reminder(usize),
-}
-
 pub struct App {
     pub running: bool,
     pub view_mode: ViewMode,
@@ -36,84 +111,403 @@ pub struct App {
     pub selected_date: NaiveDate,
     pub today: NaiveDate,
     pub calendars: Vec<CalendarInfo>,
+    pub reminder_calendars: Vec<CalendarInfo>,
+    pub hidden_calendars: HashSet<String>,
+    pub pinned_events: HashSet<String>,
     pub month_events: Vec<CalendarEvent>,
+    /// Recently shown months as fetched, before hidden calendars and
+    /// filters are applied.
+    month_cache: MonthCache<EventQuery>,
+    /// Why the selected month's events may be incomplete.
+    month_gaps: Vec<String>,
+    /// Why the events on screen may be incomplete, shown as a marker in
+    /// the status bar and listed by `:status`.
+    pub data_gaps: Vec<String>,
+    /// Bumped whenever cached months are dropped, to ignore prefetches
+    /// started before.
+    cache_generation: u64,
+    /// Months asked of the prefetcher and not yet answered.
+    prefetching: HashSet<(i32, u32)>,
     pub week_events: Vec<CalendarEvent>,
+    /// Today's events, for the meeting timer in the status bar.
+    pub today_events: Vec<CalendarEvent>,
     pub day_events: Vec<CalendarEvent>,
-    pub days_with_events: HashSet<u32>,
-    pub days_with_reminders: HashSet<u32>,
+    // Events left out by hidden calendars, filters or declined-hiding
+    pub day_hidden_events: usize,
+    pub week_hidden: usize,
+    /// Event count per day of the displayed month.
+    pub day_event_counts: HashMap<u32, usize>,
+    /// Color of the dominant reminder list per day of the displayed month.
+    pub day_reminder_colors: HashMap<u32, ratatui::style::Color>,
+    /// Whether events can be read.
     pub access_granted: bool,
+    /// Access as of the last check; with add-only event access the app
+    /// still runs so new events can be created.
+    pub permissions: Permissions,
     pub day_scroll: usize,
+    // Week view cursor over the selected day's events (index into day_events)
+    pub week_cursor: usize,
+    /// Month grid: the selected date's whole week row is selected (`V`).
+    pub week_row_selected: bool,
+    // Per-view scroll and size settings, persisted across sessions
+    pub view_state: ViewState,
+    /// `view_state` as last loaded or saved, to tell our changes from
+    /// another instance's.
+    view_state_saved: ViewState,
     // Reminders (inline in day view)
     pub reminders: Vec<Reminder>,
     pub completed_reminders: Vec<Reminder>,
+    // Incomplete reminders in hidden lists, or all of them while hidden with R
+    pub hidden_reminders: Vec<Reminder>,
     pub day_reminders: Vec<Reminder>,
     pub day_completed_count: usize,
     pub day_total_reminders: usize,
     // Event form
     pub form_state: Option<EventFormState>,
+    // Reminder edit form
+    pub reminder_form: Option<ReminderFormState>,
     // Detail popup (index into day_events or day_reminders via DayAction)
     pub detail_item: Option<DayAction>,
+    // Recurring reminder awaiting "complete occurrence" vs "delete series"
+    pub recurring_prompt: Option<String>,
+    /// Event copied with `y`, pasted onto the selected date with `D`.
+    pub yanked_event: Option<CalendarEvent>,
+    /// Event picked up with `x`, moved onto the selected date with `p`.
+    pub cut_event: Option<CalendarEvent>,
+    /// Event waiting for delete confirmation.
+    pub delete_prompt: Option<CalendarEvent>,
+    /// Focus day waiting for confirmation from `:focus`.
+    pub focus_prompt: Option<FocusDay>,
+    /// Offer to export an `.ics` invite after creating an event with attendees.
+    pub invite_prompt: Option<Invite>,
+    // Bulk review (cleanup, duplicates, templates)
+    pub event_review: Option<EventReviewState>,
+    // Calendar visibility popup (cursor into calendars)
+    pub calendar_picker: Option<usize>,
+    /// Cursor in the checklist of the selected day's reminders, when open.
+    pub reminder_checklist: Option<usize>,
+    // `:` command line and its persisted history
+    pub command_line: Option<CommandLineState>,
+    pub command_history: History,
+    // Saved filter in use (index into config.filters)
+    pub active_filter: Option<usize>,
+    /// Calendars picked with `--calendar`, applied like a saved filter
+    /// until another filter is chosen.
+    launch_filter: Option<Filter>,
+    // Reminders are shown in the day list and month markers (session only)
+    pub show_reminders: bool,
     // Help overlay
     pub show_help: bool,
-    // Status message
-    pub status_message: Option<String>,
-    store: Store,
+    /// Count typed before a motion, vim style (`5l`, `3]`).
+    pub pending_count: Option<u32>,
+    /// `z` was pressed; the next key picks a display toggle, as in `zT`,
+    /// or a saved filter, as in `z1`.
+    pub pending_z: bool,
+    /// `n` was pressed with new event shortcuts configured; the next key
+    /// picks one, as in `nw`.
+    pub pending_n: bool,
+    /// View shown before the count's first digit switched it, since 1-3
+    /// also pick views.
+    count_view: Option<ViewMode>,
+    /// Transient messages stacked above the status bar.
+    pub toasts: Toasts,
+    /// A failure shown in full, over everything else.
+    pub error: Option<ErrorState>,
+    /// Persistent notice above the status bar, e.g. running without EventKit.
+    pub banner: Option<String>,
+    /// Timezone the calendar is shown in by `:travel`; `None` is local time.
+    pub travel: Option<Zone>,
+    /// When a full refresh started; the status bar shows a spinner until
+    /// the sync reports back or [`SYNC_TIMEOUT`] passes.
+    pub syncing_since: Option<Instant>,
+    /// When the last sync started with `R` finished.
+    pub synced_at: Option<DateTime<Local>>,
+    /// What the last `:shift` did, and the changes that revert it.
+    pub undo: Option<(String, Vec<Reschedule>)>,
+    /// When events were last loaded from the store.
+    pub last_fetch: Option<DateTime<Local>>,
+    /// Permissions and accounts summary, shown on launch and by `:status`.
+    pub health: Option<Health>,
+    /// Bulk deletion, creation or scan in progress.
+    pub job: Option<Job>,
+    /// Save the next rendered frame in this format.
+    pub pending_snapshot: Option<SnapshotFormat>,
+    /// Events that just started, shown one at a time over the whole screen.
+    pub alert_queue: VecDeque<CalendarEvent>,
+    alerts: Alerts,
+    alerts_polled: Instant,
+    /// End of the current status bar flash.
+    pub flash_until: Option<Instant>,
+    /// Whether the terminal has focus. Terminals that don't report focus
+    /// changes leave this on.
+    pub focused: bool,
+    /// Something changed since the last frame; the main loop only redraws
+    /// for changes, animations and the clock.
+    pub redraw: bool,
+    /// Time of the last key press, for idle hiding.
+    pub last_input: Instant,
+    /// Contents are hidden until the next key press.
+    pub idle: bool,
+    pub config: Config,
+    /// Whether settings, view state and history are written back to the
+    /// config directory; off for apps built from given settings.
+    persist: bool,
+    store: Box<dyn Backend>,
+    prefetcher: Option<Prefetcher>,
 }
 
 impl App {
     pub fn new() -> Result<Self> {
-        let store = Store::new()?;
+        Self::connect(&mut |_| {})
+    }
+
+    /// Connect to EventKit and load, calling `progress` with each stage,
+    /// and repeatedly while the permission prompt is up.
+    pub fn connect(progress: &mut dyn FnMut(&str)) -> Result<Self> {
+        progress("Connecting to Apple Calendar");
+        let mut app = Self::unloaded(Box::new(Store::new()?));
+        app.load(progress)?;
+        Ok(app)
+    }
+
+    /// Add a digit to the pending count. A leading 1, 2 or 3 still switches
+    /// view at once; the switch is undone if a motion uses the count.
+    pub fn count_digit(&mut self, digit: u32) {
+        match self.pending_count {
+            Some(n) => self.pending_count = Some((n * 10 + digit).min(MAX_COUNT)),
+            None if digit == 0 => {}
+            None => {
+                self.pending_count = Some(digit);
+                let view = match digit {
+                    1 => Some(ViewMode::Month),
+                    2 => Some(ViewMode::Week),
+                    3 => Some(ViewMode::Day),
+                    _ => None,
+                };
+                if let Some(view) = view {
+                    self.count_view = Some(self.view_mode);
+                    self.view_mode = view;
+                }
+            }
+        }
+    }
+
+    /// Take the pending count (1 if none) for a motion.
+    pub fn take_count(&mut self) -> u32 {
+        if let Some(view) = self.count_view.take() {
+            self.view_mode = view;
+        }
+        self.pending_count.take().unwrap_or(1)
+    }
+
+    /// Drop the pending count; keys other than motions ignore it.
+    pub fn clear_count(&mut self) {
+        self.pending_count = None;
+        self.count_view = None;
+    }
+
+    /// Fall back to the read-only `.ics` calendars in the config directory
+    /// when EventKit cannot be used, e.g. over SSH without a GUI session.
+    pub fn local(reason: &str) -> Result<Self> {
+        let mut app = Self::with_backend(Box::new(ics::local_backend()?))?;
+        let dir = ics::local_dir().map(|d| d.display().to_string()).unwrap_or_default();
+        app.banner = Some(format!("EventKit unavailable ({}) \u{2014} read-only calendars from {}", reason, dir));
+        Ok(app)
+    }
+
+    /// Build the app on top of any [`Backend`], e.g. the in-memory demo data.
+    pub fn with_backend(store: Box<dyn Backend>) -> Result<Self> {
+        let mut app = Self::unloaded(store);
+        app.load(&mut |_| {})?;
+        Ok(app)
+    }
+
+    /// Build the app on top of `store` with the given settings instead of
+    /// the ones in the config directory, which is neither read nor written.
+    pub fn with_backend_and_config(
+        store: Box<dyn Backend>,
+        config: Config,
+        view_state: ViewState,
+        history: History,
+    ) -> Result<Self> {
+        let mut app = Self::unloaded_with(store, config, view_state, history);
+        app.persist = false;
+        app.load(&mut |_| {})?;
+        Ok(app)
+    }
+
+    /// Settings and empty views over `store`; [`load`](Self::load) fetches.
+    fn unloaded(store: Box<dyn Backend>) -> Self {
+        Self::unloaded_with(store, Config::load(), ViewState::load(), History::load())
+    }
+
+    fn unloaded_with(store: Box<dyn Backend>, config: Config, view_state: ViewState, history: History) -> Self {
         let today = Local::now().date_naive();
+        let hidden_calendars = config.hidden_calendars.iter().cloned().collect();
+        let pinned_events = config.pinned_events.iter().cloned().collect();
+        config.time_format.set_current();
+        config.hour_labels.set_current();
+        config.truncation.set_current();
 
         let mut app = Self {
             running: true,
-            view_mode: ViewMode::Month,
+            view_mode: view_state.view.unwrap_or(ViewMode::Month),
             input_mode: InputMode::Normal,
-            selected_date: today,
+            selected_date: view_state.selected_date.unwrap_or(today),
             today,
             calendars: Vec::new(),
+            reminder_calendars: Vec::new(),
+            hidden_calendars,
+            pinned_events,
             month_events: Vec::new(),
+            month_cache: MonthCache::default(),
+            month_gaps: Vec::new(),
+            data_gaps: Vec::new(),
+            cache_generation: 0,
+            prefetching: HashSet::new(),
             week_events: Vec::new(),
+            today_events: Vec::new(),
             day_events: Vec::new(),
-            days_with_events: HashSet::new(),
-            days_with_reminders: HashSet::new(),
+            day_hidden_events: 0,
+            week_hidden: 0,
+            day_event_counts: HashMap::new(),
+            day_reminder_colors: HashMap::new(),
             access_granted: false,
+            permissions: Permissions { events: Access::NotDetermined, reminders: Access::NotDetermined },
             day_scroll: 0,
+            week_cursor: 0,
+            week_row_selected: false,
+            view_state: view_state.clone(),
+            view_state_saved: view_state,
             reminders: Vec::new(),
             completed_reminders: Vec::new(),
+            hidden_reminders: Vec::new(),
             day_reminders: Vec::new(),
             day_completed_count: 0,
             day_total_reminders: 0,
             form_state: None,
+            reminder_form: None,
             detail_item: None,
+            recurring_prompt: None,
+            yanked_event: None,
+            cut_event: None,
+            delete_prompt: None,
+            focus_prompt: None,
+            invite_prompt: None,
+            event_review: None,
+            calendar_picker: None,
+            reminder_checklist: None,
+            command_line: None,
+            command_history: history,
+            active_filter: None,
+            launch_filter: None,
+            show_reminders: true,
             show_help: false,
-            status_message: None,
+            pending_count: None,
+            pending_z: false,
+            pending_n: false,
+            count_view: None,
+            toasts: Toasts::new(),
+            error: None,
+            banner: None,
+            travel: None,
+            syncing_since: None,
+            synced_at: None,
+            undo: None,
+            last_fetch: None,
+            health: None,
+            job: None,
+            pending_snapshot: None,
+            alert_queue: VecDeque::new(),
+            alerts: Alerts::new(Local::now()),
+            alerts_polled: Instant::now(),
+            flash_until: None,
+            focused: true,
+            redraw: true,
+            last_input: Instant::now(),
+            idle: false,
+            config,
+            persist: true,
+            prefetcher: store.prefetcher(),
             store,
         };
+        for warning in std::mem::take(&mut app.config.warnings) {
+            app.toasts.warn(warning);
+        }
+        app
+    }
 
-        app.access_granted = app.store.request_access()?;
-        if app.access_granted {
-            app.calendars = app.store.calendars();
-            app.refresh_events();
+    /// Ask for access and fetch calendars, events and reminders.
+    fn load(&mut self, progress: &mut dyn FnMut(&str)) -> Result<()> {
+        self.access_granted = self.store.request_access_waiting(&mut || progress("Waiting for calendar access"))?;
+        self.permissions = self.store.permissions();
+        if self.access_granted {
+            progress("Loading events");
+            self.load_calendars();
+            self.refresh_events();
         }
+        if self.config.startup_check {
+            self.open_health();
+        }
+        Ok(())
+    }
 
-        Ok(app)
+    fn load_calendars(&mut self) {
+        self.calendars = if self.access_granted { self.store.calendars() } else { Vec::new() };
+        self.reminder_calendars = if self.permissions.reminders.can_read() {
+            self.store.reminder_calendars()
+        } else {
+            Vec::new()
+        };
+    }
+
+    /// Whether the calendar can be shown: events are readable, or can at
+    /// least be added.
+    pub fn usable(&self) -> bool {
+        self.access_granted || self.permissions.events == Access::WriteOnly
+    }
+
+    /// Pick up access granted or revoked in System Settings while running.
+    fn recheck_access(&mut self) {
+        let permissions = self.store.permissions();
+        if permissions == self.permissions {
+            return;
+        }
+        self.permissions = permissions;
+        self.access_granted = permissions.events.can_read();
+        self.load_calendars();
     }
 
+    /// Reload everything the views show, dropping cached months since
+    /// events may have been created, changed or deleted.
     pub fn refresh_events(&mut self) {
+        self.month_cache.clear();
+        self.cache_generation += 1;
+        self.prefetching.clear();
+        self.show_month();
+    }
+
+    /// Load the selected date's month, from the cache when it was shown
+    /// recently, along with its day, week and reminders.
+    fn show_month(&mut self) {
         let year = self.selected_date.year();
         let month = self.selected_date.month();
 
-        self.month_events = self.store.events_for_month(year, month);
-        self.day_events = self.store.events_for_date(self.selected_date);
-        self.week_events = self.store.events_for_week(self.selected_date);
-        self.days_with_events.clear();
-        for ev in &self.month_events {
-            let ev_date = ev.start.date_naive();
-            if ev_date.year() == year && ev_date.month() == month {
-                self.days_with_events.insert(ev_date.day());
+        let query = match self.month_cache.get(year, month) {
+            Some(query) => query,
+            None => {
+                let query = self.query_in(month_range(year, month));
+                if self.access_granted {
+                    self.month_cache.insert(year, month, query.clone());
+                }
+                query
             }
-        }
+        };
+        self.month_gaps = query.gaps;
+        self.month_events = self.visible(query.events);
+        self.refresh_day_and_week_events();
+        self.refresh_today_events();
+        self.day_event_counts = layout::month_event_counts(&self.month_events, year, month);
 
         // Fetch reminders and populate day + month indicators
         self.refresh_reminders();
@@ -122,27 +516,73 @@ impl App {
         // Set scroll to first actionable item after data loads
         self.day_scroll = 0; // temporary, reset after reminders load
 
-        self.days_with_reminders.clear();
-        for rem in &self.reminders {
-            if let Some(due) = &rem.due_date {
-                let due_date = due.date_naive();
-                if due_date.year() == year && due_date.month() == month {
-                    self.days_with_reminders.insert(due_date.day());
-                }
+        self.day_reminder_colors = layout::month_reminder_colors(&self.reminders, year, month);
+
+        self.day_scroll = self.first_actionable_scroll();
+        self.last_fetch = Some(Local::now());
+        self.prefetch_adjacent_months();
+    }
+
+    /// Ask the worker for the months either side of the selected one, so
+    /// `[` and `]` find them cached.
+    fn prefetch_adjacent_months(&mut self) {
+        let Some(prefetcher) = &self.prefetcher else {
+            return;
+        };
+        if !self.access_granted {
+            return;
+        }
+        let (year, month) = (self.selected_date.year(), self.selected_date.month());
+        let prev = if month == 1 { (year - 1, 12) } else { (year, month - 1) };
+        let next = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+        for (year, month) in [prev, next] {
+            if self.month_cache.contains(year, month) || !self.prefetching.insert((year, month)) {
+                continue;
             }
+            let (start, end) = self.store_range(month_range(year, month));
+            prefetcher.request(Request { year, month, start, end, generation: self.cache_generation });
         }
+    }
 
-        self.day_scroll = self.first_actionable_scroll();
+    /// Cache the months the worker has fetched since the last tick.
+    fn receive_prefetched(&mut self) {
+        while let Some(fetched) = self.prefetcher.as_ref().and_then(Prefetcher::try_recv) {
+            let Request { year, month, generation, .. } = fetched.request;
+            if generation != self.cache_generation {
+                continue;
+            }
+            self.prefetching.remove(&(year, month));
+            let events = self.as_displayed(fetched.query.events, month_range(year, month));
+            self.month_cache.insert(year, month, EventQuery { events, gaps: fetched.query.gaps });
+        }
     }
 
     pub fn refresh_reminders(&mut self) {
-        self.reminders = self.store.fetch_incomplete_reminders();
+        if !self.permissions.reminders.can_read() {
+            self.reminders.clear();
+            self.hidden_reminders.clear();
+            self.completed_reminders.clear();
+            return;
+        }
+        let (shown, hidden) = self
+            .store
+            .fetch_incomplete_reminders()
+            .into_iter()
+            .partition(|r| self.show_reminders && !self.hidden_calendars.contains(&r.calendar_id));
+        self.reminders = shown;
+        self.hidden_reminders = hidden;
+        if !self.show_reminders {
+            self.completed_reminders.clear();
+            return;
+        }
         self.reminders.sort_by(|a, b| {
             a.calendar_name
                 .cmp(&b.calendar_name)
                 .then(a.due_date.cmp(&b.due_date))
         });
         self.completed_reminders = self.store.fetch_completed_reminders();
+        self.completed_reminders
+            .retain(|r| !self.hidden_calendars.contains(&r.calendar_id));
     }
 
     pub fn week_start(&self) -> NaiveDate {
@@ -150,23 +590,273 @@ impl App {
         self.selected_date - chrono::Duration::days(days_since_sunday as i64)
     }
 
+    /// Event and reminder counts for the week containing the selected date.
+    pub fn week_summary(&self) -> (usize, usize) {
+        let week_start = self.week_start();
+        let week_end = week_start + chrono::Duration::days(7);
+        let in_week = |d: NaiveDate| d >= week_start && d < week_end;
+
+        let events = self.week_events.len();
+        let reminders = self
+            .reminders
+            .iter()
+            .chain(self.completed_reminders.iter())
+            .filter(|r| r.due_date.is_some_and(|due| in_week(due.date_naive())))
+            .count();
+        (events, reminders)
+    }
+
     // ── Navigation ──
 
     pub fn next_day(&mut self) {
         self.selected_date = self.selected_date.succ_opt().unwrap_or(self.selected_date);
+        while self.skips_weekend() && is_weekend(self.selected_date) {
+            self.selected_date += chrono::Duration::days(1);
+        }
         self.on_date_changed();
     }
 
     pub fn prev_day(&mut self) {
         self.selected_date = self.selected_date.pred_opt().unwrap_or(self.selected_date);
+        while self.skips_weekend() && is_weekend(self.selected_date) {
+            self.selected_date -= chrono::Duration::days(1);
+        }
         self.on_date_changed();
     }
 
+    /// In work-week mode the week view has no weekend columns to select.
+    fn skips_weekend(&self) -> bool {
+        self.view_mode == ViewMode::Week && self.config.weekend_mode() == WeekendMode::Hidden
+    }
+
+    // ── Week view selection ──
+
+    /// Event the user is acting on (index into `day_events`): the week view
+    /// cursor in week view, otherwise the day list selection.
+    pub fn selected_event_index(&self) -> Option<usize> {
+        if self.view_mode == ViewMode::Week {
+            return (self.week_cursor < self.day_events.len()).then_some(self.week_cursor);
+        }
+        match self.day_action_at_scroll() {
+            DayAction::Event(idx) => Some(idx),
+            _ => None,
+        }
+    }
+
+    pub fn week_cursor_down(&mut self) {
+        if self.week_cursor + 1 < self.day_events.len() {
+            self.week_cursor += 1;
+        }
+    }
+
+    pub fn week_cursor_up(&mut self) {
+        self.week_cursor = self.week_cursor.saturating_sub(1);
+    }
+
+    /// Hours shown by the current view's time grid: the configured range
+    /// shifted by that view's scroll offset, kept inside the day.
+    pub fn grid_hours(&self) -> (u32, u32) {
+        let (start, end) = self.config.week_hours();
+        let span = (end - start) as i32;
+        let offset = match self.view_mode {
+            ViewMode::Day => self.view_state.day_hour_offset,
+            _ => self.view_state.week_hour_offset,
+        };
+        let start = (start as i32 + offset).clamp(0, 24 - span);
+        (start as u32, (start + span) as u32)
+    }
+
+    /// Scroll the current view's hour window by `delta` hours.
+    pub fn scroll_hours(&mut self, delta: i32) {
+        let (start, end) = self.config.week_hours();
+        let min = -(start as i32);
+        let max = 24 - end as i32;
+        let offset = match self.view_mode {
+            ViewMode::Day => &mut self.view_state.day_hour_offset,
+            _ => &mut self.view_state.week_hour_offset,
+        };
+        *offset = (*offset + delta).clamp(min, max);
+        let (start, end) = self.grid_hours();
+        self.toasts.info(format!(
+            "Hours {}-{}",
+            TimeFormat::current().hour_label(start, false),
+            TimeFormat::current().hour_label(end, false)
+        ));
+        self.save_view_state();
+    }
+
+    /// Scroll the time grid so it starts at `hour`, switching to week view
+    /// from views without one, and put the week cursor on the selected
+    /// day's first event still running then.
+    pub fn jump_to_hour(&mut self, hour: u32) {
+        if !self.shows_hour_grid() {
+            self.view_mode = ViewMode::Week;
+        }
+        let (start, end) = self.config.week_hours();
+        let current = self.grid_hours().0 as i32;
+        self.scroll_hours(hour.min(24 - (end - start)) as i32 - current);
+        let Some(at) = self.selected_date.and_hms_opt(hour, 0, 0) else {
+            return;
+        };
+        if let Some(idx) = self
+            .day_events
+            .iter()
+            .position(|e| !e.is_all_day && e.end.naive_local() > at)
+        {
+            self.week_cursor = idx;
+        }
+    }
+
+    /// Widen (positive) or narrow the month grid beside the day list.
+    pub fn resize_month_pane(&mut self, delta: i16, total_width: u16) {
+        let current = self.month_pane_width(total_width) as i16;
+        let width = (current + delta).clamp(MIN_MONTH_WIDTH as i16, MAX_MONTH_WIDTH as i16) as u16;
+        self.view_state.month_width = Some(width);
+        self.save_view_state();
+    }
+
+    /// Width of the month grid when shown beside the day list, leaving the
+    /// list at least 20 columns.
+    pub fn month_pane_width(&self, total_width: u16) -> u16 {
+        let default = if total_width >= 100 { 44 } else { 30 };
+        self.view_state
+            .month_width
+            .unwrap_or(default)
+            .min(total_width.saturating_sub(20))
+            .max(MIN_MONTH_WIDTH)
+    }
+
+    /// Events and due reminders in the `days` days from `from`, with
+    /// hidden calendars and filters applied as on screen.
+    pub fn agenda(&self, from: NaiveDate, days: u32) -> Agenda {
+        let span = chrono::Duration::days(days.clamp(1, agenda::MAX_DAYS) as i64 - 1);
+        let last = from.checked_add_signed(span).unwrap_or(NaiveDate::MAX);
+        let events = self.visible(self.events_in((day_range(from).0, day_range(last).1)));
+        Agenda::build(from, days, &events, &self.reminders)
+    }
+
+    /// Save `new` in the calendar named `calendar`, or the default one, as
+    /// `calendar-tui add` does.
+    pub fn add_event(&self, mut new: NewEvent, calendar: Option<&str>) -> Result<()> {
+        if !self.access_granted {
+            return Err(eyre!("No access to calendars"));
+        }
+        if let Some(name) = calendar {
+            new.calendar_id = Some(self.calendar_by_name(name).ok_or_else(|| eyre!("No calendar named {}", name))?);
+        }
+        self.create_event(&new)
+    }
+
+    /// Add a reminder to the list named `list`, or the default one, as
+    /// `calendar-tui reminders add` does. Returns its id.
+    pub fn add_reminder(&self, title: &str, due: Option<NaiveDate>, list: Option<&str>) -> Result<String> {
+        if !self.permissions.reminders.can_read() {
+            return Err(eyre!("No access to reminders"));
+        }
+        let list_id = match list {
+            Some(name) => Some(
+                self.reminder_calendars
+                    .iter()
+                    .find(|c| c.title.eq_ignore_ascii_case(name))
+                    .map(|c| c.id.clone())
+                    .ok_or_else(|| eyre!("No reminder list named {}", name))?,
+            ),
+            None => None,
+        };
+        self.store.create_reminder(title, due, list_id.as_deref())
+    }
+
+    /// Complete the incomplete reminder `id`; recurring ones move on to
+    /// their next due date, which is returned.
+    pub fn complete_reminder(&self, id: &str) -> Result<Option<DateTime<Local>>> {
+        let reminder = self
+            .reminders
+            .iter()
+            .chain(&self.hidden_reminders)
+            .find(|r| r.id == id)
+            .ok_or_else(|| eyre!("No incomplete reminder with id {}", id))?;
+        if reminder.is_recurring {
+            self.store.complete_occurrence(id)
+        } else {
+            self.store.toggle_reminder(id).map(|_| None)
+        }
+    }
+
+    /// Remember the view and selected date for the next launch.
+    pub fn save_session(&mut self) -> Result<()> {
+        self.view_state.view = Some(self.count_view.unwrap_or(self.view_mode));
+        self.view_state.selected_date = Some(self.selected_date);
+        if self.persist {
+            self.view_state.save(&self.view_state_saved)?;
+        }
+        Ok(())
+    }
+
+    fn save_view_state(&mut self) {
+        if !self.persist {
+            return;
+        }
+        match self.view_state.save(&self.view_state_saved) {
+            Ok(merged) => {
+                self.view_state = merged.clone();
+                self.view_state_saved = merged;
+            }
+            Err(e) => self.toasts.error(format!("Error saving view state: {}", e)),
+        }
+    }
+
+    /// Write changed settings to `config.toml`.
+    fn save_config(&mut self) {
+        if !self.persist {
+            return;
+        }
+        if let Err(e) = self.config.save() {
+            self.fail("Saving settings", e);
+        }
+    }
+
     pub fn next_week(&mut self) {
         self.selected_date += chrono::Duration::weeks(1);
         self.on_date_changed();
     }
 
+    pub fn toggle_week_row(&mut self) {
+        self.week_row_selected = !self.week_row_selected;
+    }
+
+    /// Show the selected week row in week view.
+    pub fn open_selected_week(&mut self) {
+        self.week_row_selected = false;
+        self.view_mode = ViewMode::Week;
+    }
+
+    /// Write the selected week's events to an `.ics` file and copy its path.
+    pub fn export_selected_week(&mut self) {
+        self.week_row_selected = false;
+        match self.write_week_export() {
+            Ok((path, count)) => {
+                let path = path.display().to_string();
+                let plural = if count == 1 { "" } else { "s" };
+                self.toasts.success(match clipboard::write_text(&path) {
+                    Ok(()) => format!("Exported {} event{} to {} (path copied)", count, plural, path),
+                    Err(_) => format!("Exported {} event{} to {}", count, plural, path),
+                });
+            }
+            Err(e) => self.fail("Exporting week", e),
+        }
+    }
+
+    fn write_week_export(&self) -> Result<(PathBuf, usize)> {
+        let dir = ics::exports_dir().ok_or_else(|| eyre!("No home directory"))?;
+        std::fs::create_dir_all(&dir)?;
+        // Real times, not the ones shifted for travel mode
+        let (start, end) = week_range(self.selected_date);
+        let events = self.visible(self.store.events_in_range(start, end));
+        let path = dir.join(format!("week-{}.ics", self.week_start().format("%Y-%m-%d")));
+        std::fs::write(&path, ics::export(&events, chrono::Utc::now()))?;
+        Ok((path, events.len()))
+    }
+
     pub fn prev_week(&mut self) {
         self.selected_date -= chrono::Duration::weeks(1);
         self.on_date_changed();
@@ -190,6 +880,11 @@ impl App {
         self.on_date_changed();
     }
 
+    pub fn go_to_date(&mut self, date: NaiveDate) {
+        self.selected_date = date;
+        self.on_date_changed();
+    }
+
     pub fn go_to_today(&mut self) {
         self.today = Local::now().date_naive();
         self.selected_date = self.today;
@@ -244,6 +939,17 @@ impl App {
         0
     }
 
+    /// Events and reminders of the selected day that are not shown.
+    pub fn day_hidden(&self) -> usize {
+        let date = self.selected_date;
+        let reminders = self
+            .hidden_reminders
+            .iter()
+            .filter(|r| r.due_date.is_some_and(|due| due.date_naive() == date))
+            .count();
+        self.day_hidden_events + reminders
+    }
+
     // ── Reminders (inline in day view) ──
 
     /// Filter reminders for the selected date and compute progress counts.
@@ -272,197 +978,837 @@ impl App {
         self.day_total_reminders = self.day_reminders.len() + self.day_completed_count;
     }
 
-    /// Total number of visual items in the day list (headers + items + spacers).
-    pub fn day_list_len(&self) -> usize {
-        let all_day = self.day_events.iter().filter(|e| e.is_all_day).count();
-        let timed = self.day_events.iter().filter(|e| !e.is_all_day).count();
-        let rems = self.day_reminders.len();
+    /// The current time when the selected day is today, for the day list's
+    /// "now" marker.
+    pub fn day_now(&self) -> Option<DateTime<Local>> {
+        let now = Local::now();
+        (self.selected_date == now.date_naive()).then_some(now)
+    }
 
-        let mut len = 0;
-        if all_day > 0 {
-            len += 1 + all_day; // header + items
-            if rems > 0 || timed > 0 {
-                len += 1; // spacer
+    /// Keep `today` current when the app stays open past midnight.
+    pub fn tick(&mut self) {
+        let today = Local::now().date_naive();
+        if today != self.today {
+            self.today = today;
+            self.refresh_today_events();
+            self.redraw = true;
+        }
+        if self.job.is_some() {
+            self.run_job();
+            self.redraw = true;
+        }
+        self.receive_prefetched();
+        if self.config.start_alerts && self.alerts_polled.elapsed() >= ALERT_POLL {
+            self.poll_alerts();
+        }
+        if self.flash_until.is_some_and(|until| Instant::now() >= until) {
+            self.flash_until = None;
+            self.redraw = true;
+        }
+        self.redraw |= self.toasts.expire(Instant::now());
+        let idle_after = Duration::from_secs(self.config.idle_minutes as u64 * 60);
+        // Accounts with nothing new never report back
+        if self.syncing_since.is_some_and(|since| since.elapsed() >= SYNC_TIMEOUT) {
+            self.finish_sync();
+            self.redraw = true;
+        }
+        // Another app or instance wrote to the calendar database, or a sync
+        // finished; picked up on focus instead while in the background
+        if self.focused && self.store.take_changes() {
+            if self.syncing_since.is_some() {
+                self.finish_sync();
             }
+            self.recheck_access();
+            self.reload();
+            if self.idle && self.config.idle_mode == IdleMode::Busy {
+                self.mask_contents();
+            }
+            self.redraw = true;
         }
-        if rems > 0 {
-            len += 1 + rems; // header + items
-            if timed > 0 {
-                len += 1; // spacer
+        if !self.idle && self.config.idle_minutes > 0 && self.last_input.elapsed() >= idle_after {
+            self.idle = true;
+            self.redraw = true;
+            if self.config.idle_mode == IdleMode::Busy {
+                self.mask_contents();
             }
         }
-        len += timed;
-        len
     }
 
-    /// Determine what kind of item is at the current scroll position.
-    pub fn day_action_at_scroll(&self) -> DayAction {
-        self.day_action_at(self.day_scroll)
+    /// Whether something on screen moves on its own and needs frequent
+    /// redraws: the sync spinner, a job's progress bar or a flash.
+    pub fn animating(&self) -> bool {
+        self.job.is_some() || self.syncing_since.is_some() || self.flash_until.is_some()
     }
 
-    /// Determine what kind of item is at the given position.
-    pub fn day_action_at(&self, scroll: usize) -> DayAction {
-        let all_day_indices: Vec<usize> = self
-            .day_events
-            .iter()
-            .enumerate()
-            .filter(|(_, e)| e.is_all_day)
-            .map(|(i, _)| i)
-            .collect();
-        let timed_indices: Vec<usize> = self
-            .day_events
-            .iter()
-            .enumerate()
-            .filter(|(_, e)| !e.is_all_day)
-            .map(|(i, _)| i)
-            .collect();
-        let rems = self.day_reminders.len();
-
-        let mut pos = 0;
-
-        // All-day section
-        if !all_day_indices.is_empty() {
-            if scroll == pos {
-                return DayAction::None;
-            }
-            pos += 1; // header
-            for &idx in &all_day_indices {
-                if scroll == pos {
-                    return DayAction::Event(idx);
-                }
-                pos += 1;
-            }
-            if rems > 0 || !timed_indices.is_empty() {
-                if scroll == pos {
-                    return DayAction::None;
-                }
-                pos += 1; // spacer
-            }
+    /// Record a key press. Returns true when it woke the app from idle, in
+    /// which case the key should not be acted on.
+    pub fn on_input(&mut self) -> bool {
+        self.last_input = Instant::now();
+        if !self.idle {
+            return false;
         }
-
-        // Reminders section
-        if rems > 0 {
-            if scroll == pos {
-                return DayAction::None;
-            }
-            pos += 1; // header
-            for i in 0..rems {
-                if scroll == pos {
-                    return DayAction::Reminder(i);
-                }
-                pos += 1;
-            }
-            if !timed_indices.is_empty() {
-                if scroll == pos {
-                    return DayAction::None;
-                }
-                pos += 1; // spacer
-            }
+        self.idle = false;
+        if self.config.idle_mode == IdleMode::Busy {
+            // Bring back the real titles
+            self.reload();
         }
+        true
+    }
 
-        // Timed events
-        for &idx in &timed_indices {
-            if scroll == pos {
-                return DayAction::Event(idx);
+    /// Follow terminal focus. Store changes wait while the terminal is in
+    /// the background, and regaining focus reloads straight away.
+    pub fn set_focused(&mut self, focused: bool) {
+        if focused && !self.focused {
+            self.store.take_changes();
+            self.recheck_access();
+            self.reload();
+            if self.idle && self.config.idle_mode == IdleMode::Busy {
+                self.mask_contents();
             }
-            pos += 1;
         }
+        self.focused = focused;
+    }
 
-        DayAction::None
+    /// Reload everything from the store, keeping the cursor where it was.
+    fn reload(&mut self) {
+        let (scroll, cursor) = (self.day_scroll, self.week_cursor);
+        self.refresh_events();
+        self.day_scroll = scroll.min(self.day_list_len().saturating_sub(1));
+        self.week_cursor = cursor;
     }
 
-    /// Toggle the reminder at the current scroll position (if it is a reminder).
-    pub fn toggle_day_reminder(&mut self) {
-        if let DayAction::Reminder(rem_idx) = self.day_action_at_scroll() {
-            if let Some(reminder) = self.day_reminders.get(rem_idx) {
-                let id = reminder.id.clone();
-                match self.store.toggle_reminder(&id) {
-                    Ok(new_state) => {
-                        let action = if new_state { "completed" } else { "uncompleted" };
-                        self.status_message = Some(format!("Reminder {}", action));
-                        self.refresh_reminders();
-                        self.update_day_reminders();
-                    }
-                    Err(e) => {
-                        self.status_message = Some(format!("Error: {}", e));
-                    }
-                }
-            }
-        }
+    /// Reload what the current view shows: events around the selected date
+    /// and reminders.
+    pub fn refresh_view(&mut self) {
+        self.reload();
+        self.toasts.info("Refreshed");
     }
 
-    // ── Detail popup ──
+    /// Reload calendars and lists too, and ask every account and
+    /// subscription to sync; the view reloads again once they report back.
+    pub fn refresh_all(&mut self) {
+        self.store.refresh_sources();
+        self.load_calendars();
+        self.reload();
+        self.syncing_since = Some(Instant::now());
+        self.toasts.info("Syncing calendars\u{2026}");
+    }
 
-    pub fn show_detail(&mut self) {
-        let action = self.day_action_at_scroll();
-        match action {
-            DayAction::Event(_) | DayAction::Reminder(_) => {
-                self.detail_item = Some(action);
-            }
-            DayAction::None => {}
-        }
+    fn finish_sync(&mut self) {
+        self.syncing_since = None;
+        let now = Local::now();
+        self.synced_at = Some(now);
+        self.toasts.success(format!("Synced at {}", format_time(now)));
     }
 
-    pub fn close_detail(&mut self) {
+    /// Replace loaded titles and details with "Busy" while idle.
+    fn mask_contents(&mut self) {
+        let events = self.month_events.iter_mut().chain(&mut self.week_events).chain(&mut self.day_events);
+        for ev in events {
+            ev.title = "Busy".to_string();
+            ev.location = None;
+            ev.notes = None;
+        }
+        let reminders = self
+            .reminders
+            .iter_mut()
+            .chain(&mut self.completed_reminders)
+            .chain(&mut self.day_reminders);
+        for rem in reminders {
+            rem.title = "Busy".to_string();
+        }
         self.detail_item = None;
     }
 
-    // ── Event form ──
+    /// Whether the status bar is mid-flash.
+    pub fn flashing(&self) -> bool {
+        self.flash_until.is_some()
+    }
 
-    pub fn open_event_form(&mut self) {
-        self.form_state = Some(EventFormState::new(self.selected_date));
-        self.input_mode = InputMode::Form;
+    /// Show `err` in full in the error popup. `doing` names the action
+    /// that failed, e.g. "Deleting event".
+    fn fail(&mut self, doing: &str, err: Report) {
+        self.error = Some(ErrorState::new(doing, &err));
     }
 
-    pub fn close_event_form(&mut self) {
-        self.form_state = None;
-        self.input_mode = InputMode::Normal;
+    pub fn close_error(&mut self) {
+        self.error = None;
     }
 
-    pub fn submit_event_form(&mut self) {
-        let form = match &self.form_state {
-            Some(f) if f.is_valid() => f.clone(),
-            Some(_) => {
-                self.status_message = Some("Invalid form data".to_string());
-                return;
-            }
-            None => return,
+    /// Copy the text of the error popup, for a bug report.
+    pub fn copy_error(&mut self) {
+        let Some(text) = self.error.as_ref().map(|e| e.text()) else {
+            return;
         };
+        match clipboard::write_text(&text) {
+            Ok(()) => self.toasts.info("Error copied"),
+            Err(e) => self.toasts.error(format!("Error: {}", e)),
+        }
+    }
 
-        let date = form.parsed_date().unwrap();
-        let start_time = form.parsed_start_time().unwrap_or(chrono::NaiveTime::from_hms_opt(9, 0, 0).unwrap());
-        let end_time = form.parsed_end_time().unwrap_or(chrono::NaiveTime::from_hms_opt(10, 0, 0).unwrap());
-        let cal_id = self.calendars.get(form.calendar_index).map(|c| c.id.as_str());
-
-        match self.store.create_event(
-            &form.title,
-            date,
-            start_time,
-            end_time,
-            form.is_all_day,
-            cal_id,
-        ) {
-            Ok(()) => {
-                self.status_message = Some(format!("Created: {}", form.title));
-                self.close_event_form();
-                self.refresh_events();
-            }
-            Err(e) => {
-                self.status_message = Some(format!("Error: {}", e));
+    /// Give the configured cue after completing or saving something.
+    fn feedback(&mut self) {
+        match self.config.feedback {
+            Feedback::Off => {}
+            Feedback::Bell => {
+                let mut out = std::io::stdout();
+                let _ = out.write_all(b"\x07").and_then(|_| out.flush());
             }
+            Feedback::Flash => self.flash_until = Some(Instant::now() + FLASH_DURATION),
         }
     }
 
-    pub fn form_tab(&mut self) {
-        if let Some(ref mut form) = self.form_state {
-            form.active_field = form.active_field.next();
+    /// What the day list of the selected date is built from.
+    pub fn day_items(&self) -> DayItems<'_> {
+        DayItems {
+            events: &self.day_events,
+            reminders: &self.day_reminders,
+            pinned: &self.pinned_events,
+            now: self.day_now(),
+            min_break: self.config.min_break(),
+            sections: &self.config.day_sections,
         }
     }
 
-    pub fn form_backtab(&mut self) {
-        if let Some(ref mut form) = self.form_state {
-            form.active_field = form.active_field.prev();
-        }
+    /// Total number of visual items in the day list (headers + items + spacers).
+    pub fn day_list_len(&self) -> usize {
+        layout::day_list_len(&self.day_items())
+    }
+
+    /// Determine what kind of item is at the current scroll position.
+    pub fn day_action_at_scroll(&self) -> DayAction {
+        self.day_action_at(self.day_scroll)
+    }
+
+    /// Determine what kind of item is at the given position.
+    pub fn day_action_at(&self, scroll: usize) -> DayAction {
+        layout::day_action_at(&self.day_items(), scroll)
+    }
+
+    /// Toggle the reminder at the current scroll position (if it is a reminder).
+    pub fn toggle_day_reminder(&mut self) {
+        if let DayAction::Reminder(rem_idx) = self.day_action_at_scroll() {
+            if let Some(reminder) = self.day_reminders.get(rem_idx).cloned() {
+                self.toggle_reminder(&reminder);
+            }
+        }
+    }
+
+    fn toggle_reminder(&mut self, reminder: &Reminder) {
+        let id = reminder.id.clone();
+        // Completing a recurring reminder needs a decision first
+        if reminder.is_recurring && !reminder.is_completed {
+            self.recurring_prompt = Some(id);
+            return;
+        }
+        match self.store.toggle_reminder(&id) {
+            Ok(new_state) => {
+                let action = if new_state { "completed" } else { "uncompleted" };
+                self.toasts.success(format!("Reminder {}", action));
+                if new_state {
+                    self.feedback();
+                }
+                self.refresh_reminders();
+                self.update_day_reminders();
+            }
+            Err(e) => self.fail("Updating reminder", e),
+        }
+    }
+
+    // ── Reminder checklist ──
+
+    /// Whether the selected day has reminders but no events, so Space on
+    /// the month grid opens the checklist.
+    pub fn day_has_only_reminders(&self) -> bool {
+        self.day_events.is_empty() && self.day_total_reminders > 0
+    }
+
+    /// The selected day's reminders, open ones first, then completed ones.
+    pub fn checklist_reminders(&self) -> Vec<&Reminder> {
+        let date = self.selected_date;
+        let completed = self
+            .completed_reminders
+            .iter()
+            .filter(|r| r.due_date.is_some_and(|due| due.date_naive() == date));
+        self.day_reminders.iter().chain(completed).collect()
+    }
+
+    pub fn open_reminder_checklist(&mut self) {
+        self.reminder_checklist = Some(0);
+    }
+
+    pub fn close_reminder_checklist(&mut self) {
+        self.reminder_checklist = None;
+    }
+
+    pub fn reminder_checklist_down(&mut self) {
+        let len = self.checklist_reminders().len();
+        if let Some(ref mut idx) = self.reminder_checklist {
+            if *idx + 1 < len {
+                *idx += 1;
+            }
+        }
+    }
+
+    pub fn reminder_checklist_up(&mut self) {
+        if let Some(ref mut idx) = self.reminder_checklist {
+            *idx = idx.saturating_sub(1);
+        }
+    }
+
+    /// Toggle the reminder under the checklist cursor. Completed ones move
+    /// to the end, leaving the cursor on the next open one.
+    pub fn toggle_checklist_reminder(&mut self) {
+        let Some(idx) = self.reminder_checklist else {
+            return;
+        };
+        let Some(reminder) = self.checklist_reminders().get(idx).map(|r| (*r).clone()) else {
+            return;
+        };
+        self.toggle_reminder(&reminder);
+        let len = self.checklist_reminders().len();
+        self.reminder_checklist = Some(idx.min(len.saturating_sub(1)));
+    }
+
+    /// Complete the current occurrence of the reminder in the recurring prompt.
+    pub fn complete_recurring_occurrence(&mut self) {
+        let Some(id) = self.recurring_prompt.take() else {
+            return;
+        };
+        match self.store.complete_occurrence(&id) {
+            Ok(Some(next)) => {
+                self.toasts.success(format!("Completed, next due {}", next.format("%a %b %d")));
+                self.feedback();
+            }
+            Ok(None) => {
+                self.toasts.success("Reminder completed");
+                self.feedback();
+            }
+            Err(e) => self.fail("Completing reminder", e),
+        }
+        self.refresh_reminders();
+        self.update_day_reminders();
+    }
+
+    /// Delete the whole series of the reminder in the recurring prompt.
+    pub fn delete_recurring_series(&mut self) {
+        let Some(id) = self.recurring_prompt.take() else {
+            return;
+        };
+        match self.store.delete_reminder(&id) {
+            Ok(()) => self.toasts.success("Deleted recurring reminder"),
+            Err(e) => self.fail("Deleting reminder", e),
+        }
+        self.refresh_reminders();
+        self.update_day_reminders();
+    }
+
+    pub fn close_recurring_prompt(&mut self) {
+        self.recurring_prompt = None;
+    }
+
+    // ── Detail popup ──
+
+    pub fn show_detail(&mut self) {
+        if self.view_mode == ViewMode::Week {
+            self.detail_item = self.selected_event_index().map(DayAction::Event);
+            return;
+        }
+        let action = self.day_action_at_scroll();
+        match action {
+            DayAction::Event(_) | DayAction::Reminder(_) => {
+                self.detail_item = Some(action);
+            }
+            DayAction::None => {}
+        }
+    }
+
+    pub fn close_detail(&mut self) {
+        self.detail_item = None;
+    }
+
+    // ── Status panel ──
+
+    pub fn open_health(&mut self) {
+        self.health = Some(Health::check(
+            self.store.permissions(),
+            &self.calendars,
+            &self.reminder_calendars,
+            &self.hidden_calendars,
+            self.last_fetch,
+        ));
+        if let Some(health) = &mut self.health {
+            health.gaps = self.data_gaps.clone();
+        }
+    }
+
+    pub fn close_health(&mut self) {
+        self.health = None;
+    }
+
+    // ── Calendar visibility ──
+
+    pub fn open_calendar_picker(&mut self) {
+        self.calendar_picker = Some(0);
+    }
+
+    pub fn close_calendar_picker(&mut self) {
+        self.calendar_picker = None;
+    }
+
+    pub fn calendar_picker_down(&mut self) {
+        if let Some(ref mut idx) = self.calendar_picker {
+            if *idx + 1 < self.calendars.len() {
+                *idx += 1;
+            }
+        }
+    }
+
+    pub fn calendar_picker_up(&mut self) {
+        if let Some(ref mut idx) = self.calendar_picker {
+            *idx = idx.saturating_sub(1);
+        }
+    }
+
+    /// Toggle visibility of the calendar under the picker cursor and persist it.
+    pub fn toggle_calendar_visibility(&mut self) {
+        let Some(cal) = self.calendar_picker.and_then(|i| self.calendars.get(i)) else {
+            return;
+        };
+        let id = cal.id.clone();
+        let hidden = !self.hidden_calendars.contains(&id);
+        self.set_calendar_hidden(&id, hidden);
+    }
+
+    /// Cycle week view weekend columns full → narrow → hidden and persist it.
+    pub fn cycle_weekend_mode(&mut self) {
+        self.config.weekend = self.config.weekend_mode().next();
+        self.config.work_week = false;
+        self.toasts.info(format!("Weekend columns: {}", self.config.weekend.label()));
+        self.save_config();
+    }
+
+    /// Toggle per-account lanes in week view and persist it.
+    pub fn toggle_week_lanes(&mut self) {
+        self.config.week_lanes = !self.config.week_lanes;
+        let state = if self.config.week_lanes { "by account" } else { "merged" };
+        self.toasts.info(format!("Week view: {}", state));
+        self.save_config();
+    }
+
+    /// Hide or show reminders everywhere, for when only meetings matter.
+    pub fn toggle_reminders(&mut self) {
+        self.show_reminders = !self.show_reminders;
+        self.refresh_events();
+        let state = if self.show_reminders { "shown" } else { "hidden" };
+        self.toasts.info(format!("Reminders {}", state));
+    }
+
+    pub fn toggle_day_timeline(&mut self) {
+        self.config.day_timeline = !self.config.day_timeline;
+        let state = if self.config.day_timeline { "timeline" } else { "list" };
+        self.toasts.info(format!("Day view: {}", state));
+        self.save_config();
+    }
+
+    /// Whether the current view has an hour grid that Ctrl-Up/Down scroll.
+    pub fn shows_hour_grid(&self) -> bool {
+        match self.view_mode {
+            ViewMode::Week => true,
+            ViewMode::Day => self.config.day_timeline,
+            ViewMode::Month => false,
+        }
+    }
+
+    /// Week view lanes, one per calendar source (account), or none when lanes
+    /// are off or there is only one account.
+    pub fn week_lanes(&self) -> Vec<Lane> {
+        if !self.config.week_lanes {
+            return Vec::new();
+        }
+        let mut lanes: Vec<Lane> = Vec::new();
+        for cal in self.calendars.iter().filter(|c| !self.hidden_calendars.contains(&c.id)) {
+            let name = if cal.source.is_empty() { "Other" } else { cal.source.as_str() };
+            match lanes.iter_mut().find(|l| l.name == name) {
+                Some(lane) => {
+                    lane.calendar_ids.insert(cal.id.clone());
+                }
+                None => lanes.push(Lane {
+                    name: name.to_string(),
+                    calendar_ids: HashSet::from([cal.id.clone()]),
+                }),
+            }
+        }
+        if lanes.len() < 2 {
+            lanes.clear();
+        }
+        lanes
+    }
+
+    /// Hide or show a calendar by id, persisting the choice to the config file.
+    pub fn set_calendar_hidden(&mut self, id: &str, hidden: bool) {
+        if hidden {
+            self.hidden_calendars.insert(id.to_string());
+        } else {
+            self.hidden_calendars.remove(id);
+        }
+
+        // Keep config order stable: calendar list order, then any unknown ids
+        let mut hidden: Vec<String> = self
+            .calendars
+            .iter()
+            .filter(|c| self.hidden_calendars.contains(&c.id))
+            .map(|c| c.id.clone())
+            .collect();
+        for id in &self.config.hidden_calendars {
+            if self.hidden_calendars.contains(id) && !hidden.contains(id) {
+                hidden.push(id.clone());
+            }
+        }
+        self.config.hidden_calendars = hidden;
+        self.save_config();
+
+        self.refresh_events();
+    }
+
+    // ── Command line ──
+
+    pub fn open_command_line(&mut self) {
+        self.command_line = Some(CommandLineState::default());
+        self.command_history.reset_cursor();
+        self.input_mode = InputMode::Command;
+    }
+
+    /// Open the command line with `input` already typed.
+    pub fn open_command_line_with(&mut self, input: &str) {
+        self.open_command_line();
+        if let Some(line) = &mut self.command_line {
+            line.set_input(input);
+        }
+    }
+
+    pub fn close_command_line(&mut self) {
+        self.command_line = None;
+        self.input_mode = InputMode::Normal;
+    }
+
+    pub fn command_history_older(&mut self) {
+        if let Some(ref mut line) = self.command_line {
+            if let Some(entry) = self.command_history.older() {
+                line.set_input(entry);
+            }
+        }
+    }
+
+    pub fn command_history_newer(&mut self) {
+        if let Some(ref mut line) = self.command_line {
+            let entry = self.command_history.newer().unwrap_or_default();
+            line.set_input(entry);
+        }
+    }
+
+    pub fn command_complete(&mut self) {
+        let calendars = &self.calendars;
+        let filters = &self.config.filters;
+        let custom = &self.config.actions;
+        if let Some(ref mut line) = self.command_line {
+            line.complete(|input| {
+                let mut names = command::completions(input, calendars, filters);
+                if !input.contains(' ') {
+                    let actions = action::ACTIONS.iter().map(|a| a.name).chain(custom.iter().map(|a| a.name.as_str()));
+                    names.extend(actions.filter(|n| n.starts_with(input)).map(str::to_string));
+                }
+                names
+            });
+        }
+    }
+
+    /// Run the command currently typed on the command line.
+    pub fn submit_command(&mut self) {
+        let Some(line) = self.command_line.take() else {
+            return;
+        };
+        self.input_mode = InputMode::Normal;
+
+        self.command_history.push(&line.input);
+        if self.persist {
+            let _ = self.command_history.save();
+        }
+
+        match command::parse(&line.input, self.today) {
+            Ok(cmd) => self.execute_command(cmd),
+            Err(_) if self.run_named_action(line.input.trim()) => {}
+            Err(e) => self.toasts.error(e.to_string()),
+        }
+    }
+
+    /// Run a built-in or custom action by name. Returns false when no
+    /// action has that name.
+    pub fn run_named_action(&mut self, name: &str) -> bool {
+        if let Some(action) = action::by_name(name) {
+            action.run(self);
+            return true;
+        }
+        match self.config.actions.iter().position(|a| a.name == name) {
+            Some(idx) => {
+                self.run_custom_action(idx);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Run the custom action bound to `key`, if any.
+    pub fn run_custom_key(&mut self, key: char) {
+        if let Some(idx) = self.config.actions.iter().position(|a| a.key == Some(key)) {
+            self.run_custom_action(idx);
+        }
+    }
+
+    fn run_custom_action(&mut self, idx: usize) {
+        let action = self.config.actions[idx].clone();
+        let mut vars = vec![("CAL_DATE", self.selected_date.to_string())];
+        vars.extend(self.selection_vars());
+        match opener::run_shell(&action.command, &vars) {
+            Ok(()) => self.toasts.info(format!("Ran {}", action.name)),
+            Err(e) => self.fail(&format!("Running {}", action.name), e),
+        }
+    }
+
+    /// Fields of the selected event or reminder, for custom actions. Events
+    /// give their real times, not the ones shown in travel mode.
+    fn selection_vars(&self) -> Vec<(&'static str, String)> {
+        if let Some(event) = self.selected_event_index().and_then(|i| self.day_events.get(i)) {
+            return self.real_event(event).map(|real| real.env_vars()).unwrap_or_default();
+        }
+        if self.view_mode != ViewMode::Week {
+            if let DayAction::Reminder(idx) = self.day_action_at_scroll() {
+                if let Some(reminder) = self.day_reminders.get(idx) {
+                    return reminder.env_vars();
+                }
+            }
+        }
+        Vec::new()
+    }
+
+    fn execute_command(&mut self, cmd: Command) {
+        match cmd {
+            Command::Quit => self.running = false,
+            Command::Today => self.go_to_today(),
+            Command::Goto(date) => self.go_to_date(date),
+            Command::View(mode) => self.view_mode = mode,
+            Command::Hide(name) | Command::Show(name) if self.calendar_by_name(&name).is_none() => {
+                self.toasts.warn(format!("No calendar named {}", name));
+            }
+            Command::Hide(name) => {
+                if let Some(id) = self.calendar_by_name(&name) {
+                    self.set_calendar_hidden(&id, true);
+                    self.toasts.info(format!("Hidden: {}", name));
+                }
+            }
+            Command::Show(name) => {
+                if let Some(id) = self.calendar_by_name(&name) {
+                    self.set_calendar_hidden(&id, false);
+                    self.toasts.info(format!("Shown: {}", name));
+                }
+            }
+            Command::Cleanup(days) => self.open_cleanup(days),
+            Command::Duplicates => self.open_duplicates(),
+            Command::Paste => self.schedule_from_clipboard(),
+            Command::Focus => self.open_focus_day(),
+            Command::Travel(zone) => self.set_travel(zone.as_deref()),
+            Command::Status => self.open_health(),
+            Command::Hour(hour) => self.jump_to_hour(hour),
+            Command::Theme(name) => {
+                theme::set(Theme::preset(&name));
+                self.toasts.info(format!("Theme: {}", name));
+            }
+            Command::Shift { from, to, minutes } => self.shift_events(from, to, minutes),
+            Command::Template { week, source, target, until } => self.open_template(week, source, target, until),
+            Command::OfficeHours(weeks) => self.share_office_hours(weeks),
+            Command::Filter(None) => self.select_filter(None),
+            Command::Filter(Some(name)) => {
+                // A name first, then a position in the list, as `z1` picks
+                let by_name = self.config.filters.iter().position(|f| f.name.eq_ignore_ascii_case(&name));
+                let by_number = || name.parse::<usize>().ok().and_then(|n| n.checked_sub(1));
+                match by_name.or_else(by_number) {
+                    Some(idx) => self.select_filter(Some(idx)),
+                    None => self.toasts.warn(format!("No filter named {}", name)),
+                }
+            }
+        }
+    }
+
+    /// Apply the saved filter at `idx` in the config's list, or clear the
+    /// filter with `None`.
+    pub fn select_filter(&mut self, idx: Option<usize>) {
+        match idx {
+            None => self.toasts.info("Filter cleared"),
+            Some(idx) => match self.config.filters.get(idx) {
+                Some(filter) => self.toasts.info(format!("Filter: {}", filter.name)),
+                None => return self.toasts.warn(format!("No filter {}; {} saved", idx + 1, self.config.filters.len())),
+            },
+        }
+        self.active_filter = idx;
+        self.launch_filter = None;
+        self.refresh_events();
+    }
+
+    /// Name of the saved filter currently applied, if any.
+    pub fn active_filter_name(&self) -> Option<&str> {
+        self.current_filter().map(|f| f.name.as_str())
+    }
+
+    fn current_filter(&self) -> Option<&Filter> {
+        self.active_filter
+            .and_then(|idx| self.config.filters.get(idx))
+            .or(self.launch_filter.as_ref())
+    }
+
+    /// Show only the calendars named, as `--calendar` asks, without saving
+    /// a filter. Cleared by `:filter off`.
+    pub fn show_only_calendars(&mut self, names: Vec<String>) {
+        let unknown: Vec<&str> = names
+            .iter()
+            .filter(|n| self.calendar_by_name(n).is_none())
+            .map(String::as_str)
+            .collect();
+        if !unknown.is_empty() {
+            self.toasts.warn(format!("No calendar named {}", unknown.join(", ")));
+        }
+        self.launch_filter = Some(Filter { name: names.join(", "), calendars: names, ..Filter::default() });
+        self.refresh_events();
+    }
+
+    fn calendar_by_name(&self, name: &str) -> Option<String> {
+        self.calendars
+            .iter()
+            .find(|c| c.title.eq_ignore_ascii_case(name))
+            .map(|c| c.id.clone())
+    }
+
+    // ── Event form ──
+
+    /// New event on the selected day. Today it starts at the next snapped
+    /// slot from now; other days keep the 09:00 default.
+    pub fn open_event_form(&mut self) {
+        let now = Local::now().naive_local();
+        self.form_state = Some(if self.selected_date == now.date() {
+            let start = snap_up(now, self.config.snap_minutes());
+            EventFormState::with_times(start, start + chrono::Duration::hours(1))
+        } else {
+            EventFormState::new(self.selected_date)
+        });
+        self.input_mode = InputMode::Form;
+    }
+
+    /// New event from a shortcut such as `nw`: the usual form, with the
+    /// shortcut's calendar and alert picked.
+    pub fn open_event_form_with_shortcut(&mut self, key: char) {
+        let Some(shortcut) = self.config.event_shortcut(key).cloned() else {
+            return;
+        };
+        let Some(calendar_index) = self
+            .calendars
+            .iter()
+            .position(|c| c.title.eq_ignore_ascii_case(&shortcut.calendar))
+        else {
+            self.toasts.warn(format!("No calendar named {}", shortcut.calendar));
+            return;
+        };
+        self.open_event_form();
+        if let Some(form) = &mut self.form_state {
+            form.calendar_index = calendar_index;
+            if !form.set_alarm(shortcut.alert_minutes) {
+                let minutes = shortcut.alert_minutes.unwrap_or_default();
+                self.toasts.warn(format!("No {} minute alert option; none set", minutes));
+            }
+        }
+    }
+
+    /// Open the event form filled in from the clipboard, e.g. a meeting line
+    /// copied from an email.
+    pub fn schedule_from_clipboard(&mut self) {
+        let text = match clipboard::read_text() {
+            Ok(text) if !text.trim().is_empty() => text,
+            Ok(_) => {
+                self.toasts.warn("Clipboard is empty");
+                return;
+            }
+            Err(e) => {
+                self.toasts.error(format!("Error reading clipboard: {}", e));
+                return;
+            }
+        };
+        let parsed = natural::parse(&text, Local::now().date_naive());
+        let mut form = match parsed.time_range(self.selected_date) {
+            Some((start, end)) => EventFormState::with_times(start, end),
+            None => {
+                let mut form = EventFormState::new(parsed.date.unwrap_or(self.selected_date));
+                form.is_all_day = parsed.date.is_some();
+                form
+            }
+        };
+        form.title = parsed.title;
+        form.url = parsed.url.unwrap_or_default();
+        form.notes = parsed.notes.unwrap_or_default();
+        self.form_state = Some(form);
+        self.input_mode = InputMode::Form;
+    }
+
+    /// New event an hour from now, snapped to the configured granularity.
+    pub fn open_event_form_in_an_hour(&mut self) {
+        let start = snap_up(
+            Local::now().naive_local() + chrono::Duration::hours(1),
+            self.config.snap_minutes(),
+        );
+        self.form_state = Some(EventFormState::with_times(start, start + chrono::Duration::hours(1)));
+        self.input_mode = InputMode::Form;
+    }
+
+    pub fn close_event_form(&mut self) {
+        self.form_state = None;
+        self.input_mode = InputMode::Normal;
+    }
+
+    pub fn submit_event_form(&mut self) {
+        let form = match &self.form_state {
+            Some(f) if f.is_valid() => f.clone(),
+            Some(_) => {
+                self.toasts.warn("Invalid form data");
+                return;
+            }
+            None => return,
+        };
+
+        let cal_id = self.calendars.get(form.calendar_index).map(|c| c.id.clone());
+        let new_event = form.to_new_event(cal_id);
+
+        match self.create_event(&new_event) {
+            Ok(()) => {
+                self.toasts.success(format!("Created: {}", form.title));
+                self.feedback();
+                self.close_event_form();
+                self.refresh_events();
+                let attendees = form.attendee_list();
+                if !attendees.is_empty() {
+                    self.invite_prompt = Some(Invite { event: new_event, attendees });
+                }
+            }
+            Err(e) => self.fail("Creating event", e),
+        }
+    }
+
+    pub fn form_tab(&mut self) {
+        if let Some(ref mut form) = self.form_state {
+            form.active_field = form.active_field.next();
+        }
+    }
+
+    pub fn form_backtab(&mut self) {
+        if let Some(ref mut form) = self.form_state {
+            form.active_field = form.active_field.prev();
+        }
     }
 
     pub fn form_input_char(&mut self, c: char) {
@@ -470,55 +1816,931 @@ impl App {
             match form.active_field {
                 FormField::AllDay => form.toggle_all_day(),
                 FormField::Calendar => form.next_calendar(self.calendars.len()),
+                FormField::Alarm => form.next_alarm(),
+                _ => form.input_char(c),
+            }
+        }
+    }
+
+    pub fn form_backspace(&mut self) {
+        if let Some(ref mut form) = self.form_state {
+            form.backspace();
+        }
+    }
+
+    // ── Reminder editing ──
+
+    /// Open the edit form for the reminder shown in the detail popup.
+    pub fn edit_detail_reminder(&mut self) {
+        let Some(DayAction::Reminder(idx)) = self.detail_item else {
+            return;
+        };
+        if let Some(rem) = self.day_reminders.get(idx) {
+            self.reminder_form = Some(ReminderFormState::from_reminder(rem, &self.reminder_calendars));
+            self.detail_item = None;
+            self.input_mode = InputMode::ReminderForm;
+        }
+    }
+
+    pub fn close_reminder_form(&mut self) {
+        self.reminder_form = None;
+        self.input_mode = InputMode::Normal;
+    }
+
+    pub fn submit_reminder_form(&mut self) {
+        let form = match &self.reminder_form {
+            Some(f) if f.is_valid() => f.clone(),
+            Some(_) => {
+                self.toasts.warn("Invalid form data");
+                return;
+            }
+            None => return,
+        };
+
+        let cal_id = self
+            .reminder_calendars
+            .get(form.calendar_index)
+            .map(|c| c.id.as_str());
+
+        match self.store.update_reminder(
+            &form.reminder_id,
+            &form.title,
+            form.parsed_due_date(),
+            form.priority,
+            cal_id,
+        ) {
+            Ok(()) => {
+                self.toasts.success(format!("Updated: {}", form.title));
+                self.feedback();
+                self.close_reminder_form();
+                self.refresh_events();
+            }
+            Err(e) => self.fail("Saving reminder", e),
+        }
+    }
+
+    pub fn reminder_form_tab(&mut self) {
+        if let Some(ref mut form) = self.reminder_form {
+            form.active_field = form.active_field.next();
+        }
+    }
+
+    pub fn reminder_form_backtab(&mut self) {
+        if let Some(ref mut form) = self.reminder_form {
+            form.active_field = form.active_field.prev();
+        }
+    }
+
+    pub fn reminder_form_input_char(&mut self, c: char) {
+        if let Some(ref mut form) = self.reminder_form {
+            match form.active_field {
+                ReminderField::Priority => form.cycle_priority(),
+                ReminderField::List => form.next_list(self.reminder_calendars.len()),
                 _ => form.input_char(c),
             }
         }
     }
 
-    pub fn form_backspace(&mut self) {
-        if let Some(ref mut form) = self.form_state {
-            form.backspace();
+    pub fn reminder_form_backspace(&mut self) {
+        if let Some(ref mut form) = self.reminder_form {
+            form.backspace();
+        }
+    }
+
+    // ── Pinning ──
+
+    /// Pin or unpin the selected event. Pins are kept by event identifier, so
+    /// every occurrence of a recurring event shares its pin.
+    pub fn toggle_pin_selected(&mut self) {
+        let Some(idx) = self.selected_event_index() else {
+            return;
+        };
+        let Some(ev) = self.day_events.get(idx) else {
+            return;
+        };
+        let id = ev.id.clone();
+        let title = ev.title.clone();
+
+        if self.pinned_events.remove(&id) {
+            self.config.pinned_events.retain(|p| *p != id);
+            self.toasts.info(format!("Unpinned: {}", title));
+        } else {
+            self.pinned_events.insert(id.clone());
+            self.config.pinned_events.push(id.clone());
+            self.toasts.info(format!("Pinned: {}", title));
+        }
+        self.save_config();
+
+        // Keep the cursor on the event that moved
+        let len = self.day_list_len();
+        if let Some(pos) = (0..len).find(|&i| self.day_action_at(i) == DayAction::Event(idx)) {
+            self.day_scroll = pos;
+        }
+    }
+
+    // ── Maintenance ──
+
+    /// Scan the past year for stale suggested and declined events.
+    pub fn open_cleanup(&mut self, older_than_days: i64) {
+        let end = Local::now();
+        let start = end - chrono::Duration::days(CLEANUP_LOOKBACK_DAYS);
+        self.job = Some(Job::scan("Scanning", start, end, Finish::Cleanup(older_than_days)));
+    }
+
+    /// Scan around today for events duplicated across calendars.
+    pub fn open_duplicates(&mut self) {
+        let now = Local::now();
+        let window = chrono::Duration::days(DUPLICATE_WINDOW_DAYS);
+        self.job = Some(Job::scan("Scanning", now - window, now + window, Finish::Duplicates));
+    }
+
+    /// Preview copies of `source`'s events, or its week's, on each day or
+    /// week from `target` through `until`, to pick from before creating them.
+    pub fn open_template(&mut self, week: bool, source: NaiveDate, target: NaiveDate, until: NaiveDate) {
+        let align = |date: NaiveDate| if week { week_range(date).0.date_naive() } else { date };
+        let (source, target, until) = (align(source), align(target), align(until));
+        let days = if week { 7 } else { 1 };
+        let (start, end) = (day_range(source).0, day_range(source + chrono::Duration::days(days - 1)).1);
+        // Real times, not the ones shifted for travel mode
+        let events = self.visible(self.store.events_in_range(start, end));
+        let Some(template) = shift::template(&events, source, days, target, until) else {
+            let unit = if week { "weeks" } else { "days" };
+            self.toasts.error(format!("Can copy onto at most {} {}", shift::MAX_TEMPLATE_PERIODS, unit));
+            return;
+        };
+        let skipped = match template.skipped {
+            0 => String::new(),
+            n => format!(", skipped {} repeating", n),
+        };
+        if template.copies.is_empty() {
+            self.toasts.warn(format!("No events to copy on {}{}", source.format("%a %b %d"), skipped));
+            return;
+        }
+        if template.skipped > 0 {
+            self.toasts.info(format!("Repeating events skipped: {}", template.skipped));
+        }
+        let (title, from) = if week {
+            ("Copy week", format!("from week of {}", source.format("%b %d")))
+        } else {
+            ("Copy day", format!("from {}", source.format("%a %b %d")))
+        };
+        let items = template.copies.into_iter().map(|ev| (ev, from.clone())).collect();
+        self.event_review = Some(EventReviewState::create(title, items));
+    }
+
+    fn show_cleanup(&mut self, events: &[CalendarEvent], older_than_days: i64) {
+        let stale = maintenance::stale_events(events, self.today, older_than_days);
+        if stale.is_empty() {
+            self.toasts.warn("Nothing to clean up");
+            return;
+        }
+        self.event_review = Some(EventReviewState::new("Clean up", stale));
+    }
+
+    fn show_duplicates(&mut self, events: &[CalendarEvent]) {
+        let pairs = maintenance::duplicate_events(events);
+        if pairs.is_empty() {
+            self.toasts.warn("No duplicates found");
+            return;
+        }
+        let items = pairs.into_iter().map(|p| (p.remove, p.keep)).collect();
+        self.event_review = Some(EventReviewState::with_alternates("Duplicates", items));
+    }
+
+    // ── Start alerts ──
+
+    /// Queue alerts for events that started since the last poll. Times are
+    /// read from the store directly, unaffected by travel mode.
+    fn poll_alerts(&mut self) {
+        self.alerts_polled = Instant::now();
+        let (start, end) = day_range(self.today);
+        let events = self.visible(self.store.events_in_range(start, end));
+        let due = self.alerts.due(&events, Local::now());
+        if !due.is_empty() {
+            let mut out = std::io::stdout();
+            let _ = out.write_all(b"\x07").and_then(|_| out.flush());
         }
+        self.redraw |= !due.is_empty();
+        self.alert_queue.extend(due);
     }
 
-    // ── Event deletion ──
+    pub fn dismiss_alert(&mut self) {
+        self.alert_queue.pop_front();
+    }
 
-    pub fn delete_selected_event(&mut self) {
-        if let DayAction::Event(idx) = self.day_action_at_scroll() {
-            if let Some(ev) = self.day_events.get(idx) {
-                let event_id = ev.id.clone();
-                let event_title = ev.title.clone();
-
-                match self.store.delete_event(&event_id) {
-                    Ok(()) => {
-                        self.status_message = Some(format!("Deleted: {}", event_title));
+    /// Dismiss the current alert and raise it again in `snooze_minutes`.
+    pub fn snooze_alert(&mut self) {
+        let Some(ev) = self.alert_queue.pop_front() else {
+            return;
+        };
+        let minutes = self.config.snooze_minutes.max(1);
+        let until = Local::now() + chrono::Duration::minutes(minutes as i64);
+        self.toasts.info(format!("Snoozed {} for {} min", ev.title, minutes));
+        self.alerts.snooze(ev, until);
+    }
+
+    /// Open the current alert's video link and dismiss it.
+    pub fn join_alert(&mut self) {
+        let Some(link) = self.alert_queue.front().and_then(|ev| ev.video_link()) else {
+            return;
+        };
+        match opener::open_url(&link.url) {
+            Ok(()) => self.toasts.info(format!("Opening {}", link.service.label())),
+            Err(e) => self.toasts.error(format!("Error: {}", e)),
+        }
+        self.alert_queue.pop_front();
+    }
+
+    // ── Jobs ──
+
+    /// Run steps of the current job for up to [`JOB_SLICE`], finishing it
+    /// when none are left. Called every frame.
+    pub fn run_job(&mut self) {
+        let Some(mut job) = self.job.take() else {
+            return;
+        };
+        let started = Instant::now();
+        while started.elapsed() < JOB_SLICE {
+            let Some(step) = job.next_step() else {
+                break;
+            };
+            match &step {
+                Step::Delete(ids) => {
+                    if let Err(e) = self.store.delete_events(ids) {
+                        self.fail(&format!("Deleting events ({} of {} done)", job.done, job.total), e);
                         self.refresh_events();
+                        return;
                     }
-                    Err(e) => {
-                        self.status_message = Some(format!("Error: {}", e));
+                }
+                Step::Create(events) => {
+                    if let Err(e) = events.iter().try_for_each(|ev| self.store.create_event(ev)) {
+                        self.fail(&format!("Creating events ({} of {} done)", job.done, job.total), e);
+                        self.refresh_events();
+                        return;
                     }
                 }
+                Step::Query(start, end) => job.collect(self.store.events_in_range(*start, *end)),
+            }
+            job.advance(&step);
+        }
+        if !job.is_finished() {
+            self.job = Some(job);
+            return;
+        }
+        match job.finish {
+            Finish::Deleted => {
+                let n = job.done;
+                self.toasts.success(format!("Deleted {} event{}", n, if n == 1 { "" } else { "s" }));
+                self.refresh_events();
+            }
+            Finish::Created => {
+                let n = job.done;
+                self.toasts.success(format!("Created {} event{}", n, if n == 1 { "" } else { "s" }));
+                self.feedback();
+                self.refresh_events();
+            }
+            Finish::Cleanup(days) => self.show_cleanup(&job.events, days),
+            Finish::Duplicates => self.show_duplicates(&job.events),
+        }
+    }
+
+    /// Stop the current job after the step in progress. Events already
+    /// deleted or created stay that way.
+    pub fn cancel_job(&mut self) {
+        let Some(job) = self.job.take() else {
+            return;
+        };
+        self.toasts.info(format!("Cancelled: {}", job.progress_label().to_lowercase()));
+        if matches!(job.finish, Finish::Deleted | Finish::Created) {
+            self.refresh_events();
+        }
+    }
+
+    pub fn review_swap(&mut self) {
+        if let Some(ref mut review) = self.event_review {
+            review.swap();
+        }
+    }
+
+    pub fn review_down(&mut self) {
+        if let Some(ref mut review) = self.event_review {
+            review.down();
+        }
+    }
+
+    pub fn review_up(&mut self) {
+        if let Some(ref mut review) = self.event_review {
+            review.up();
+        }
+    }
+
+    pub fn review_toggle(&mut self) {
+        if let Some(ref mut review) = self.event_review {
+            review.toggle();
+        }
+    }
+
+    /// Ask for confirmation before deleting the marked events.
+    pub fn review_confirm(&mut self) {
+        if let Some(ref mut review) = self.event_review {
+            review.confirming = !review.marked_ids().is_empty();
+        }
+    }
+
+    pub fn review_cancel(&mut self) {
+        match self.event_review {
+            Some(ref mut review) if review.confirming => review.confirming = false,
+            _ => self.event_review = None,
+        }
+    }
+
+    /// Move every visible event starting from `from` through `to` by
+    /// `minutes`, all in one change that `u` reverts.
+    pub fn shift_events(&mut self, from: NaiveDate, to: NaiveDate, minutes: i64) {
+        // Real times, not the ones shifted for travel mode
+        let (start, end) = (day_range(from).0, day_range(to).1);
+        let mut events = self.visible(self.store.events_in_range(start, end));
+        events.retain(|ev| ev.start >= start);
+        let plan = shift::plan(&events, minutes);
+        let skipped = match plan.skipped {
+            0 => String::new(),
+            n => format!(", skipped {}", n),
+        };
+        if plan.changes.is_empty() {
+            self.toasts.warn(format!("Nothing to shift{}", skipped));
+            return;
+        }
+        match self.store.reschedule_events(&plan.changes) {
+            Ok(()) => {
+                let count = plan.changes.len();
+                let done = format!(
+                    "Shifted {} event{} by {}",
+                    count,
+                    if count == 1 { "" } else { "s" },
+                    shift::delta_label(minutes)
+                );
+                self.toasts.success(format!("{}{} (u to undo)", done, skipped));
+                self.undo = Some((done, plan.undo));
+                self.feedback();
+                self.reload();
+            }
+            Err(e) => self.fail("Shifting events", e),
+        }
+    }
+
+    /// Revert the last `:shift`.
+    pub fn undo(&mut self) {
+        let Some((done, changes)) = self.undo.take() else {
+            self.toasts.info("Nothing to undo");
+            return;
+        };
+        match self.store.reschedule_events(&changes) {
+            Ok(()) => {
+                self.toasts.success(format!("Undone: {}", done));
+                self.reload();
+            }
+            Err(e) => self.fail("Undoing shift", e),
+        }
+    }
+
+    /// Propose a focus day on the selected date, on the work calendar.
+    pub fn open_focus_day(&mut self) {
+        let calendar = match self.config.work_calendar.as_deref() {
+            Some(name) => self.calendars.iter().find(|c| c.title.eq_ignore_ascii_case(name)).cloned(),
+            None => self.calendars.first().cloned(),
+        };
+        let Some(calendar) = calendar else {
+            self.toasts.warn(match self.config.work_calendar.as_deref() {
+                Some(name) => format!("No calendar named {}", name),
+                None => "No calendar available".to_string(),
+            });
+            return;
+        };
+        let events = self.events_in(day_range(self.selected_date));
+        self.focus_prompt = Some(FocusDay {
+            date: self.selected_date,
+            calendar,
+            conflicts: maintenance::focus_conflicts(&events, self.selected_date),
+        });
+    }
+
+    /// Create the proposed focus day.
+    pub fn confirm_focus_day(&mut self) {
+        let Some(focus) = self.focus_prompt.take() else {
+            return;
+        };
+        let event = NewEvent {
+            title: FOCUS_DAY_TITLE.to_string(),
+            date: focus.date,
+            start_time: NaiveTime::MIN,
+            end_date: focus.date,
+            end_time: NaiveTime::MIN,
+            is_all_day: true,
+            calendar_id: Some(focus.calendar.id),
+            location: None,
+            url: None,
+            notes: None,
+            alarm_minutes: None,
+            busy: true,
+        };
+        match self.create_event(&event) {
+            Ok(()) => {
+                let n = focus.conflicts.len();
+                self.toasts.success(match n {
+                    0 => format!("Focus day on {}", focus.date.format("%a %b %d")),
+                    _ => format!(
+                        "Focus day on {}; {} meeting{} to decline or move",
+                        focus.date.format("%a %b %d"),
+                        n,
+                        if n == 1 { "" } else { "s" }
+                    ),
+                });
+                self.feedback();
+                self.refresh_events();
+            }
+            Err(e) => self.fail("Creating focus day", e),
+        }
+    }
+
+    pub fn close_focus_prompt(&mut self) {
+        self.focus_prompt = None;
+    }
+
+    /// Copy the weekday slots free in working hours in each of the next
+    /// `weeks` weeks, starting tomorrow.
+    pub fn share_office_hours(&mut self, weeks: u32) {
+        let today = Local::now().date_naive();
+        let from = today.succ_opt().unwrap_or(today);
+        let Some(last) = chrono::Duration::try_weeks(weeks as i64).and_then(|w| from.checked_add_signed(w)) else {
+            self.toasts.error(format!("Can't look {} weeks ahead", weeks));
+            return;
+        };
+        let (start, _) = day_range(from);
+        let (_, end) = day_range(last);
+        let mut events = self.events_in((start, end));
+        events.retain(|e| !self.hidden_calendars.contains(&e.calendar_id));
+        let hours = (self.config.work_start_hour, self.config.work_end_hour);
+        let slots = availability::office_hours(&events, from, weeks, hours, OFFICE_HOURS_MIN_MINUTES);
+        let text = availability::summary(&slots);
+        self.toasts.info(match clipboard::write_text(&text) {
+            Ok(()) => format!("{} (copied)", text),
+            Err(_) => text,
+        });
+    }
+
+    /// Flip between 24-hour and 12-hour times for this session; the
+    /// `time_format` setting is left as it is.
+    pub fn toggle_time_format(&mut self) {
+        let format = match TimeFormat::current() {
+            TimeFormat::H24 => TimeFormat::H12,
+            TimeFormat::H12 => TimeFormat::H24,
+        };
+        format.set_current();
+        self.toasts.info(match format {
+            TimeFormat::H24 => "24-hour times".to_string(),
+            TimeFormat::H12 => "12-hour times".to_string(),
+        });
+    }
+
+    /// Show times in `zone`, or local time again with `None`.
+    pub fn set_travel(&mut self, zone: Option<&str>) {
+        match zone.map(Zone::named).transpose() {
+            Ok(zone) => {
+                self.toasts.info(match &zone {
+                    Some(z) => format!("Showing times in {}", z.name()),
+                    None => "Showing local time".to_string(),
+                });
+                self.travel = zone;
+                self.refresh_events();
+            }
+            Err(e) => self.toasts.error(e.to_string()),
+        }
+    }
+
+    /// Text for the notice row above the status bar, if any.
+    pub fn banner_text(&self) -> Option<String> {
+        let travel = self.travel.as_ref().map(|zone| {
+            format!(
+                "Travel mode: times in {} ({}) \u{2014} :travel off to return",
+                zone.name(),
+                zone.offset_label(chrono::Utc::now())
+            )
+        });
+        let parts: Vec<String> = [travel, self.banner.clone(), health::access_banner(self.permissions)]
+            .into_iter()
+            .flatten()
+            .collect();
+        (!parts.is_empty()).then(|| parts.join(" \u{2502} "))
+    }
+
+    /// Write the pending invite to the invites directory and copy its path.
+    pub fn export_invite(&mut self) {
+        let Some(invite) = self.invite_prompt.take() else {
+            return;
+        };
+        match self.write_invite(&invite) {
+            Ok(path) => {
+                let path = path.display().to_string();
+                self.toasts.success(match clipboard::write_text(&path) {
+                    Ok(()) => format!("Invite saved to {} (path copied)", path),
+                    Err(_) => format!("Invite saved to {}", path),
+                });
+            }
+            Err(e) => self.fail("Saving invite", e),
+        }
+    }
+
+    fn write_invite(&self, invite: &Invite) -> Result<PathBuf> {
+        let dir = ics::invites_dir().ok_or_else(|| eyre!("No home directory"))?;
+        std::fs::create_dir_all(&dir)?;
+        let now = chrono::Utc::now();
+        let uid = format!("{}@calendar-tui", now.format("%Y%m%dT%H%M%S%3f"));
+        let path = dir.join(format!("{}-{}.ics", invite.event.date.format("%Y-%m-%d"), file_slug(&invite.event.title)));
+        let text = ics::invite(&invite.event, &uid, self.config.email.as_deref(), &invite.attendees, now);
+        std::fs::write(&path, text)?;
+        Ok(path)
+    }
+
+    pub fn close_invite_prompt(&mut self) {
+        self.invite_prompt = None;
+    }
+
+    /// Write `buffer`, the frame just drawn, to the snapshots directory and
+    /// copy the file's path.
+    pub fn save_snapshot(&mut self, buffer: &Buffer, format: SnapshotFormat) {
+        match write_snapshot(buffer, format) {
+            Ok(path) => {
+                let path = path.display().to_string();
+                self.toasts.success(match clipboard::write_text(&path) {
+                    Ok(()) => format!("Snapshot saved to {} (path copied)", path),
+                    Err(_) => format!("Snapshot saved to {}", path),
+                });
+            }
+            Err(e) => self.fail("Saving snapshot", e),
+        }
+    }
+
+    /// Join the video call of the event shown in the detail popup.
+    pub fn open_video_link(&mut self) {
+        let Some(DayAction::Event(idx)) = self.detail_item else {
+            return;
+        };
+        let Some(link) = self.day_events.get(idx).and_then(|ev| ev.video_link()) else {
+            self.toasts.warn("No video link");
+            return;
+        };
+        match opener::open_url(&link.url) {
+            Ok(()) => self.toasts.info(format!("Opening {}", link.service.label())),
+            Err(e) => self.toasts.error(format!("Error: {}", e)),
+        }
+    }
+
+    /// Look up the location of the event shown in the detail popup in Maps.
+    pub fn open_location_in_maps(&mut self) {
+        let Some(DayAction::Event(idx)) = self.detail_item else {
+            return;
+        };
+        let Some(location) = self.day_events.get(idx).and_then(|ev| ev.location.clone()) else {
+            self.toasts.warn("No location");
+            return;
+        };
+        match opener::open_url(&opener::maps_url(&location)) {
+            Ok(()) => self.toasts.info(format!("Opening {} in Maps", location)),
+            Err(e) => self.toasts.error(format!("Error: {}", e)),
+        }
+    }
+
+    /// Answer the invitation shown in the detail popup. EventKit keeps
+    /// attendee replies read-only, so the answer is written as an iTIP reply
+    /// for the organizer and its path copied, ready to attach to an email.
+    pub fn rsvp(&mut self, status: ParticipantStatus) {
+        let Some(DayAction::Event(idx)) = self.detail_item else {
+            return;
+        };
+        let Some(ev) = self.day_events.get(idx).filter(|ev| ev.awaits_reply()) else {
+            self.toasts.warn("Not an invitation you can reply to");
+            return;
+        };
+        match self.write_reply(ev, status) {
+            Ok(path) => {
+                let path = path.display().to_string();
+                let organizer = ev.organizer.as_ref().and_then(|o| o.email.clone()).unwrap_or_default();
+                let copied = if clipboard::write_text(&path).is_ok() { " (path copied)" } else { "" };
+                self.toasts.success(format!(
+                    "Reply ({}) saved to {}{}; send it to {}",
+                    status.label(),
+                    path,
+                    copied,
+                    organizer
+                ));
+            }
+            Err(e) => self.fail("Saving reply", e),
+        }
+    }
+
+    fn write_reply(&self, ev: &CalendarEvent, status: ParticipantStatus) -> Result<PathBuf> {
+        // The reply states times in UTC, so it needs the real ones
+        let ev = &self.real_event(ev).ok_or_else(|| eyre!("The event is no longer in the calendar"))?;
+        let me = ev.attendees.iter().find(|a| a.is_me).ok_or_else(|| eyre!("You are not invited"))?;
+        let text = ics::reply(ev, me, status, chrono::Utc::now())?;
+        let dir = ics::invites_dir().ok_or_else(|| eyre!("No home directory"))?;
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join(format!("reply-{}-{}.ics", ev.start.format("%Y-%m-%d"), file_slug(&ev.title)));
+        std::fs::write(&path, text)?;
+        Ok(path)
+    }
+
+    /// Delete every marked event, or create every marked copy for a
+    /// `:template` review, a batch per step of a job.
+    pub fn review_apply(&mut self) {
+        let Some(review) = self.event_review.take() else {
+            return;
+        };
+        self.job = Some(match review.action {
+            ReviewAction::Delete => Job::delete(review.marked_ids()),
+            ReviewAction::Create => Job::create(review.marked_events().map(|ev| ev.copy_to(ev.start.date_naive())).collect()),
+        });
+    }
+
+    // ── Event deletion ──
+
+    /// Delete the selected event, asking first unless `confirm_delete` is off.
+    pub fn delete_selected_event(&mut self) {
+        let Some(ev) = self.selected_event_index().and_then(|idx| self.day_events.get(idx)).cloned() else {
+            return;
+        };
+        if self.config.confirm_delete {
+            self.delete_prompt = Some(ev);
+        } else {
+            self.delete_event(&ev);
+        }
+    }
+
+    pub fn confirm_delete(&mut self) {
+        if let Some(ev) = self.delete_prompt.take() {
+            self.delete_event(&ev);
+        }
+    }
+
+    pub fn close_delete_prompt(&mut self) {
+        self.delete_prompt = None;
+    }
+
+    /// Remember the selected event for [`duplicate_yanked_event`](Self::duplicate_yanked_event).
+    pub fn yank_selected_event(&mut self) {
+        if let Some(ev) = self.selected_event_index().and_then(|idx| self.day_events.get(idx)).cloned() {
+            self.toasts.info(format!("Yanked: {} (D to copy onto a day)", ev.title));
+            self.yanked_event = Some(ev);
+        }
+    }
+
+    /// Copy the yanked event onto the selected date, keeping its times.
+    pub fn duplicate_yanked_event(&mut self) {
+        let Some(ev) = self.yanked_event.as_ref() else {
+            self.toasts.warn("Nothing yanked; press y on an event first");
+            return;
+        };
+        let copy = ev.copy_to(self.selected_date);
+        match self.create_event(&copy) {
+            Ok(()) => {
+                self.toasts.success(format!("Copied {} to {}", copy.title, self.selected_date.format("%a %b %d")));
+                self.feedback();
+                self.refresh_events();
+            }
+            Err(e) => self.fail("Copying event", e),
+        }
+    }
+
+    /// Pick up the selected event to reschedule it with [`drop_cut_event`](Self::drop_cut_event).
+    pub fn cut_selected_event(&mut self) {
+        let Some(ev) = self.selected_event_index().and_then(|idx| self.day_events.get(idx)).cloned() else {
+            return;
+        };
+        if ev.recurrence.is_some() {
+            self.toasts.warn("Can't move a repeating event");
+            return;
+        }
+        self.toasts.info(format!("Picked up: {} (p to drop on a day, Esc to cancel)", ev.title));
+        self.cut_event = Some(ev);
+    }
+
+    /// Move the picked-up event onto the selected date.
+    pub fn drop_cut_event(&mut self) {
+        let Some(ev) = self.cut_event.take() else {
+            return;
+        };
+        let days = (self.selected_date - ev.start.date_naive()).num_days();
+        if days == 0 {
+            self.toasts.warn(format!("{} is already on this day", ev.title));
+            return;
+        }
+        match self.store.move_event(&ev.id, days) {
+            Ok(()) => {
+                self.toasts.success(format!("Moved {} to {}", ev.title, self.selected_date.format("%a %b %d")));
+                self.feedback();
+                self.refresh_events();
+            }
+            Err(e) => self.fail("Moving event", e),
+        }
+    }
+
+    /// Shift the selected event's start and end by `minutes`, e.g. 15 or -60.
+    pub fn nudge_selected_event(&mut self, minutes: i64) {
+        let Some(ev) = self.selected_event_index().and_then(|idx| self.day_events.get(idx)).cloned() else {
+            return;
+        };
+        if ev.is_all_day {
+            self.toasts.warn("All-day events have no time to nudge");
+            return;
+        }
+        if ev.recurrence.is_some() {
+            self.toasts.warn("Can't move a repeating event");
+            return;
+        }
+        let by = chrono::Duration::minutes(minutes);
+        let (start, end) = match &self.travel {
+            Some(zone) => (zone.to_local(ev.start + by), zone.to_local(ev.end + by)),
+            None => (ev.start + by, ev.end + by),
+        };
+        match self.store.reschedule_event(&ev.id, start, end) {
+            Ok(()) => {
+                self.reload();
+                self.select_event(&ev.id);
+                self.toasts.info(format!("{} now {}", ev.title, format_time(ev.start + by)));
+            }
+            Err(e) => self.fail("Moving event", e),
+        }
+    }
+
+    /// Put the cursor on the event with `id`, if it is on the selected day.
+    fn select_event(&mut self, id: &str) {
+        let Some(idx) = self.day_events.iter().position(|e| e.id == id) else {
+            return;
+        };
+        if self.view_mode == ViewMode::Week {
+            self.week_cursor = idx;
+        } else if let Some(pos) = (0..self.day_list_len()).find(|&i| self.day_action_at(i) == DayAction::Event(idx)) {
+            self.day_scroll = pos;
+        }
+    }
+
+    pub fn cancel_cut(&mut self) {
+        if self.cut_event.take().is_some() {
+            self.toasts.clear();
+        }
+    }
+
+    fn delete_event(&mut self, ev: &CalendarEvent) {
+        match self.store.delete_event(&ev.id) {
+            Ok(()) => {
+                self.toasts.success(format!("Deleted: {}", ev.title));
+                self.refresh_events();
             }
+            Err(e) => self.fail("Deleting event", e),
         }
     }
 
     // ── Internal ──
 
+    /// Drop events belonging to hidden calendars or outside the active filter.
+    fn visible(&self, mut events: Vec<CalendarEvent>) -> Vec<CalendarEvent> {
+        let filter = self.current_filter();
+        events.retain(|e| {
+            !self.hidden_calendars.contains(&e.calendar_id)
+                && filter.is_none_or(|f| f.matches(e))
+                && !(self.config.hide_declined && e.declined)
+        });
+        events
+    }
+
+    /// Events overlapping a local range, read on the travel timezone's
+    /// clock while travel mode is on.
+    fn events_in(&self, range: (DateTime<Local>, DateTime<Local>)) -> Vec<CalendarEvent> {
+        self.query_in(range).events
+    }
+
+    /// Like [`events_in`](Self::events_in), with what the store left out.
+    fn query_in(&self, range: (DateTime<Local>, DateTime<Local>)) -> EventQuery {
+        if !self.access_granted {
+            return EventQuery::default();
+        }
+        let (start, end) = self.store_range(range);
+        let query = self.store.query_events(start, end);
+        EventQuery { events: self.as_displayed(query.events, range), gaps: query.gaps }
+    }
+
+    /// The store's range for a displayed one, which differs in travel mode.
+    fn store_range(&self, (start, end): (DateTime<Local>, DateTime<Local>)) -> (DateTime<Local>, DateTime<Local>) {
+        match &self.travel {
+            Some(zone) => (zone.to_local(start), zone.to_local(end)),
+            None => (start, end),
+        }
+    }
+
+    /// Events fetched for [`store_range`](Self::store_range) of `range`, as
+    /// displayed.
+    fn as_displayed(
+        &self,
+        mut events: Vec<CalendarEvent>,
+        (start, end): (DateTime<Local>, DateTime<Local>),
+    ) -> Vec<CalendarEvent> {
+        let Some(zone) = &self.travel else {
+            return events;
+        };
+        for ev in &mut events {
+            zone.shift_event(ev);
+        }
+        // All-day events keep their dates, so the shifted query can catch a neighbour
+        events.retain(|e| e.start < end && e.end > start);
+        events.sort_by_key(|e| e.start);
+        events
+    }
+
+    /// The store's copy of `ev`, one of the events as displayed, with real
+    /// times in travel mode. `None` when the store no longer has it.
+    fn real_event(&self, ev: &CalendarEvent) -> Option<CalendarEvent> {
+        let Some(zone) = &self.travel else {
+            return Some(ev.clone());
+        };
+        let (start, end) = self.store_range((ev.start, ev.end.max(ev.start + chrono::Duration::minutes(1))));
+        // Occurrences of a series share an id; the start tells them apart
+        self.store.events_in_range(start, end).into_iter().find(|real| {
+            let mut shown = real.clone();
+            zone.shift_event(&mut shown);
+            real.id == ev.id && shown.start == ev.start
+        })
+    }
+
+    /// Save a new event, reading its times on the travel timezone's clock
+    /// while travel mode is on.
+    fn create_event(&self, new: &NewEvent) -> Result<()> {
+        match &self.travel {
+            Some(zone) => self.store.create_event(&zone.new_event_from_display(new)),
+            None => self.store.create_event(new),
+        }
+    }
+
+    /// Minutes left at `now` in a displayed event, read from its real times
+    /// in [`today_events`](Self::today_events) since the clock is not shifted
+    /// for travel mode.
+    pub fn minutes_left(&self, shown: &CalendarEvent, now: DateTime<Local>) -> Option<u32> {
+        let real = self.today_events.iter().find(|real| {
+            let mut displayed = (*real).clone();
+            if let Some(zone) = &self.travel {
+                zone.shift_event(&mut displayed);
+            }
+            real.id == shown.id && displayed.start == shown.start
+        })?;
+        layout::minutes_left(real, now)
+    }
+
+    /// Real times, not the ones shifted for travel mode, to compare with the clock.
+    fn refresh_today_events(&mut self) {
+        if !self.access_granted {
+            return;
+        }
+        let (start, end) = day_range(self.today);
+        self.today_events = self.visible(self.store.events_in_range(start, end));
+    }
+
+    /// Reload the selected day's and week's events, counting what the
+    /// filters leave out.
+    fn refresh_day_and_week_events(&mut self) {
+        let day = self.query_in(day_range(self.selected_date));
+        let week = self.query_in(week_range(self.selected_date));
+        self.data_gaps = self.month_gaps.clone();
+        for gap in day.gaps.into_iter().chain(week.gaps) {
+            if !self.data_gaps.contains(&gap) {
+                self.data_gaps.push(gap);
+            }
+        }
+        let (day, week) = (day.events, week.events);
+        let (day_total, week_total) = (day.len(), week.len());
+        self.day_events = self.visible(day);
+        self.week_events = self.visible(week);
+        self.day_hidden_events = day_total - self.day_events.len();
+        self.week_hidden = week_total - self.week_events.len();
+    }
+
     fn on_date_changed(&mut self) {
+        self.week_cursor = 0;
         let old_month = self.month_events.first().map(|e| e.start.date_naive().month());
         let new_month = self.selected_date.month();
 
         if old_month != Some(new_month) || self.month_events.is_empty() {
-            self.refresh_events();
+            self.show_month();
         } else {
-            self.day_events = self.store.events_for_date(self.selected_date);
-            self.week_events = self.store.events_for_week(self.selected_date);
+            self.refresh_day_and_week_events();
             self.update_day_reminders();
             self.day_scroll = self.first_actionable_scroll();
         }
     }
 }
 
+fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), chrono::Weekday::Sat | chrono::Weekday::Sun)
+}
+
 fn days_in_month(year: i32, month: u32) -> u32 {
     if month == 12 {
         NaiveDate::from_ymd_opt(year + 1, 1, 1)
@@ -529,3 +2751,20 @@ fn days_in_month(year: i32, month: u32) -> u32 {
     .signed_duration_since(NaiveDate::from_ymd_opt(year, month, 1).unwrap())
     .num_days() as u32
 }
+
+fn write_snapshot(buffer: &Buffer, format: SnapshotFormat) -> Result<PathBuf> {
+    let dir = snapshot::snapshots_dir().ok_or_else(|| eyre!("No home directory"))?;
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("calendar-{}.txt", Local::now().format("%Y-%m-%d-%H%M%S")));
+    std::fs::write(&path, snapshot::render(buffer, format))?;
+    Ok(path)
+}
+
+/// Lowercase file name part for `title`, e.g. "team-sync" for "Team sync!".
+fn file_slug(title: &str) -> String {
+    let slug: String = title
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c.to_ascii_lowercase() } else { '-' })
+        .collect();
+    slug.trim_matches('-').to_string()
+}
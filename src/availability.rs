@@ -0,0 +1,132 @@
+//! Free time within working hours, and the weekly slots that stay free week
+//! after week, for sharing as office hours.
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime, Weekday};
+
+use crate::calendar::{CalendarEvent, TimeFormat};
+
+/// Working days considered for office hours.
+const WORKDAYS: [Weekday; 5] = [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri];
+
+/// A free interval in minutes since midnight, end exclusive.
+pub type Slot = (u32, u32);
+
+/// Free intervals on `date` between the `hours` bounds (start hour,
+/// exclusive end hour). Declined invitations and all-day events do not
+/// take up time.
+pub fn free_slots(events: &[CalendarEvent], date: NaiveDate, (first, last): (u32, u32)) -> Vec<Slot> {
+    let (day_start, day_end) = (first * 60, last.min(24) * 60);
+    let Some(midnight) = date.and_hms_opt(0, 0, 0) else {
+        return Vec::new();
+    };
+    let mut busy: Vec<Slot> = events
+        .iter()
+        .filter(|ev| !ev.is_all_day && !ev.declined && ev.occurs_on(date))
+        .map(|ev| {
+            let minutes = |t: chrono::NaiveDateTime| (t - midnight).num_minutes().clamp(0, 24 * 60) as u32;
+            (minutes(ev.start.naive_local()), minutes(ev.end.naive_local()))
+        })
+        .collect();
+    busy.sort();
+
+    let mut free = Vec::new();
+    let mut cursor = day_start;
+    for (start, end) in busy {
+        if start > cursor {
+            free.push((cursor, start.min(day_end)));
+        }
+        cursor = cursor.max(end);
+        if cursor >= day_end {
+            break;
+        }
+    }
+    if cursor < day_end {
+        free.push((cursor, day_end));
+    }
+    free.retain(|(start, end)| end > start);
+    free
+}
+
+/// A weekday and time range that was free in every week looked at.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OfficeHours {
+    pub weekday: Weekday,
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+/// Slots of at least `min_minutes` free on the same weekday and time in
+/// each of the `weeks` weeks from `from` onwards, Monday to Friday.
+/// Repeating meetings are caught because `events` holds their occurrences.
+pub fn office_hours(
+    events: &[CalendarEvent],
+    from: NaiveDate,
+    weeks: u32,
+    hours: (u32, u32),
+    min_minutes: u32,
+) -> Vec<OfficeHours> {
+    let mut result = Vec::new();
+    for weekday in WORKDAYS {
+        let ahead = (weekday.num_days_from_monday() as i64 - from.weekday().num_days_from_monday() as i64)
+            .rem_euclid(7);
+        let first = from + Duration::days(ahead);
+        let mut common = vec![(hours.0 * 60, hours.1.min(24) * 60)];
+        for week in 0..weeks.max(1) {
+            let date = first + Duration::weeks(week as i64);
+            common = intersect(&common, &free_slots(events, date, hours));
+        }
+        for (start, end) in common {
+            if end - start < min_minutes {
+                continue;
+            }
+            let time = |m: u32| NaiveTime::from_hms_opt(m / 60 % 24, m % 60, 0).unwrap_or(NaiveTime::MIN);
+            result.push(OfficeHours { weekday, start: time(start), end: time(end) });
+        }
+    }
+    result
+}
+
+/// "Office hours: Tue 2–4pm, Thu 10am–12pm", in the current time format.
+pub fn summary(slots: &[OfficeHours]) -> String {
+    if slots.is_empty() {
+        return "Office hours: none free this period".to_string();
+    }
+    let parts: Vec<String> = slots
+        .iter()
+        .map(|s| format!("{} {}", s.weekday, range_label(s.start, s.end)))
+        .collect();
+    format!("Office hours: {}", parts.join(", "))
+}
+
+/// "14:00–16:00", or in 12-hour format "2–4pm" / "11am–1pm".
+pub fn range_label(start: NaiveTime, end: NaiveTime) -> String {
+    if TimeFormat::current() == TimeFormat::H24 {
+        return format!("{}\u{2013}{}", start.format("%H:%M"), end.format("%H:%M"));
+    }
+    let short = |t: NaiveTime| {
+        if t.format("%M").to_string() == "00" {
+            t.format("%-I").to_string()
+        } else {
+            t.format("%-I:%M").to_string()
+        }
+    };
+    let meridiem = |t: NaiveTime| t.format("%P").to_string();
+    if meridiem(start) == meridiem(end) {
+        format!("{}\u{2013}{}{}", short(start), short(end), meridiem(end))
+    } else {
+        format!("{}{}\u{2013}{}{}", short(start), meridiem(start), short(end), meridiem(end))
+    }
+}
+
+fn intersect(a: &[Slot], b: &[Slot]) -> Vec<Slot> {
+    let mut out = Vec::new();
+    for &(a_start, a_end) in a {
+        for &(b_start, b_end) in b {
+            let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+            if start < end {
+                out.push((start, end));
+            }
+        }
+    }
+    out
+}
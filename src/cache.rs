@@ -0,0 +1,62 @@
+//! Recently fetched months, so paging back and forth with `[`/`]` doesn't
+//! query EventKit each time.
+
+use std::collections::VecDeque;
+
+/// Months kept before the least recently used one is dropped.
+pub const MONTH_CACHE_SIZE: usize = 6;
+
+/// Values keyed by (year, month), most recently used last.
+#[derive(Debug, Clone)]
+pub struct MonthCache<T> {
+    entries: VecDeque<((i32, u32), T)>,
+    capacity: usize,
+}
+
+impl<T> Default for MonthCache<T> {
+    fn default() -> Self {
+        Self::new(MONTH_CACHE_SIZE)
+    }
+}
+
+impl<T> MonthCache<T> {
+    pub fn new(capacity: usize) -> Self {
+        Self { entries: VecDeque::with_capacity(capacity), capacity }
+    }
+
+    pub fn insert(&mut self, year: i32, month: u32, value: T) {
+        self.entries.retain(|(key, _)| *key != (year, month));
+        if self.entries.len() >= self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(((year, month), value));
+    }
+
+    /// Forget every month, after events were created, changed or deleted.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    pub fn contains(&self, year: i32, month: u32) -> bool {
+        self.entries.iter().any(|(key, _)| *key == (year, month))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<T: Clone> MonthCache<T> {
+    /// A copy of the cached month, marking it as just used.
+    pub fn get(&mut self, year: i32, month: u32) -> Option<T> {
+        let idx = self.entries.iter().position(|(key, _)| *key == (year, month))?;
+        let entry = self.entries.remove(idx)?;
+        let value = entry.1.clone();
+        self.entries.push_back(entry);
+        Some(value)
+    }
+}
@@ -0,0 +1,223 @@
+use chrono::{DateTime, Datelike, Local, NaiveDate, TimeZone};
+use color_eyre::Result;
+
+use super::calendar::CalendarInfo;
+use super::event::{CalendarEvent, NewEvent};
+use super::prefetch::Prefetcher;
+use super::reminder::Reminder;
+
+/// Authorization state for one kind of data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Access {
+    Full,
+    /// Events can be added but not read (macOS "Add Events Only").
+    WriteOnly,
+    Denied,
+    /// Blocked by a device management profile or parental controls.
+    Restricted,
+    /// Never asked, or the request is still pending.
+    NotDetermined,
+}
+
+impl Access {
+    pub fn label(self) -> &'static str {
+        match self {
+            Access::Full => "full access",
+            Access::WriteOnly => "add only",
+            Access::Denied => "denied",
+            Access::Restricted => "restricted",
+            Access::NotDetermined => "not asked",
+        }
+    }
+
+    pub fn can_read(self) -> bool {
+        self == Access::Full
+    }
+}
+
+/// New times for one event of a batch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reschedule {
+    pub event_id: String,
+    pub start: DateTime<Local>,
+    pub end: DateTime<Local>,
+}
+
+/// Access to events and to reminders, which macOS grants separately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Permissions {
+    pub events: Access,
+    pub reminders: Access,
+}
+
+/// Events over a range, with anything known to be missing from them.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EventQuery {
+    pub events: Vec<CalendarEvent>,
+    /// Why the events may be incomplete, e.g. a range shortened by the
+    /// store; empty when they are known to be complete.
+    pub gaps: Vec<String>,
+}
+
+/// A source of calendars, events and reminders.
+///
+/// [`Store`](super::Store) implements this over EventKit; other backends
+/// (in-memory demo data, test fixtures) let the UI run without macOS permissions.
+pub trait Backend {
+    /// Ask for calendar access; returns whether events can be read.
+    fn request_access(&self) -> Result<bool>;
+
+    /// Like [`request_access`](Self::request_access), calling `waiting`
+    /// regularly while a permission prompt is up, e.g. to keep a spinner
+    /// turning.
+    fn request_access_waiting(&self, waiting: &mut dyn FnMut()) -> Result<bool> {
+        let _ = waiting;
+        self.request_access()
+    }
+
+    /// Current access to events and reminders. Backends without a
+    /// permission system can read everything.
+    fn permissions(&self) -> Permissions {
+        Permissions { events: Access::Full, reminders: Access::Full }
+    }
+
+    fn calendars(&self) -> Vec<CalendarInfo>;
+
+    fn reminder_calendars(&self) -> Vec<CalendarInfo>;
+
+    /// Whether the data changed outside this app since the last call, e.g.
+    /// in Calendar.app or another instance.
+    fn take_changes(&self) -> bool {
+        false
+    }
+
+    /// Ask accounts and subscribed calendars to sync with their servers.
+    /// Returns at once; new data is reported later through [`take_changes`](Self::take_changes).
+    fn refresh_sources(&self) {}
+
+    /// A worker fetching events off the UI thread, for backends slow
+    /// enough to need one.
+    fn prefetcher(&self) -> Option<Prefetcher> {
+        None
+    }
+
+    /// Events overlapping `[start, end)`, sorted by start time.
+    fn events_in_range(&self, start: DateTime<Local>, end: DateTime<Local>) -> Vec<CalendarEvent>;
+
+    /// Like [`events_in_range`](Self::events_in_range), also reporting
+    /// what the store knows it left out.
+    fn query_events(&self, start: DateTime<Local>, end: DateTime<Local>) -> EventQuery {
+        EventQuery { events: self.events_in_range(start, end), gaps: Vec::new() }
+    }
+
+    fn events_for_date(&self, date: NaiveDate) -> Vec<CalendarEvent> {
+        let (start, end) = day_range(date);
+        self.events_in_range(start, end)
+    }
+
+    fn events_for_week(&self, date: NaiveDate) -> Vec<CalendarEvent> {
+        let (start, end) = week_range(date);
+        self.events_in_range(start, end)
+    }
+
+    fn events_for_month(&self, year: i32, month: u32) -> Vec<CalendarEvent> {
+        let (start, end) = month_range(year, month);
+        self.events_in_range(start, end)
+    }
+
+    fn create_event(&self, event: &NewEvent) -> Result<()>;
+
+    fn delete_event(&self, event_id: &str) -> Result<()>;
+
+    /// Reschedule an event by whole days, keeping its times of day.
+    fn move_event(&self, event_id: &str, days: i64) -> Result<()>;
+
+    /// Give an event new start and end times.
+    fn reschedule_event(&self, event_id: &str, start: DateTime<Local>, end: DateTime<Local>) -> Result<()>;
+
+    /// Give several events new times. Backends that can should apply all of
+    /// them or none; this default stops at the first failure.
+    fn reschedule_events(&self, changes: &[Reschedule]) -> Result<()> {
+        for change in changes {
+            self.reschedule_event(&change.event_id, change.start, change.end)?;
+        }
+        Ok(())
+    }
+
+    /// Delete several events, stopping at the first failure. Returns how many
+    /// were deleted.
+    fn delete_events(&self, event_ids: &[String]) -> Result<usize> {
+        for id in event_ids {
+            self.delete_event(id)?;
+        }
+        Ok(event_ids.len())
+    }
+
+    fn fetch_incomplete_reminders(&self) -> Vec<Reminder>;
+
+    fn fetch_completed_reminders(&self) -> Vec<Reminder>;
+
+    /// Add an incomplete reminder to the list `calendar_id`, or the default
+    /// one; returns its id.
+    fn create_reminder(&self, title: &str, due_date: Option<NaiveDate>, calendar_id: Option<&str>) -> Result<String>;
+
+    /// Flip a reminder's completion state; returns the new state.
+    fn toggle_reminder(&self, reminder_id: &str) -> Result<bool>;
+
+    /// Complete the current occurrence of a recurring reminder; returns the
+    /// due date of the next occurrence, if any.
+    fn complete_occurrence(&self, reminder_id: &str) -> Result<Option<DateTime<Local>>>;
+
+    /// Remove a reminder, including every future occurrence.
+    fn delete_reminder(&self, reminder_id: &str) -> Result<()>;
+
+    fn update_reminder(
+        &self,
+        reminder_id: &str,
+        title: &str,
+        due_date: Option<NaiveDate>,
+        priority: u8,
+        calendar_id: Option<&str>,
+    ) -> Result<()>;
+}
+
+/// Range queried by [`Backend::events_for_date`]: midnight to the next
+/// midnight, so events in the day's last second are included.
+pub fn day_range(date: NaiveDate) -> (DateTime<Local>, DateTime<Local>) {
+    let start_of_day = date.and_hms_opt(0, 0, 0).expect("valid time");
+    let next_day = date.succ_opt().unwrap_or(date).and_hms_opt(0, 0, 0).expect("valid time");
+
+    let start_dt = Local.from_local_datetime(&start_of_day).single().expect("valid");
+    let end_dt = Local.from_local_datetime(&next_day).single().expect("valid");
+    (start_dt, end_dt)
+}
+
+/// Range queried by [`Backend::events_for_week`]: the Sunday-to-Saturday
+/// week holding `date`.
+pub fn week_range(date: NaiveDate) -> (DateTime<Local>, DateTime<Local>) {
+    let days_since_sunday = date.weekday().num_days_from_sunday();
+    let week_start = date - chrono::Duration::days(days_since_sunday as i64);
+    let week_end = week_start + chrono::Duration::days(7);
+
+    let start_dt = Local.from_local_datetime(&week_start.and_hms_opt(0, 0, 0).unwrap())
+        .single().expect("valid");
+    let end_dt = Local.from_local_datetime(&week_end.and_hms_opt(0, 0, 0).unwrap())
+        .single().expect("valid");
+    (start_dt, end_dt)
+}
+
+/// Range queried by [`Backend::events_for_month`].
+pub fn month_range(year: i32, month: u32) -> (DateTime<Local>, DateTime<Local>) {
+    let start = NaiveDate::from_ymd_opt(year, month, 1).expect("valid date");
+    let end = if month == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1).expect("valid date")
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1).expect("valid date")
+    };
+
+    let start_dt = Local.from_local_datetime(&start.and_hms_opt(0, 0, 0).unwrap())
+        .single().expect("valid");
+    let end_dt = Local.from_local_datetime(&end.and_hms_opt(0, 0, 0).unwrap())
+        .single().expect("valid");
+    (start_dt, end_dt)
+}
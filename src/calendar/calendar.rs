@@ -1,10 +1,47 @@
 use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
-#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct CalendarInfo {
     pub id: String,
     pub title: String,
+    #[serde(with = "super::color")]
     pub color: Color,
     pub source: String,
 }
+
+impl CalendarInfo {
+    pub fn builder(id: impl Into<String>, title: impl Into<String>) -> CalendarInfoBuilder {
+        CalendarInfoBuilder {
+            info: CalendarInfo {
+                id: id.into(),
+                title: title.into(),
+                color: Color::White,
+                source: String::new(),
+            },
+        }
+    }
+}
+
+/// Builder for [`CalendarInfo`], for use outside this crate.
+#[derive(Debug, Clone)]
+pub struct CalendarInfoBuilder {
+    info: CalendarInfo,
+}
+
+impl CalendarInfoBuilder {
+    pub fn color(mut self, color: Color) -> Self {
+        self.info.color = color;
+        self
+    }
+
+    pub fn source(mut self, source: impl Into<String>) -> Self {
+        self.info.source = source.into();
+        self
+    }
+
+    pub fn build(self) -> CalendarInfo {
+        self.info
+    }
+}
@@ -0,0 +1,13 @@
+//! Serde helpers storing `ratatui::style::Color` as a string (`"#FF9500"`, `"Red"`).
+
+use ratatui::style::Color;
+use serde::{de, Deserialize, Deserializer, Serializer};
+
+pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(color)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+    let s = String::deserialize(deserializer)?;
+    s.parse().map_err(de::Error::custom)
+}
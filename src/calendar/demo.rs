@@ -0,0 +1,271 @@
+//! Synthetic dataset for `--demo`: enough variety to exercise every view
+//! without a real calendar or EventKit permissions.
+
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, TimeZone, Weekday};
+use ratatui::style::Color;
+
+use super::calendar::CalendarInfo;
+use super::event::{CalendarEvent, EventOrigin};
+use super::memory::MemoryBackend;
+use super::recurrence::{DayOfWeek, Frequency, Recurrence};
+use super::reminder::Reminder;
+
+/// Weeks of recurring events generated on either side of today.
+const DEMO_WEEKS: i64 = 10;
+
+/// Day offset from today, title, calendar index, start, end, location.
+type OneOff = (i64, &'static str, usize, (u32, u32), (u32, u32), Option<&'static str>);
+
+/// Due offset from today, title, list index, priority, completed, recurring.
+type DemoReminder = (Option<i64>, &'static str, usize, u8, bool, bool);
+
+struct Recurring {
+    title: &'static str,
+    calendar: usize,
+    weekdays: &'static [Weekday],
+    start: (u32, u32),
+    end: (u32, u32),
+    location: Option<&'static str>,
+    /// Only every n-th week (1 = weekly).
+    every_weeks: i64,
+}
+
+impl Recurring {
+    fn rule(&self) -> Recurrence {
+        let mut rule = Recurrence::new(Frequency::Weekly);
+        rule.interval = self.every_weeks as u32;
+        rule.by_day = self
+            .weekdays
+            .iter()
+            .map(|&weekday| DayOfWeek { weekday, nth: None })
+            .collect();
+        rule
+    }
+}
+
+const RECURRING: &[Recurring] = &[
+    Recurring {
+        title: "Standup",
+        calendar: 0,
+        weekdays: &[Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri],
+        start: (9, 30),
+        end: (9, 45),
+        location: Some("Zoom"),
+        every_weeks: 1,
+    },
+    Recurring {
+        title: "Design review",
+        calendar: 0,
+        weekdays: &[Weekday::Tue],
+        start: (14, 0),
+        end: (15, 0),
+        location: Some("Room 4B"),
+        every_weeks: 1,
+    },
+    Recurring {
+        title: "1:1 with Sam",
+        calendar: 0,
+        weekdays: &[Weekday::Thu],
+        start: (11, 0),
+        end: (11, 30),
+        location: None,
+        every_weeks: 1,
+    },
+    Recurring {
+        title: "Sprint retro",
+        calendar: 0,
+        weekdays: &[Weekday::Fri],
+        start: (16, 0),
+        end: (17, 0),
+        location: Some("Room 2A"),
+        every_weeks: 2,
+    },
+    Recurring {
+        title: "Architecture sync",
+        calendar: 0,
+        weekdays: &[Weekday::Wed],
+        start: (13, 0),
+        end: (14, 30),
+        location: None,
+        every_weeks: 1,
+    },
+    // Overlaps the tail of "Architecture sync".
+    Recurring {
+        title: "Customer call",
+        calendar: 0,
+        weekdays: &[Weekday::Wed],
+        start: (14, 0),
+        end: (15, 0),
+        location: Some("Phone"),
+        every_weeks: 1,
+    },
+    Recurring {
+        title: "Gym",
+        calendar: 1,
+        weekdays: &[Weekday::Tue, Weekday::Thu],
+        start: (18, 0),
+        end: (19, 0),
+        location: Some("Downtown Fitness"),
+        every_weeks: 1,
+    },
+    Recurring {
+        title: "Piano lesson",
+        calendar: 2,
+        weekdays: &[Weekday::Sat],
+        start: (10, 0),
+        end: (11, 0),
+        location: None,
+        every_weeks: 1,
+    },
+];
+
+/// Build a [`MemoryBackend`] filled with demo data around `today`.
+pub fn demo_backend(today: NaiveDate) -> MemoryBackend {
+    let calendars = vec![
+        CalendarInfo::builder("demo-work", "Work").color(Color::Rgb(0, 122, 255)).source("Exchange").build(),
+        CalendarInfo::builder("demo-personal", "Personal").color(Color::Rgb(52, 199, 89)).source("iCloud").build(),
+        CalendarInfo::builder("demo-family", "Family").color(Color::Rgb(255, 149, 0)).source("iCloud").build(),
+        CalendarInfo::builder("demo-birthdays", "Birthdays").color(Color::Rgb(175, 82, 222)).source("iCloud").build(),
+    ];
+    let reminder_lists = vec![
+        CalendarInfo::builder("demo-inbox", "Inbox").color(Color::Rgb(0, 122, 255)).source("iCloud").build(),
+        CalendarInfo::builder("demo-errands", "Errands").color(Color::Rgb(255, 59, 48)).source("iCloud").build(),
+    ];
+
+    let backend = MemoryBackend::new().with_calendars(calendars.clone(), reminder_lists.clone());
+    let mut next_id = 0;
+    let mut id = || {
+        next_id += 1;
+        format!("demo-{}", next_id)
+    };
+
+    // Recurring meetings, expanded week by week.
+    let week_start = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+    for week in -DEMO_WEEKS..=DEMO_WEEKS {
+        let monday = week_start + Duration::weeks(week);
+        for rec in RECURRING {
+            if week.rem_euclid(rec.every_weeks) != 0 {
+                continue;
+            }
+            for weekday in rec.weekdays {
+                let date = monday + Duration::days(weekday.num_days_from_monday() as i64);
+                let cal = &calendars[rec.calendar];
+                let mut builder = CalendarEvent::builder(
+                    id(),
+                    rec.title,
+                    at(date, rec.start.0, rec.start.1),
+                    at(date, rec.end.0, rec.end.1),
+                )
+                .calendar(cal.id.clone(), cal.title.clone(), cal.color)
+                .recurrence(rec.rule());
+                if let Some(location) = rec.location {
+                    builder = builder.location(location);
+                }
+                backend.add_event(builder.build());
+            }
+        }
+    }
+
+    // One-off timed events, including a back-to-back afternoon.
+    let one_off: &[OneOff] = &[
+        (0, "Lunch with Alex", 1, (12, 0), (13, 0), Some("Blue Bottle Cafe")),
+        (0, "Quarterly planning", 0, (15, 30), (17, 0), Some("Board room")),
+        (0, "Interview: backend", 0, (16, 0), (17, 0), None),
+        (1, "Dentist", 1, (8, 0), (8, 45), Some("Smile Dental")),
+        // Same appointment synced into a second calendar, for `:duplicates`
+        (1, "Dentist", 2, (8, 0), (8, 45), Some("Smile Dental")),
+        (2, "Release cut", 0, (10, 0), (10, 30), None),
+        (3, "Parent-teacher meeting", 2, (17, 30), (18, 15), Some("Lincoln Elementary")),
+        (-2, "Movie night", 2, (19, 30), (22, 0), None),
+        (5, "Flight to Berlin", 1, (7, 15), (10, 40), Some("SFO Terminal 2")),
+    ];
+    for &(offset, title, cal, start, end, location) in one_off {
+        let date = today + Duration::days(offset);
+        let cal = &calendars[cal];
+        let mut builder = CalendarEvent::builder(id(), title, at(date, start.0, start.1), at(date, end.0, end.1))
+            .calendar(cal.id.clone(), cal.title.clone(), cal.color)
+            .notes("Demo event");
+        if let Some(location) = location {
+            builder = builder.location(location);
+        }
+        builder = match title {
+            "Quarterly planning" => builder.origin(EventOrigin::Invitation { organizer: "Priya Shah".to_string() }),
+            "Flight to Berlin" => builder.origin(EventOrigin::Suggestion),
+            _ => builder,
+        };
+        backend.add_event(builder.build());
+    }
+
+    // All-day and multi-day events.
+    let all_day: &[(i64, i64, &str, usize)] = &[
+        (1, 1, "Team offsite", 0),
+        (6, 3, "RustConf", 0),
+        (-4, 1, "Farmers market", 2),
+        (12, 2, "Camping trip", 2),
+    ];
+    for &(offset, days, title, cal) in all_day {
+        let date = today + Duration::days(offset);
+        let cal = &calendars[cal];
+        backend.add_event(
+            CalendarEvent::builder(id(), title, at(date, 0, 0), at(date + Duration::days(days), 0, 0))
+                .all_day(true)
+                .calendar(cal.id.clone(), cal.title.clone(), cal.color)
+                .build(),
+        );
+    }
+
+    // Birthdays and anniversaries carry an origin year like EventKit's Birthdays calendar.
+    let birthdays: &[(i64, &str, i32)] = &[
+        (3, "Maya's Birthday", 1991),
+        (9, "Mom & Dad's Anniversary", 1985),
+        (-6, "Jordan's Birthday", 2015),
+    ];
+    let cal = &calendars[3];
+    for &(offset, title, year) in birthdays {
+        let date = today + Duration::days(offset);
+        backend.add_event(
+            CalendarEvent::builder(id(), title, at(date, 0, 0), at(date + Duration::days(1), 0, 0))
+                .all_day(true)
+                .calendar(cal.id.clone(), cal.title.clone(), cal.color)
+                .origin_year(year)
+                .origin(EventOrigin::Birthday)
+                .build(),
+        );
+    }
+
+    // Reminders: due today, overdue, upcoming, undated and completed.
+    let reminders: &[DemoReminder] = &[
+        (Some(0), "Submit expense report", 0, 1, false, false),
+        (Some(0), "Review pull requests", 0, 5, false, false),
+        (Some(0), "Water the plants", 1, 0, true, false),
+        (Some(0), "Take out recycling", 1, 0, false, true),
+        (Some(-1), "Renew passport", 0, 1, false, false),
+        (Some(1), "Buy groceries", 1, 9, false, false),
+        (Some(2), "Call the plumber", 1, 5, false, false),
+        (Some(4), "Book hotel for RustConf", 0, 0, false, false),
+        (None, "Read \"Designing Data-Intensive Applications\"", 0, 9, false, false),
+        (Some(-3), "Pick up dry cleaning", 1, 0, true, false),
+    ];
+    for &(due, title, list, priority, completed, recurring) in reminders {
+        let list = &reminder_lists[list];
+        let mut builder = Reminder::builder(id(), title)
+            .calendar(list.id.clone(), list.title.clone(), list.color)
+            .priority(priority)
+            .completed(completed)
+            .recurring(recurring);
+        if let Some(offset) = due {
+            builder = builder.due_date(at(today + Duration::days(offset), 9, 0));
+        }
+        backend.add_reminder(builder.build());
+    }
+
+    backend
+}
+
+fn at(date: NaiveDate, hour: u32, minute: u32) -> DateTime<Local> {
+    let naive = date.and_hms_opt(hour, minute, 0).expect("valid time");
+    Local
+        .from_local_datetime(&naive)
+        .earliest()
+        .unwrap_or_else(|| Local.from_utc_datetime(&naive))
+}
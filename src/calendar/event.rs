@@ -1,28 +1,412 @@
-use chrono::{DateTime, Local};
+use chrono::{DateTime, Datelike, Local, NaiveDate, NaiveTime};
 use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
-#[allow(dead_code)]
+use super::recurrence::Recurrence;
+use super::time::format_time;
+use super::video::{self, VideoLink};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct CalendarEvent {
     pub id: String,
     pub title: String,
     pub start: DateTime<Local>,
     pub end: DateTime<Local>,
     pub is_all_day: bool,
+    pub calendar_id: String,
     pub calendar_name: String,
+    #[serde(with = "super::color")]
     pub calendar_color: Color,
     pub location: Option<String>,
+    /// The event's URL field, often a meeting link.
+    #[serde(default)]
+    pub url: Option<String>,
     pub notes: Option<String>,
+    /// Year of the first occurrence for Birthdays-calendar events (birth or wedding year).
+    pub origin_year: Option<i32>,
+    /// Where the event came from, as far as EventKit can tell.
+    #[serde(default)]
+    pub origin: EventOrigin,
+    /// You were invited and declined.
+    #[serde(default)]
+    pub declined: bool,
+    /// Recurrence rule of the series this occurrence belongs to.
+    #[serde(default)]
+    pub recurrence: Option<Recurrence>,
+    /// Who sent the invitation; `None` for events without invitees.
+    #[serde(default)]
+    pub organizer: Option<Participant>,
+    /// Everyone invited, including you.
+    #[serde(default)]
+    pub attendees: Vec<Participant>,
+    /// iCalendar UID shared with the other attendees' copies of the event.
+    #[serde(default)]
+    pub uid: Option<String>,
+    /// Start this occurrence of a repeating series was scheduled for, which
+    /// stays put when the occurrence alone is moved. iCalendar names the
+    /// occurrence by it (`RECURRENCE-ID`).
+    #[serde(default)]
+    pub occurrence_start: Option<DateTime<Local>>,
+}
+
+/// An organizer or attendee of an event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Participant {
+    /// Display name, or the email address when there is none.
+    pub name: String,
+    pub email: Option<String>,
+    #[serde(default)]
+    pub status: ParticipantStatus,
+    /// This participant is you.
+    #[serde(default)]
+    pub is_me: bool,
+}
+
+/// Reply of an attendee to an invitation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ParticipantStatus {
+    #[default]
+    Pending,
+    Accepted,
+    Declined,
+    Tentative,
+}
+
+impl ParticipantStatus {
+    /// "✓", "✗" or "?" for a reply still open or tentative.
+    pub fn glyph(self) -> &'static str {
+        match self {
+            ParticipantStatus::Accepted => "\u{2713}",
+            ParticipantStatus::Declined => "\u{2717}",
+            ParticipantStatus::Pending | ParticipantStatus::Tentative => "?",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ParticipantStatus::Pending => "no reply",
+            ParticipantStatus::Accepted => "accepted",
+            ParticipantStatus::Declined => "declined",
+            ParticipantStatus::Tentative => "maybe",
+        }
+    }
+}
+
+/// How an event ended up in the calendar.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum EventOrigin {
+    /// No organizer or other marker: added directly to one of your calendars.
+    #[default]
+    UserCreated,
+    /// You organized it and invited others.
+    Organized,
+    /// An invitation from someone else.
+    Invitation { organizer: String },
+    /// From a subscribed (read-only) calendar.
+    Subscription,
+    /// Generated from Contacts birthdays and anniversaries.
+    Birthday,
+    /// Found by Siri in Mail, Messages and other apps. Only recognized
+    /// with the system in English.
+    Suggestion,
+}
+
+impl EventOrigin {
+    pub fn label(&self) -> String {
+        match self {
+            EventOrigin::UserCreated => "Created by you".to_string(),
+            EventOrigin::Organized => "Your invitation".to_string(),
+            EventOrigin::Invitation { organizer } if organizer.is_empty() => "Invitation".to_string(),
+            EventOrigin::Invitation { organizer } => format!("Invitation from {}", organizer),
+            EventOrigin::Subscription => "Subscribed calendar".to_string(),
+            EventOrigin::Birthday => "Contacts".to_string(),
+            EventOrigin::Suggestion => "Siri Suggestions".to_string(),
+        }
+    }
 }
 
 impl CalendarEvent {
+    pub fn builder(
+        id: impl Into<String>,
+        title: impl Into<String>,
+        start: DateTime<Local>,
+        end: DateTime<Local>,
+    ) -> CalendarEventBuilder {
+        CalendarEventBuilder {
+            event: CalendarEvent {
+                id: id.into(),
+                title: title.into(),
+                start,
+                end,
+                is_all_day: false,
+                calendar_id: String::new(),
+                calendar_name: "Unknown".to_string(),
+                calendar_color: Color::White,
+                location: None,
+                url: None,
+                notes: None,
+                origin_year: None,
+                origin: EventOrigin::UserCreated,
+                declined: false,
+                recurrence: None,
+                organizer: None,
+                attendees: Vec::new(),
+                uid: None,
+                occurrence_start: None,
+            },
+        }
+    }
+
     pub fn duration_display(&self) -> String {
         if self.is_all_day {
             "All day".to_string()
         } else {
-            let start = self.start.format("%H:%M");
-            let end = self.end.format("%H:%M");
-            format!("{} - {}", start, end)
+            format!("{} - {}", format_time(self.start), format_time(self.end))
+        }
+    }
+
+    /// Last calendar day the event covers. End times are exclusive, so an
+    /// event ending at midnight does not spill into the next day.
+    pub fn last_day(&self) -> NaiveDate {
+        if self.end > self.start {
+            (self.end - chrono::Duration::seconds(1)).date_naive()
+        } else {
+            self.start.date_naive()
+        }
+    }
+
+    pub fn spans_days(&self) -> bool {
+        self.last_day() > self.start.date_naive()
+    }
+
+    pub fn occurs_on(&self, date: NaiveDate) -> bool {
+        self.start.date_naive() <= date && date <= self.last_day()
+    }
+
+    /// Time range as seen from `date`, e.g. "14:00 - …" on the first day of
+    /// an event that continues overnight.
+    pub fn time_range_on(&self, date: NaiveDate) -> String {
+        if self.is_all_day {
+            return "All day".to_string();
+        }
+        let starts = self.start.date_naive() == date;
+        let ends = self.last_day() == date;
+        match (starts, ends) {
+            (true, true) => self.duration_display(),
+            (true, false) => format!("{} - \u{2026}", format_time(self.start)),
+            (false, true) => format!("\u{2026} - {}", format_time(self.end)),
+            (false, false) => "All day".to_string(),
+        }
+    }
+
+    /// "day 2/3" for events spanning several days.
+    pub fn day_of_span(&self, date: NaiveDate) -> Option<String> {
+        if !self.spans_days() {
+            return None;
         }
+        let first = self.start.date_naive();
+        let n = (date - first).num_days() + 1;
+        let total = (self.last_day() - first).num_days() + 1;
+        Some(format!("day {}/{}", n, total))
+    }
+
+    /// Number of years since the original date, for birthday/anniversary events.
+    pub fn anniversary_count(&self) -> Option<i32> {
+        self.origin_year
+            .map(|year| self.start.year() - year)
+            .filter(|&n| n > 0)
+    }
+
+    /// Whether a Birthdays-calendar event is an anniversary rather than a birthday.
+    pub fn is_anniversary(&self) -> bool {
+        self.title.to_lowercase().contains("anniversary")
+    }
+
+    /// Short label such as "turns 34" or "10th anniversary".
+    pub fn anniversary_label(&self) -> Option<String> {
+        let n = self.anniversary_count()?;
+        if self.is_anniversary() {
+            Some(format!("{} anniversary", ordinal(n)))
+        } else {
+            Some(format!("turns {}", n))
+        }
+    }
+
+    /// Your reply, when you are among the attendees.
+    pub fn my_status(&self) -> Option<ParticipantStatus> {
+        self.attendees.iter().find(|a| a.is_me).map(|a| a.status)
+    }
+
+    /// "↻" for occurrences of a repeating series.
+    pub fn repeat_marker(&self) -> Option<&'static str> {
+        self.recurrence.as_ref().map(|_| "\u{21bb}")
     }
+
+    /// The first video-call link in the URL, location or notes.
+    pub fn video_link(&self) -> Option<VideoLink> {
+        [&self.url, &self.location, &self.notes]
+            .into_iter()
+            .flatten()
+            .find_map(|text| video::find_link(text))
+    }
+
+    /// Fields passed to custom actions as `CAL_*` environment variables.
+    pub fn env_vars(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("CAL_KIND", "event".to_string()),
+            ("CAL_ID", self.id.clone()),
+            ("CAL_TITLE", self.title.clone()),
+            ("CAL_START", self.start.to_rfc3339()),
+            ("CAL_END", self.end.to_rfc3339()),
+            ("CAL_ALL_DAY", if self.is_all_day { "1" } else { "0" }.to_string()),
+            ("CAL_CALENDAR", self.calendar_name.clone()),
+            ("CAL_LOCATION", self.location.clone().unwrap_or_default()),
+            ("CAL_URL", self.url.clone().unwrap_or_default()),
+            ("CAL_NOTES", self.notes.clone().unwrap_or_default()),
+        ]
+    }
+
+    /// An invitation from someone else that you can reply to.
+    pub fn awaits_reply(&self) -> bool {
+        self.my_status().is_some() && !self.organizer.as_ref().is_some_and(|o| o.is_me)
+    }
+
+    /// A new event with the same times, calendar, location and notes,
+    /// moved to start on `date`.
+    pub fn copy_to(&self, date: NaiveDate) -> NewEvent {
+        let shift = date - self.start.date_naive();
+        let start = self.start.naive_local() + shift;
+        let end = self.end.naive_local() + shift;
+        NewEvent {
+            title: self.title.clone(),
+            date,
+            start_time: start.time(),
+            end_date: if self.is_all_day { self.last_day() + shift } else { end.date() },
+            end_time: end.time(),
+            is_all_day: self.is_all_day,
+            calendar_id: Some(self.calendar_id.clone()),
+            location: self.location.clone(),
+            url: self.url.clone(),
+            notes: self.notes.clone(),
+            alarm_minutes: None,
+            busy: false,
+        }
+    }
+}
+
+pub(crate) fn ordinal(n: i32) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+/// Builder for [`CalendarEvent`], for use outside this crate.
+#[derive(Debug, Clone)]
+pub struct CalendarEventBuilder {
+    event: CalendarEvent,
+}
+
+impl CalendarEventBuilder {
+    pub fn all_day(mut self, is_all_day: bool) -> Self {
+        self.event.is_all_day = is_all_day;
+        self
+    }
+
+    pub fn calendar(
+        mut self,
+        id: impl Into<String>,
+        name: impl Into<String>,
+        color: Color,
+    ) -> Self {
+        self.event.calendar_id = id.into();
+        self.event.calendar_name = name.into();
+        self.event.calendar_color = color;
+        self
+    }
+
+    pub fn location(mut self, location: impl Into<String>) -> Self {
+        self.event.location = Some(location.into());
+        self
+    }
+
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.event.url = Some(url.into());
+        self
+    }
+
+    pub fn notes(mut self, notes: impl Into<String>) -> Self {
+        self.event.notes = Some(notes.into());
+        self
+    }
+
+    pub fn origin_year(mut self, year: i32) -> Self {
+        self.event.origin_year = Some(year);
+        self
+    }
+
+    pub fn origin(mut self, origin: EventOrigin) -> Self {
+        self.event.origin = origin;
+        self
+    }
+
+    pub fn declined(mut self, declined: bool) -> Self {
+        self.event.declined = declined;
+        self
+    }
+
+    pub fn recurrence(mut self, recurrence: Recurrence) -> Self {
+        self.event.recurrence = Some(recurrence);
+        self
+    }
+
+    pub fn organizer(mut self, organizer: Participant) -> Self {
+        self.event.organizer = Some(organizer);
+        self
+    }
+
+    pub fn attendees(mut self, attendees: Vec<Participant>) -> Self {
+        self.event.attendees = attendees;
+        self
+    }
+
+    pub fn uid(mut self, uid: impl Into<String>) -> Self {
+        self.event.uid = Some(uid.into());
+        self
+    }
+
+    pub fn occurrence_start(mut self, start: DateTime<Local>) -> Self {
+        self.event.occurrence_start = Some(start);
+        self
+    }
+
+    pub fn build(self) -> CalendarEvent {
+        self.event
+    }
+}
+
+/// Fields for an event about to be saved, as collected by the event form.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewEvent {
+    pub title: String,
+    pub date: NaiveDate,
+    pub start_time: NaiveTime,
+    /// Last day of the event; equal to `date` for single-day events.
+    pub end_date: NaiveDate,
+    pub end_time: NaiveTime,
+    pub is_all_day: bool,
+    pub calendar_id: Option<String>,
+    pub location: Option<String>,
+    pub url: Option<String>,
+    pub notes: Option<String>,
+    /// Minutes before the start to fire an alert; `Some(0)` alerts at start.
+    pub alarm_minutes: Option<u32>,
+    /// Show the time as busy, which all-day events otherwise are not.
+    pub busy: bool,
 }
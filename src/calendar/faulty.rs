@@ -0,0 +1,232 @@
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Local, NaiveDate};
+use color_eyre::eyre::{eyre, Result};
+
+use super::backend::{Access, Backend, EventQuery, Permissions, Reschedule};
+use super::calendar::CalendarInfo;
+use super::event::{CalendarEvent, NewEvent};
+use super::reminder::Reminder;
+
+/// Faults injected by a [`FaultyBackend`], adjustable while the app runs.
+#[derive(Debug, Default)]
+pub struct Faults {
+    latency_ms: AtomicU64,
+    fail_every: AtomicUsize,
+    writes: AtomicUsize,
+    revoked: AtomicBool,
+    changed: AtomicBool,
+}
+
+impl Faults {
+    /// Delay every call by `latency`, as EventKit does on a busy database.
+    pub fn set_latency(&self, latency: Duration) {
+        self.latency_ms.store(latency.as_millis() as u64, Ordering::Relaxed);
+    }
+
+    /// Fail every `n`th write; 0 lets every write through.
+    pub fn fail_every(&self, n: usize) {
+        self.fail_every.store(n, Ordering::Relaxed);
+    }
+
+    /// Withdraw access, as turning it off in System Settings does. Reads
+    /// come back empty and writes fail until [`restore`](Self::restore).
+    pub fn revoke(&self) {
+        self.revoked.store(true, Ordering::Relaxed);
+        self.changed.store(true, Ordering::Relaxed);
+    }
+
+    pub fn restore(&self) {
+        self.revoked.store(false, Ordering::Relaxed);
+        self.changed.store(true, Ordering::Relaxed);
+    }
+
+    /// Writes attempted so far, failed ones included.
+    pub fn writes(&self) -> usize {
+        self.writes.load(Ordering::Relaxed)
+    }
+
+    fn revoked(&self) -> bool {
+        self.revoked.load(Ordering::Relaxed)
+    }
+
+    fn wait(&self) {
+        let ms = self.latency_ms.load(Ordering::Relaxed);
+        if ms > 0 {
+            std::thread::sleep(Duration::from_millis(ms));
+        }
+    }
+
+    fn write(&self) -> Result<()> {
+        self.wait();
+        let n = self.writes.fetch_add(1, Ordering::Relaxed) + 1;
+        if self.revoked() {
+            return Err(eyre!("Calendar access was revoked"));
+        }
+        let every = self.fail_every.load(Ordering::Relaxed);
+        if every > 0 && n.is_multiple_of(every) {
+            return Err(eyre!("Injected failure on write {}", n));
+        }
+        Ok(())
+    }
+
+    /// `value` after the latency, or the empty default while revoked.
+    fn read<T: Default>(&self, value: impl FnOnce() -> T) -> T {
+        self.wait();
+        if self.revoked() {
+            T::default()
+        } else {
+            value()
+        }
+    }
+}
+
+/// A [`Backend`] wrapper that slows down and breaks another backend, for
+/// testing loading states, retries and error messages.
+pub struct FaultyBackend<B> {
+    inner: B,
+    faults: Arc<Faults>,
+}
+
+impl<B: Backend> FaultyBackend<B> {
+    pub fn new(inner: B) -> Self {
+        Self { inner, faults: Arc::default() }
+    }
+
+    /// Handle for changing the faults after the backend is handed over.
+    pub fn faults(&self) -> Arc<Faults> {
+        Arc::clone(&self.faults)
+    }
+}
+
+impl<B: Backend> Backend for FaultyBackend<B> {
+    fn request_access(&self) -> Result<bool> {
+        self.faults.wait();
+        Ok(!self.faults.revoked() && self.inner.request_access()?)
+    }
+
+    /// Keeps calling `waiting` through the latency, like a slow permission
+    /// prompt.
+    fn request_access_waiting(&self, waiting: &mut dyn FnMut()) -> Result<bool> {
+        let step = Duration::from_millis(100);
+        let mut left = Duration::from_millis(self.faults.latency_ms.load(Ordering::Relaxed));
+        while !left.is_zero() {
+            waiting();
+            let nap = left.min(step);
+            std::thread::sleep(nap);
+            left -= nap;
+        }
+        Ok(!self.faults.revoked() && self.inner.request_access_waiting(waiting)?)
+    }
+
+    fn permissions(&self) -> Permissions {
+        if self.faults.revoked() {
+            Permissions { events: Access::Denied, reminders: Access::Denied }
+        } else {
+            self.inner.permissions()
+        }
+    }
+
+    fn calendars(&self) -> Vec<CalendarInfo> {
+        self.faults.read(|| self.inner.calendars())
+    }
+
+    fn reminder_calendars(&self) -> Vec<CalendarInfo> {
+        self.faults.read(|| self.inner.reminder_calendars())
+    }
+
+    /// Revoking or restoring access counts as a change, as EventKit reports
+    /// one then too.
+    fn take_changes(&self) -> bool {
+        let revocation = self.faults.changed.swap(false, Ordering::Relaxed);
+        self.inner.take_changes() || revocation
+    }
+
+    fn refresh_sources(&self) {
+        self.inner.refresh_sources()
+    }
+
+    fn events_in_range(&self, start: DateTime<Local>, end: DateTime<Local>) -> Vec<CalendarEvent> {
+        self.faults.read(|| self.inner.events_in_range(start, end))
+    }
+
+    fn query_events(&self, start: DateTime<Local>, end: DateTime<Local>) -> EventQuery {
+        if self.faults.revoked() {
+            self.faults.wait();
+            return EventQuery { events: Vec::new(), gaps: vec!["Calendar access was revoked".to_string()] };
+        }
+        self.faults.read(|| self.inner.query_events(start, end))
+    }
+
+    fn create_event(&self, event: &NewEvent) -> Result<()> {
+        self.faults.write()?;
+        self.inner.create_event(event)
+    }
+
+    fn delete_event(&self, event_id: &str) -> Result<()> {
+        self.faults.write()?;
+        self.inner.delete_event(event_id)
+    }
+
+    fn move_event(&self, event_id: &str, days: i64) -> Result<()> {
+        self.faults.write()?;
+        self.inner.move_event(event_id, days)
+    }
+
+    fn reschedule_event(&self, event_id: &str, start: DateTime<Local>, end: DateTime<Local>) -> Result<()> {
+        self.faults.write()?;
+        self.inner.reschedule_event(event_id, start, end)
+    }
+
+    fn reschedule_events(&self, changes: &[Reschedule]) -> Result<()> {
+        self.faults.write()?;
+        self.inner.reschedule_events(changes)
+    }
+
+    fn delete_events(&self, event_ids: &[String]) -> Result<usize> {
+        self.faults.write()?;
+        self.inner.delete_events(event_ids)
+    }
+
+    fn fetch_incomplete_reminders(&self) -> Vec<Reminder> {
+        self.faults.read(|| self.inner.fetch_incomplete_reminders())
+    }
+
+    fn fetch_completed_reminders(&self) -> Vec<Reminder> {
+        self.faults.read(|| self.inner.fetch_completed_reminders())
+    }
+
+    fn create_reminder(&self, title: &str, due_date: Option<NaiveDate>, calendar_id: Option<&str>) -> Result<String> {
+        self.faults.write()?;
+        self.inner.create_reminder(title, due_date, calendar_id)
+    }
+
+    fn toggle_reminder(&self, reminder_id: &str) -> Result<bool> {
+        self.faults.write()?;
+        self.inner.toggle_reminder(reminder_id)
+    }
+
+    fn complete_occurrence(&self, reminder_id: &str) -> Result<Option<DateTime<Local>>> {
+        self.faults.write()?;
+        self.inner.complete_occurrence(reminder_id)
+    }
+
+    fn delete_reminder(&self, reminder_id: &str) -> Result<()> {
+        self.faults.write()?;
+        self.inner.delete_reminder(reminder_id)
+    }
+
+    fn update_reminder(
+        &self,
+        reminder_id: &str,
+        title: &str,
+        due_date: Option<NaiveDate>,
+        priority: u8,
+        calendar_id: Option<&str>,
+    ) -> Result<()> {
+        self.faults.write()?;
+        self.inner.update_reminder(reminder_id, title, due_date, priority, calendar_id)
+    }
+}
@@ -0,0 +1,507 @@
+//! Minimal iCalendar (RFC 5545) support: a reader for local `.ics` files,
+//! used when EventKit is not available, and a writer for invites.
+//!
+//! Only VEVENT components are read. Times with a TZID are taken as local
+//! time, and recurring events show their first occurrence only.
+
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use color_eyre::eyre::{eyre, Result};
+use ratatui::style::Color;
+
+use super::calendar::CalendarInfo;
+use super::event::{CalendarEvent, NewEvent, Participant, ParticipantStatus};
+use super::memory::MemoryBackend;
+use super::recurrence::Recurrence;
+use crate::config;
+
+/// Colors handed out to local calendars in file order.
+const PALETTE: [Color; 6] = [
+    Color::Rgb(0, 122, 255),
+    Color::Rgb(52, 199, 89),
+    Color::Rgb(255, 149, 0),
+    Color::Rgb(175, 82, 222),
+    Color::Rgb(255, 59, 48),
+    Color::Rgb(90, 200, 250),
+];
+
+/// Contents of one `.ics` file.
+#[derive(Debug, Clone, Default)]
+pub struct IcsCalendar {
+    /// `X-WR-CALNAME`, if the file names itself.
+    pub name: Option<String>,
+    /// Events without calendar fields; the caller assigns those.
+    pub events: Vec<CalendarEvent>,
+}
+
+/// Parse iCalendar text. Events missing a start are skipped.
+pub fn parse(text: &str) -> Result<IcsCalendar> {
+    let mut calendar = IcsCalendar::default();
+    let mut current: Option<Vec<(String, String, String)>> = None;
+
+    for line in unfold(text) {
+        let (name, params, value) = split_property(&line)?;
+        match (name.as_str(), value.as_str()) {
+            ("BEGIN", "VEVENT") => current = Some(Vec::new()),
+            ("END", "VEVENT") => {
+                if let Some(props) = current.take() {
+                    if let Some(ev) = build_event(&props, calendar.events.len()) {
+                        calendar.events.push(ev);
+                    }
+                }
+            }
+            ("X-WR-CALNAME", _) if current.is_none() => calendar.name = Some(unescape(&value)),
+            _ => {
+                if let Some(props) = current.as_mut() {
+                    props.push((name, params, value));
+                }
+            }
+        }
+    }
+    Ok(calendar)
+}
+
+/// Directory scanned for local calendars (`~/.config/calendar-tui/ics`).
+pub fn local_dir() -> Option<PathBuf> {
+    config::config_dir().map(|d| d.join("ics"))
+}
+
+/// Directory exported invites are written to (`~/.config/calendar-tui/invites`).
+pub fn invites_dir() -> Option<PathBuf> {
+    config::config_dir().map(|d| d.join("invites"))
+}
+
+/// Directory exported calendars are written to (`~/.config/calendar-tui/exports`).
+pub fn exports_dir() -> Option<PathBuf> {
+    config::config_dir().map(|d| d.join("exports"))
+}
+
+/// A read-only backend holding every `.ics` file in [`local_dir`], one
+/// calendar per file. A missing directory gives an empty backend.
+pub fn local_backend() -> Result<MemoryBackend> {
+    let mut files: Vec<PathBuf> = match local_dir().map(std::fs::read_dir) {
+        Some(Ok(entries)) => entries
+            .filter_map(|e| e.ok().map(|e| e.path()))
+            .filter(|p| p.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("ics")))
+            .collect(),
+        _ => Vec::new(),
+    };
+    files.sort();
+
+    let mut calendars = Vec::new();
+    let mut events = Vec::new();
+    for (i, path) in files.iter().enumerate() {
+        let parsed = load_file(path)?;
+        let title = parsed.name.unwrap_or_else(|| file_stem(path));
+        let info = CalendarInfo::builder(format!("ics-{}", i), title)
+            .color(PALETTE[i % PALETTE.len()])
+            .source("Local")
+            .build();
+        for mut ev in parsed.events {
+            ev.id = format!("{}-{}", info.id, ev.id);
+            ev.calendar_id = info.id.clone();
+            ev.calendar_name = info.title.clone();
+            ev.calendar_color = info.color;
+            events.push(ev);
+        }
+        calendars.push(info);
+    }
+
+    let backend = MemoryBackend::new().with_calendars(calendars, Vec::new()).read_only();
+    for ev in events {
+        backend.add_event(ev);
+    }
+    Ok(backend)
+}
+
+/// A `METHOD:REQUEST` invite for `event`, suitable for attaching to an email.
+/// Timed events are written in UTC; all-day events as dates with an
+/// exclusive end.
+pub fn invite(
+    event: &NewEvent,
+    uid: &str,
+    organizer: Option<&str>,
+    attendees: &[String],
+    stamp: DateTime<Utc>,
+) -> String {
+    let utc = |date: NaiveDate, time| {
+        let local = Local.from_local_datetime(&date.and_time(time)).earliest();
+        local.map(|t| t.with_timezone(&Utc)).unwrap_or_else(|| date.and_time(time).and_utc())
+    };
+    let span = if event.is_all_day {
+        Span::Days(event.date, event.end_date)
+    } else {
+        Span::Times(utc(event.date, event.start_time), utc(event.end_date, event.end_time))
+    };
+
+    let mut lines = vevent(uid, stamp, span, None, &event.title);
+    if let Some(location) = &event.location {
+        lines.push(format!("LOCATION:{}", escape(location)));
+    }
+    if let Some(url) = &event.url {
+        lines.push(format!("URL:{}", url));
+    }
+    if let Some(notes) = &event.notes {
+        lines.push(format!("DESCRIPTION:{}", escape(notes)));
+    }
+    if let Some(organizer) = organizer {
+        lines.push(format!("ORGANIZER:mailto:{}", organizer));
+    }
+    for attendee in attendees {
+        lines.push(format!(
+            "ATTENDEE;ROLE=REQ-PARTICIPANT;PARTSTAT=NEEDS-ACTION;RSVP=TRUE:mailto:{}",
+            attendee
+        ));
+    }
+    lines.push("STATUS:CONFIRMED".to_string());
+    lines.push("END:VEVENT".to_string());
+
+    calendar("REQUEST", lines)
+}
+
+/// A `METHOD:REPLY` answering the invitation `event` as `me`, for sending
+/// back to the organizer; for an occurrence of a repeating event, to that
+/// occurrence only. Fails when the event has no UID or the organizer
+/// has no email address, since the reply could not be matched or delivered.
+pub fn reply(
+    event: &CalendarEvent,
+    me: &Participant,
+    status: ParticipantStatus,
+    stamp: DateTime<Utc>,
+) -> Result<String> {
+    let uid = event.uid.as_deref().ok_or_else(|| eyre!("Event has no UID to reply to"))?;
+    let organizer = event
+        .organizer
+        .as_ref()
+        .and_then(|o| o.email.as_deref())
+        .ok_or_else(|| eyre!("Organizer has no email address"))?;
+    let my_email = me.email.as_deref().ok_or_else(|| eyre!("Your attendee entry has no email address"))?;
+    let partstat = match status {
+        ParticipantStatus::Accepted => "ACCEPTED",
+        ParticipantStatus::Declined => "DECLINED",
+        ParticipantStatus::Tentative => "TENTATIVE",
+        ParticipantStatus::Pending => "NEEDS-ACTION",
+    };
+
+    // Without RECURRENCE-ID the organizer's client applies the reply to the whole series
+    let mut lines = vevent(uid, stamp, Span::of(event), event.occurrence_start, &event.title);
+    lines.push(format!("ORGANIZER:mailto:{}", organizer));
+    lines.push(format!(
+        "ATTENDEE;CN=\"{}\";PARTSTAT={}:mailto:{}",
+        me.name.replace('"', ""),
+        partstat,
+        my_email
+    ));
+    lines.push("END:VEVENT".to_string());
+
+    Ok(calendar("REPLY", lines))
+}
+
+/// A `METHOD:PUBLISH` calendar holding copies of `events`, for sharing or
+/// importing elsewhere. Events keep their UID so re-importing updates them;
+/// occurrences of a repeating event share it and are told apart by their
+/// `RECURRENCE-ID`.
+pub fn export(events: &[CalendarEvent], stamp: DateTime<Utc>) -> String {
+    let mut lines = Vec::new();
+    for event in events {
+        let uid = event.uid.as_deref().unwrap_or(&event.id);
+        lines.extend(vevent(uid, stamp, Span::of(event), event.occurrence_start, &event.title));
+        if let Some(location) = &event.location {
+            lines.push(format!("LOCATION:{}", escape(location)));
+        }
+        if let Some(url) = &event.url {
+            lines.push(format!("URL:{}", url));
+        }
+        if let Some(notes) = &event.notes {
+            lines.push(format!("DESCRIPTION:{}", escape(notes)));
+        }
+        lines.push("END:VEVENT".to_string());
+    }
+
+    calendar("PUBLISH", lines)
+}
+
+/// When a written event happens.
+enum Span {
+    /// An all-day event from its first through its last day.
+    Days(NaiveDate, NaiveDate),
+    Times(DateTime<Utc>, DateTime<Utc>),
+}
+
+impl Span {
+    fn of(event: &CalendarEvent) -> Self {
+        if event.is_all_day {
+            Span::Days(event.start.date_naive(), event.last_day())
+        } else {
+            Span::Times(event.start.with_timezone(&Utc), event.end.with_timezone(&Utc))
+        }
+    }
+}
+
+fn utc_stamp(time: DateTime<Utc>) -> String {
+    time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// The opening lines of a VEVENT, up to its summary; the caller adds the
+/// rest and `END:VEVENT`. All-day events are written as dates with an
+/// exclusive end, timed events in UTC. `occurrence` is the scheduled start
+/// of an occurrence of a repeating event.
+fn vevent(
+    uid: &str,
+    stamp: DateTime<Utc>,
+    span: Span,
+    occurrence: Option<DateTime<Local>>,
+    title: &str,
+) -> Vec<String> {
+    let mut lines = vec![
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:{}", uid),
+        format!("DTSTAMP:{}", utc_stamp(stamp)),
+    ];
+    let all_day = matches!(span, Span::Days(..));
+    match span {
+        Span::Days(first, last) => {
+            let end = last.succ_opt().unwrap_or(last);
+            lines.push(format!("DTSTART;VALUE=DATE:{}", first.format("%Y%m%d")));
+            lines.push(format!("DTEND;VALUE=DATE:{}", end.format("%Y%m%d")));
+        }
+        Span::Times(start, end) => {
+            lines.push(format!("DTSTART:{}", utc_stamp(start)));
+            lines.push(format!("DTEND:{}", utc_stamp(end)));
+        }
+    }
+    match occurrence {
+        Some(at) if all_day => lines.push(format!("RECURRENCE-ID;VALUE=DATE:{}", at.format("%Y%m%d"))),
+        Some(at) => lines.push(format!("RECURRENCE-ID:{}", utc_stamp(at.with_timezone(&Utc)))),
+        None => {}
+    }
+    lines.push(format!("SUMMARY:{}", escape(title)));
+    lines
+}
+
+/// A folded `VCALENDAR` of `method` around the VEVENT `lines`.
+fn calendar(method: &str, lines: Vec<String>) -> String {
+    let head = [
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//calendar-tui//EN".to_string(),
+        format!("METHOD:{}", method),
+    ];
+    head.into_iter()
+        .chain(lines)
+        .chain(["END:VCALENDAR".to_string()])
+        .map(|l| fold(&l))
+        .collect()
+}
+
+fn load_file(path: &Path) -> Result<IcsCalendar> {
+    let text = std::fs::read_to_string(path).map_err(|e| eyre!("{}: {}", path.display(), e))?;
+    parse(&text).map_err(|e| eyre!("{}: {}", path.display(), e))
+}
+
+fn file_stem(path: &Path) -> String {
+    path.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default()
+}
+
+/// Join folded lines: a line starting with a space or tab continues the previous one.
+fn unfold(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for raw in text.lines() {
+        match (raw.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ if raw.trim().is_empty() => {}
+            _ => lines.push(raw.to_string()),
+        }
+    }
+    lines
+}
+
+/// Split `NAME;PARAM=x:value` into upper-cased name, params and value. The
+/// first colon outside a quoted parameter ends the params.
+fn split_property(line: &str) -> Result<(String, String, String)> {
+    let mut quoted = false;
+    let colon = line
+        .char_indices()
+        .find(|&(_, c)| {
+            if c == '"' {
+                quoted = !quoted;
+            }
+            c == ':' && !quoted
+        })
+        .map(|(i, _)| i)
+        .ok_or_else(|| eyre!("Malformed line: {}", line))?;
+    let (head, value) = (&line[..colon], &line[colon + 1..]);
+    let (name, params) = head.split_once(';').unwrap_or((head, ""));
+    Ok((name.to_ascii_uppercase(), params.to_string(), value.to_string()))
+}
+
+fn build_event(props: &[(String, String, String)], index: usize) -> Option<CalendarEvent> {
+    let get = |name: &str| props.iter().find(|(n, _, _)| n == name);
+
+    let (_, start_params, start_value) = get("DTSTART")?;
+    let all_day = is_date_only(start_params, start_value);
+    let start = parse_datetime(start_value, all_day)?;
+    let end = match (get("DTEND"), get("DURATION")) {
+        (Some((_, params, value)), _) => parse_datetime(value, is_date_only(params, value))?,
+        (None, Some((_, _, value))) => start + parse_duration(value)?,
+        // RFC 5545: a date lasts one day, a date-time has no duration
+        (None, None) if all_day => start + Duration::days(1),
+        (None, None) => start,
+    };
+
+    let id = get("UID").map(|(_, _, v)| v.clone()).unwrap_or_else(|| index.to_string());
+    let title = get("SUMMARY").map(|(_, _, v)| unescape(v)).unwrap_or_default();
+    let mut builder = CalendarEvent::builder(id, title, start, end).all_day(all_day);
+    if let Some((_, _, v)) = get("UID") {
+        builder = builder.uid(v.clone());
+    }
+    if let Some((_, _, v)) = get("LOCATION").filter(|(_, _, v)| !v.is_empty()) {
+        builder = builder.location(unescape(v));
+    }
+    if let Some((_, _, v)) = get("URL").filter(|(_, _, v)| !v.is_empty()) {
+        builder = builder.url(v.clone());
+    }
+    if let Some((_, _, v)) = get("DESCRIPTION").filter(|(_, _, v)| !v.is_empty()) {
+        builder = builder.notes(unescape(v));
+    }
+    if let Some(rule) = get("RRULE").and_then(|(_, _, v)| Recurrence::parse(v).ok()) {
+        builder = builder.recurrence(rule);
+    }
+    if let Some((_, params, value)) = get("ORGANIZER") {
+        builder = builder.organizer(participant(params, value));
+    }
+    let attendees: Vec<Participant> = props
+        .iter()
+        .filter(|(n, _, _)| n == "ATTENDEE")
+        .map(|(_, params, value)| participant(params, value))
+        .collect();
+    Some(builder.attendees(attendees).build())
+}
+
+/// An ORGANIZER or ATTENDEE property: `CN=Ana;PARTSTAT=ACCEPTED:mailto:ana@example.com`.
+fn participant(params: &str, value: &str) -> Participant {
+    let param = |key: &str| {
+        params.split(';').find_map(|p| {
+            let (k, v) = p.split_once('=')?;
+            k.eq_ignore_ascii_case(key).then(|| v.trim_matches('"').to_string())
+        })
+    };
+    let email = value
+        .get(..7)
+        .filter(|scheme| scheme.eq_ignore_ascii_case("mailto:"))
+        .map(|_| value[7..].to_string());
+    let status = match param("PARTSTAT").as_deref().map(str::to_ascii_uppercase).as_deref() {
+        Some("ACCEPTED") => ParticipantStatus::Accepted,
+        Some("DECLINED") => ParticipantStatus::Declined,
+        Some("TENTATIVE") => ParticipantStatus::Tentative,
+        _ => ParticipantStatus::Pending,
+    };
+    Participant {
+        name: param("CN").or_else(|| email.clone()).unwrap_or_else(|| value.to_string()),
+        email,
+        status,
+        is_me: false,
+    }
+}
+
+fn is_date_only(params: &str, value: &str) -> bool {
+    params.split(';').any(|p| p.eq_ignore_ascii_case("VALUE=DATE")) || !value.contains('T')
+}
+
+/// `20260310`, `20260310T090000` (floating or TZID, read as local) or
+/// `20260310T090000Z` (UTC).
+fn parse_datetime(value: &str, date_only: bool) -> Option<DateTime<Local>> {
+    if date_only {
+        let date = NaiveDate::parse_from_str(value, "%Y%m%d").ok()?;
+        return Local.from_local_datetime(&date.and_hms_opt(0, 0, 0)?).earliest();
+    }
+    if let Some(utc) = value.strip_suffix('Z') {
+        let naive = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok()?;
+        return Some(Utc.from_utc_datetime(&naive).with_timezone(&Local));
+    }
+    let naive = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok()?;
+    Local.from_local_datetime(&naive).earliest()
+}
+
+/// `PT1H30M`, `P1D`, `P2W`, `-PT15M`.
+fn parse_duration(value: &str) -> Option<Duration> {
+    let (sign, rest) = match value.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let rest = rest.strip_prefix('P')?;
+    let mut total = Duration::zero();
+    let mut number = String::new();
+    for c in rest.chars() {
+        match c {
+            'T' => {}
+            '0'..='9' => number.push(c),
+            unit => {
+                let n: i64 = number.parse().ok()?;
+                number.clear();
+                total += match unit {
+                    'W' => Duration::weeks(n),
+                    'D' => Duration::days(n),
+                    'H' => Duration::hours(n),
+                    'M' => Duration::minutes(n),
+                    'S' => Duration::seconds(n),
+                    _ => return None,
+                };
+            }
+        }
+    }
+    Some(total * sign)
+}
+
+/// TEXT escaping, the reverse of [`unescape`].
+fn escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\n' => out.push_str("\\n"),
+            '\r' => {}
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Fold a content line at 75 octets, without splitting a UTF-8 character,
+/// and terminate it with CRLF.
+fn fold(line: &str) -> String {
+    let mut out = String::with_capacity(line.len() + 4);
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out.push_str("\r\n");
+    out
+}
+
+/// Undo TEXT escaping: `\n`, `\,`, `\;` and `\\`.
+fn unescape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
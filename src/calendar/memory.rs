@@ -0,0 +1,289 @@
+use std::sync::Mutex;
+
+use chrono::{DateTime, Local, NaiveDate, NaiveTime, TimeZone};
+use color_eyre::eyre::{eyre, Result};
+
+use super::backend::{Backend, Reschedule};
+use super::calendar::CalendarInfo;
+use super::event::{CalendarEvent, NewEvent};
+use super::reminder::Reminder;
+use super::time::shift_days;
+
+/// Time of day given to reminders that get a due date without one.
+const DEFAULT_DUE_TIME: NaiveTime = NaiveTime::MIN;
+
+/// A [`Backend`] that keeps everything in memory. Used by `--demo`, local
+/// `.ics` calendars and tests.
+#[derive(Debug, Default)]
+pub struct MemoryBackend {
+    data: Mutex<MemoryData>,
+    /// Reject every change, e.g. for calendars loaded from files.
+    read_only: bool,
+}
+
+#[derive(Debug, Default)]
+struct MemoryData {
+    calendars: Vec<CalendarInfo>,
+    reminder_calendars: Vec<CalendarInfo>,
+    events: Vec<CalendarEvent>,
+    reminders: Vec<Reminder>,
+    next_id: u64,
+}
+
+impl MemoryData {
+    fn next_id(&mut self, prefix: &str) -> String {
+        self.next_id += 1;
+        format!("{}-{}", prefix, self.next_id)
+    }
+}
+
+impl MemoryBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_calendars(self, calendars: Vec<CalendarInfo>, reminder_calendars: Vec<CalendarInfo>) -> Self {
+        {
+            let mut data = self.data.lock().expect("memory backend lock");
+            data.calendars = calendars;
+            data.reminder_calendars = reminder_calendars;
+        }
+        self
+    }
+
+    pub fn read_only(mut self) -> Self {
+        self.read_only = true;
+        self
+    }
+
+    fn writable(&self) -> Result<()> {
+        if self.read_only {
+            Err(eyre!("Calendars are read-only"))
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn add_event(&self, event: CalendarEvent) {
+        self.data.lock().expect("memory backend lock").events.push(event);
+    }
+
+    pub fn add_reminder(&self, reminder: Reminder) {
+        self.data.lock().expect("memory backend lock").reminders.push(reminder);
+    }
+}
+
+impl Backend for MemoryBackend {
+    fn request_access(&self) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn calendars(&self) -> Vec<CalendarInfo> {
+        self.data.lock().expect("memory backend lock").calendars.clone()
+    }
+
+    fn reminder_calendars(&self) -> Vec<CalendarInfo> {
+        self.data.lock().expect("memory backend lock").reminder_calendars.clone()
+    }
+
+    fn events_in_range(&self, start: DateTime<Local>, end: DateTime<Local>) -> Vec<CalendarEvent> {
+        let data = self.data.lock().expect("memory backend lock");
+        let mut events: Vec<CalendarEvent> = data
+            .events
+            .iter()
+            .filter(|e| e.start < end && e.end > start)
+            .cloned()
+            .collect();
+        events.sort_by_key(|e| e.start);
+        events
+    }
+
+    fn create_event(&self, new: &NewEvent) -> Result<()> {
+        self.writable()?;
+        let mut data = self.data.lock().expect("memory backend lock");
+        let calendar = match new.calendar_id.as_deref() {
+            Some(id) => data.calendars.iter().find(|c| c.id == id),
+            None => data.calendars.first(),
+        }
+        .cloned()
+        .ok_or_else(|| eyre!("No calendar available"))?;
+
+        let (start, end) = if new.is_all_day {
+            let start = new.date.and_hms_opt(0, 0, 0).expect("valid time");
+            let end = new.end_date.and_hms_opt(0, 0, 0).expect("valid time");
+            (start, end + chrono::Duration::days(1))
+        } else {
+            (new.date.and_time(new.start_time), new.end_date.and_time(new.end_time))
+        };
+        let start = Local.from_local_datetime(&start).single().ok_or_else(|| eyre!("Invalid start time"))?;
+        let end = Local.from_local_datetime(&end).single().ok_or_else(|| eyre!("Invalid end time"))?;
+
+        let id = data.next_id("event");
+        let mut event = CalendarEvent::builder(id, new.title.clone(), start, end)
+            .all_day(new.is_all_day)
+            .calendar(calendar.id, calendar.title, calendar.color)
+            .build();
+        event.location = new.location.clone();
+        event.notes = new.notes.clone();
+        data.events.push(event);
+        Ok(())
+    }
+
+    fn delete_event(&self, event_id: &str) -> Result<()> {
+        self.writable()?;
+        let mut data = self.data.lock().expect("memory backend lock");
+        let before = data.events.len();
+        data.events.retain(|e| e.id != event_id);
+        if data.events.len() == before {
+            return Err(eyre!("Event not found"));
+        }
+        Ok(())
+    }
+
+    fn move_event(&self, event_id: &str, days: i64) -> Result<()> {
+        self.writable()?;
+        let mut data = self.data.lock().expect("memory backend lock");
+        let event = data
+            .events
+            .iter_mut()
+            .find(|e| e.id == event_id)
+            .ok_or_else(|| eyre!("Event not found"))?;
+        let (Some(start), Some(end)) = (shift_days(event.start, days), shift_days(event.end, days)) else {
+            return Err(eyre!("Invalid time after moving"));
+        };
+        event.start = start;
+        event.end = end;
+        Ok(())
+    }
+
+    fn reschedule_event(&self, event_id: &str, start: DateTime<Local>, end: DateTime<Local>) -> Result<()> {
+        self.writable()?;
+        let mut data = self.data.lock().expect("memory backend lock");
+        let event = data
+            .events
+            .iter_mut()
+            .find(|e| e.id == event_id)
+            .ok_or_else(|| eyre!("Event not found"))?;
+        event.start = start;
+        event.end = end;
+        Ok(())
+    }
+
+    fn reschedule_events(&self, changes: &[Reschedule]) -> Result<()> {
+        self.writable()?;
+        let mut data = self.data.lock().expect("memory backend lock");
+        if let Some(missing) = changes.iter().find(|c| !data.events.iter().any(|e| e.id == c.event_id)) {
+            return Err(eyre!("Event not found: {}", missing.event_id));
+        }
+        for change in changes {
+            if let Some(event) = data.events.iter_mut().find(|e| e.id == change.event_id) {
+                event.start = change.start;
+                event.end = change.end;
+            }
+        }
+        Ok(())
+    }
+
+    fn fetch_incomplete_reminders(&self) -> Vec<Reminder> {
+        let data = self.data.lock().expect("memory backend lock");
+        data.reminders.iter().filter(|r| !r.is_completed).cloned().collect()
+    }
+
+    fn fetch_completed_reminders(&self) -> Vec<Reminder> {
+        let data = self.data.lock().expect("memory backend lock");
+        data.reminders.iter().filter(|r| r.is_completed).cloned().collect()
+    }
+
+    fn create_reminder(&self, title: &str, due_date: Option<NaiveDate>, calendar_id: Option<&str>) -> Result<String> {
+        self.writable()?;
+        let mut data = self.data.lock().expect("memory backend lock");
+        let list = match calendar_id {
+            Some(id) => data.reminder_calendars.iter().find(|c| c.id == id),
+            None => data.reminder_calendars.first(),
+        }
+        .cloned()
+        .ok_or_else(|| eyre!("No reminder list available"))?;
+
+        let id = data.next_id("reminder");
+        let mut builder = Reminder::builder(id.clone(), title).calendar(list.id, list.title, list.color);
+        if let Some(due) = due_date.and_then(|d| Local.from_local_datetime(&d.and_time(DEFAULT_DUE_TIME)).single()) {
+            builder = builder.due_date(due);
+        }
+        data.reminders.push(builder.build());
+        Ok(id)
+    }
+
+    fn toggle_reminder(&self, reminder_id: &str) -> Result<bool> {
+        self.writable()?;
+        let mut data = self.data.lock().expect("memory backend lock");
+        let reminder = data
+            .reminders
+            .iter_mut()
+            .find(|r| r.id == reminder_id)
+            .ok_or_else(|| eyre!("Reminder not found"))?;
+        reminder.is_completed = !reminder.is_completed;
+        Ok(reminder.is_completed)
+    }
+
+    /// Memory reminders carry no recurrence rule, so recurring ones are
+    /// treated as weekly: a completed copy is kept and the original moves on.
+    fn complete_occurrence(&self, reminder_id: &str) -> Result<Option<DateTime<Local>>> {
+        self.writable()?;
+        let mut data = self.data.lock().expect("memory backend lock");
+        let id = data.next_id("reminder");
+        let reminder = data
+            .reminders
+            .iter_mut()
+            .find(|r| r.id == reminder_id)
+            .ok_or_else(|| eyre!("Reminder not found"))?;
+
+        let mut done = reminder.clone();
+        done.id = id;
+        done.is_completed = true;
+        done.is_recurring = false;
+        reminder.due_date = reminder.due_date.map(|d| d + chrono::Duration::weeks(1));
+        let next = reminder.due_date;
+        data.reminders.push(done);
+        Ok(next)
+    }
+
+    fn delete_reminder(&self, reminder_id: &str) -> Result<()> {
+        self.writable()?;
+        let mut data = self.data.lock().expect("memory backend lock");
+        let before = data.reminders.len();
+        data.reminders.retain(|r| r.id != reminder_id);
+        if data.reminders.len() == before {
+            return Err(eyre!("Reminder not found"));
+        }
+        Ok(())
+    }
+
+    fn update_reminder(
+        &self,
+        reminder_id: &str,
+        title: &str,
+        due_date: Option<NaiveDate>,
+        priority: u8,
+        calendar_id: Option<&str>,
+    ) -> Result<()> {
+        self.writable()?;
+        let mut data = self.data.lock().expect("memory backend lock");
+        let list = calendar_id.and_then(|id| data.reminder_calendars.iter().find(|c| c.id == id).cloned());
+        let reminder = data
+            .reminders
+            .iter_mut()
+            .find(|r| r.id == reminder_id)
+            .ok_or_else(|| eyre!("Reminder not found"))?;
+        reminder.title = title.to_string();
+        // Only the day is edited; keep the time of day already due
+        let time = reminder.due_date.map_or(DEFAULT_DUE_TIME, |d| d.time());
+        reminder.due_date = due_date.and_then(|d| Local.from_local_datetime(&d.and_time(time)).single());
+        reminder.priority = priority;
+        if let Some(list) = list {
+            reminder.calendar_id = list.id;
+            reminder.calendar_name = list.title;
+            reminder.calendar_color = list.color;
+        }
+        Ok(())
+    }
+}
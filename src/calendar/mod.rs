@@ -1,9 +1,27 @@
+pub mod backend;
 pub mod calendar;
+mod color;
+pub mod demo;
 pub mod event;
+pub mod faulty;
+pub mod ics;
+pub mod memory;
+pub mod prefetch;
+pub mod recurrence;
 pub mod reminder;
 pub mod store;
+pub mod time;
+pub mod video;
+pub mod zone;
 
-pub use calendar::CalendarInfo;
-pub use event::CalendarEvent;
-pub use reminder::Reminder;
+pub use backend::{Access, Backend, EventQuery, Permissions, Reschedule};
+pub use calendar::{CalendarInfo, CalendarInfoBuilder};
+pub use event::{CalendarEvent, CalendarEventBuilder, EventOrigin, NewEvent, Participant, ParticipantStatus};
+pub use faulty::{Faults, FaultyBackend};
+pub use memory::MemoryBackend;
+pub use recurrence::Recurrence;
+pub use reminder::{Reminder, ReminderBuilder};
 pub use store::Store;
+pub use time::{HourLabels, TimeFormat};
+pub use video::{VideoLink, VideoService};
+pub use zone::Zone;
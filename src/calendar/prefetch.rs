@@ -0,0 +1,71 @@
+//! Fetching months of events on a worker thread before they're shown.
+
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+
+use chrono::{DateTime, Local};
+
+use super::backend::EventQuery;
+
+/// Events wanted for a month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Request {
+    pub year: i32,
+    pub month: u32,
+    /// Range to query, which can differ from the month's own in travel mode.
+    pub start: DateTime<Local>,
+    pub end: DateTime<Local>,
+    /// Bumped by the app whenever events change, so answers to older
+    /// requests can be dropped.
+    pub generation: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Fetched {
+    pub request: Request,
+    /// The month's events and anything the store left out of them.
+    pub query: EventQuery,
+}
+
+/// A worker thread answering [`Request`]s in the order they were made.
+pub struct Prefetcher {
+    requests: Sender<Request>,
+    results: Receiver<Fetched>,
+}
+
+impl Prefetcher {
+    /// Start the worker. `open` runs on the worker thread and returns the
+    /// function answering requests, so backends whose handles can't cross
+    /// threads open their own there. The worker stops when `open` returns
+    /// `None` or the prefetcher is dropped.
+    pub fn spawn<O, F>(open: O) -> Self
+    where
+        O: FnOnce() -> Option<F> + Send + 'static,
+        F: FnMut(&Request) -> EventQuery,
+    {
+        let (requests, pending) = mpsc::channel::<Request>();
+        let (done, results) = mpsc::channel();
+        thread::spawn(move || {
+            let Some(mut fetch) = open() else {
+                return;
+            };
+            for request in pending {
+                let query = fetch(&request);
+                if done.send(Fetched { request, query }).is_err() {
+                    break;
+                }
+            }
+        });
+        Self { requests, results }
+    }
+
+    pub fn request(&self, request: Request) {
+        // A worker that stopped just never answers
+        let _ = self.requests.send(request);
+    }
+
+    /// The next answer, if one is ready.
+    pub fn try_recv(&self) -> Option<Fetched> {
+        self.results.try_recv().ok()
+    }
+}
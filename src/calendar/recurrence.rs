@@ -0,0 +1,266 @@
+//! Recurrence rules and their human-readable descriptions.
+//!
+//! [`Recurrence`] mirrors the parts of an RFC 5545 `RRULE` (and of
+//! `EKRecurrenceRule`) that calendars actually use. [`Recurrence::describe`]
+//! turns it into text such as "every 2 weeks on Tue and Thu, 10 times".
+
+use chrono::{NaiveDate, Weekday};
+use color_eyre::eyre::{eyre, Result};
+use serde::{Deserialize, Serialize};
+
+use super::event::ordinal;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// A weekday, optionally restricted to the n-th occurrence in the month or
+/// year (`-1` is the last).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayOfWeek {
+    pub weekday: Weekday,
+    pub nth: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Recurrence {
+    pub frequency: Frequency,
+    pub interval: u32,
+    pub by_day: Vec<DayOfWeek>,
+    /// Days of the month; negative values count from the end.
+    pub by_month_day: Vec<i32>,
+    /// Months, 1-12.
+    pub by_month: Vec<u32>,
+    /// Which of the matching days to keep within each period (`BYSETPOS`).
+    pub by_set_pos: Vec<i32>,
+    pub count: Option<u32>,
+    pub until: Option<NaiveDate>,
+}
+
+impl Recurrence {
+    pub fn new(frequency: Frequency) -> Self {
+        Self {
+            frequency,
+            interval: 1,
+            by_day: Vec::new(),
+            by_month_day: Vec::new(),
+            by_month: Vec::new(),
+            by_set_pos: Vec::new(),
+            count: None,
+            until: None,
+        }
+    }
+
+    /// Parse an RFC 5545 recurrence rule, with or without the `RRULE:` prefix.
+    /// Parts this type does not model (e.g. `WKST`) are ignored.
+    pub fn parse(rule: &str) -> Result<Self> {
+        let rule = rule.trim();
+        let rule = rule.strip_prefix("RRULE:").unwrap_or(rule);
+
+        let mut frequency = None;
+        let mut rec = Recurrence::new(Frequency::Daily);
+        for part in rule.split(';').filter(|p| !p.is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| eyre!("Malformed rule part: {}", part))?;
+            match key.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    frequency = Some(match value.to_ascii_uppercase().as_str() {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        _ => return Err(eyre!("Unsupported frequency: {}", value)),
+                    })
+                }
+                "INTERVAL" => {
+                    rec.interval = value
+                        .parse()
+                        .ok()
+                        .filter(|&n| n > 0)
+                        .ok_or_else(|| eyre!("Invalid interval: {}", value))?
+                }
+                "COUNT" => {
+                    rec.count = Some(value.parse().map_err(|_| eyre!("Invalid count: {}", value))?)
+                }
+                "UNTIL" => rec.until = Some(parse_until(value)?),
+                "BYDAY" => {
+                    rec.by_day = value.split(',').map(parse_day).collect::<Result<_>>()?;
+                }
+                "BYMONTHDAY" => rec.by_month_day = parse_list(value, -31, 31)?,
+                "BYMONTH" => {
+                    rec.by_month = parse_list(value, 1, 12)?.into_iter().map(|m| m as u32).collect();
+                }
+                "BYSETPOS" => rec.by_set_pos = parse_list(value, -366, 366)?,
+                _ => {}
+            }
+        }
+        rec.frequency = frequency.ok_or_else(|| eyre!("Missing FREQ"))?;
+        Ok(rec)
+    }
+
+    /// Human-readable summary, e.g. "every 2 weeks on Tue and Thu, 10 times".
+    pub fn describe(&self) -> String {
+        let mut text = self.describe_period();
+
+        let weekdays_only = self.by_day.iter().all(|d| d.nth.is_none());
+        if self.is_every_weekday() {
+            // Already "every weekday"
+        } else if !self.by_day.is_empty() && weekdays_only && self.by_set_pos.is_empty() {
+            let days: Vec<String> = self.by_day.iter().map(|d| short_weekday(d.weekday).to_string()).collect();
+            text.push_str(&format!(" on {}", join_and(&days)));
+        } else if !self.by_day.is_empty() {
+            let days: Vec<String> = self
+                .by_day
+                .iter()
+                .map(|d| {
+                    let nth = d.nth.or_else(|| self.by_set_pos.first().copied());
+                    match nth {
+                        Some(n) => format!("the {} {}", ordinal_word(n), short_weekday(d.weekday)),
+                        None => short_weekday(d.weekday).to_string(),
+                    }
+                })
+                .collect();
+            text.push_str(&format!(" on {}", join_and(&days)));
+        } else if !self.by_month_day.is_empty() {
+            let days: Vec<String> = self.by_month_day.iter().map(|&d| month_day(d)).collect();
+            text.push_str(&format!(" on the {}", join_and(&days)));
+        }
+
+        if !self.by_month.is_empty() {
+            let months: Vec<String> = self.by_month.iter().map(|&m| short_month(m).to_string()).collect();
+            text.push_str(&format!(" in {}", join_and(&months)));
+        }
+
+        match (self.count, self.until) {
+            (Some(1), _) => text.push_str(", once"),
+            (Some(n), _) => text.push_str(&format!(", {} times", n)),
+            (None, Some(until)) => text.push_str(&format!(", until {}", until.format("%b %-d, %Y"))),
+            (None, None) => {}
+        }
+        text
+    }
+
+    fn is_every_weekday(&self) -> bool {
+        const WORKDAYS: [Weekday; 5] = [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri];
+        self.frequency == Frequency::Weekly
+            && self.interval == 1
+            && self.by_day.len() == 5
+            && self.by_day.iter().all(|d| d.nth.is_none() && WORKDAYS.contains(&d.weekday))
+    }
+
+    fn describe_period(&self) -> String {
+        if self.is_every_weekday() {
+            return "every weekday".to_string();
+        }
+        let unit = match self.frequency {
+            Frequency::Daily => "day",
+            Frequency::Weekly => "week",
+            Frequency::Monthly => "month",
+            Frequency::Yearly => "year",
+        };
+        match self.interval {
+            1 => format!("every {}", unit),
+            2 if self.frequency == Frequency::Weekly => "every other week".to_string(),
+            n => format!("every {} {}s", n, unit),
+        }
+    }
+}
+
+fn parse_until(value: &str) -> Result<NaiveDate> {
+    let date = value.get(..8).ok_or_else(|| eyre!("Invalid UNTIL: {}", value))?;
+    NaiveDate::parse_from_str(date, "%Y%m%d").map_err(|_| eyre!("Invalid UNTIL: {}", value))
+}
+
+/// Parse a `BYDAY` entry such as `TU`, `2MO` or `-1FR`.
+fn parse_day(value: &str) -> Result<DayOfWeek> {
+    let value = value.trim();
+    // Day codes are ASCII; this also keeps the split on a char boundary
+    if value.len() < 2 || !value.is_ascii() {
+        return Err(eyre!("Invalid day: {}", value));
+    }
+    let (nth, code) = value.split_at(value.len() - 2);
+    let weekday = match code.to_ascii_uppercase().as_str() {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return Err(eyre!("Invalid day: {}", value)),
+    };
+    let nth = match nth {
+        "" => None,
+        n => Some(
+            n.trim_start_matches('+')
+                .parse::<i32>()
+                .ok()
+                .filter(|&n| n != 0 && (-53..=53).contains(&n))
+                .ok_or_else(|| eyre!("Invalid day: {}", value))?,
+        ),
+    };
+    Ok(DayOfWeek { weekday, nth })
+}
+
+fn parse_list(value: &str, min: i32, max: i32) -> Result<Vec<i32>> {
+    value
+        .split(',')
+        .map(|v| {
+            v.trim()
+                .parse::<i32>()
+                .ok()
+                .filter(|&n| n != 0 && (min..=max).contains(&n))
+                .ok_or_else(|| eyre!("Invalid value: {}", v))
+        })
+        .collect()
+}
+
+fn short_weekday(day: Weekday) -> &'static str {
+    match day {
+        Weekday::Mon => "Mon",
+        Weekday::Tue => "Tue",
+        Weekday::Wed => "Wed",
+        Weekday::Thu => "Thu",
+        Weekday::Fri => "Fri",
+        Weekday::Sat => "Sat",
+        Weekday::Sun => "Sun",
+    }
+}
+
+fn short_month(month: u32) -> &'static str {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    MONTHS.get(month.wrapping_sub(1) as usize).copied().unwrap_or("?")
+}
+
+/// "1st", "2nd", "last", "2nd to last".
+fn ordinal_word(n: i32) -> String {
+    match n {
+        -1 => "last".to_string(),
+        n if n < 0 => format!("{} to last", ordinal(-n)),
+        n => ordinal(n),
+    }
+}
+
+fn month_day(n: i32) -> String {
+    match n {
+        -1 => "last day".to_string(),
+        n if n < 0 => format!("{} to last day", ordinal(-n)),
+        n => ordinal(n),
+    }
+}
+
+/// "a", "a and b", "a, b and c".
+fn join_and(items: &[String]) -> String {
+    match items {
+        [] => String::new(),
+        [one] => one.clone(),
+        [rest @ .., last] => format!("{} and {}", rest.join(", "), last),
+    }
+}
@@ -1,14 +1,94 @@
 use chrono::{DateTime, Local};
 use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
-#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct Reminder {
     pub id: String,
     pub title: String,
     pub is_completed: bool,
     pub due_date: Option<DateTime<Local>>,
+    pub calendar_id: String,
     pub calendar_name: String,
+    #[serde(with = "super::color")]
     pub calendar_color: Color,
     pub priority: u8,
+    /// Has recurrence rules; completing it advances the due date instead.
+    #[serde(default)]
+    pub is_recurring: bool,
+}
+
+impl Reminder {
+    pub fn builder(id: impl Into<String>, title: impl Into<String>) -> ReminderBuilder {
+        ReminderBuilder {
+            reminder: Reminder {
+                id: id.into(),
+                title: title.into(),
+                is_completed: false,
+                due_date: None,
+                calendar_id: String::new(),
+                calendar_name: "Unknown".to_string(),
+                calendar_color: Color::White,
+                priority: 0,
+                is_recurring: false,
+            },
+        }
+    }
+
+    /// Fields passed to custom actions as `CAL_*` environment variables.
+    pub fn env_vars(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("CAL_KIND", "reminder".to_string()),
+            ("CAL_ID", self.id.clone()),
+            ("CAL_TITLE", self.title.clone()),
+            ("CAL_DUE", self.due_date.map(|d| d.to_rfc3339()).unwrap_or_default()),
+            ("CAL_CALENDAR", self.calendar_name.clone()),
+            ("CAL_COMPLETED", if self.is_completed { "1" } else { "0" }.to_string()),
+        ]
+    }
+}
+
+/// Builder for [`Reminder`], for use outside this crate.
+#[derive(Debug, Clone)]
+pub struct ReminderBuilder {
+    reminder: Reminder,
+}
+
+impl ReminderBuilder {
+    pub fn completed(mut self, is_completed: bool) -> Self {
+        self.reminder.is_completed = is_completed;
+        self
+    }
+
+    pub fn due_date(mut self, due_date: DateTime<Local>) -> Self {
+        self.reminder.due_date = Some(due_date);
+        self
+    }
+
+    pub fn calendar(
+        mut self,
+        id: impl Into<String>,
+        name: impl Into<String>,
+        color: Color,
+    ) -> Self {
+        self.reminder.calendar_id = id.into();
+        self.reminder.calendar_name = name.into();
+        self.reminder.calendar_color = color;
+        self
+    }
+
+    pub fn priority(mut self, priority: u8) -> Self {
+        self.reminder.priority = priority;
+        self
+    }
+
+    pub fn recurring(mut self, is_recurring: bool) -> Self {
+        self.reminder.is_recurring = is_recurring;
+        self
+    }
+
+    pub fn build(self) -> Reminder {
+        self.reminder
+    }
 }
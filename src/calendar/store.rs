@@ -1,61 +1,130 @@
-use std::sync::mpsc;
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
 
 use block2::RcBlock;
-use chrono::{Datelike, DateTime, Local, NaiveDate, NaiveTime, TimeZone};
+use chrono::{Datelike, DateTime, Local, NaiveDate, TimeZone, Weekday};
 use color_eyre::eyre::{eyre, Result};
 use objc2::rc::Retained;
-use objc2::runtime::Bool;
+use objc2::runtime::{Bool, NSObjectProtocol, ProtocolObject};
 use objc2_event_kit::{
-    EKAuthorizationStatus, EKEntityType, EKEvent, EKEventStore, EKReminder, EKSpan,
+    EKAlarm, EKAuthorizationStatus, EKCalendarType, EKEntityType, EKEvent, EKEventAvailability,
+    EKEventStore,
+    EKEventStoreChangedNotification, EKParticipant, EKParticipantStatus, EKRecurrenceFrequency, EKReminder,
+    EKSpan,
+};
+use objc2_foundation::{
+    NSArray, NSDate, NSDateComponents, NSError, NSNotification, NSNotificationCenter, NSNumber,
+    NSRunLoop, NSString, NSURL,
 };
-use objc2_foundation::{NSArray, NSDate, NSError, NSRunLoop, NSString};
 use ratatui::style::Color;
+use tracing::{debug, instrument};
 
+use super::backend::{Access, Backend, EventQuery, Permissions, Reschedule};
 use super::calendar::CalendarInfo;
-use super::event::CalendarEvent;
+use super::event::{CalendarEvent, EventOrigin, NewEvent, Participant, ParticipantStatus};
+use super::prefetch::{Prefetcher, Request};
+use super::recurrence::{DayOfWeek, Frequency, Recurrence};
 use super::reminder::Reminder;
+use super::time::shift_days;
 
 /// Seconds between Unix epoch (1970-01-01) and NSDate reference date (2001-01-01)
 const NSDATE_UNIX_OFFSET: f64 = 978307200.0;
 
+/// Longest range EventKit searches for events; longer ones are shortened
+/// to their first four years.
+const MAX_QUERY_DAYS: i64 = 4 * 365;
+
 pub struct Store {
     store: Retained<EKEventStore>,
+    /// Set by the EKEventStoreChangedNotification observer.
+    changed: Arc<AtomicBool>,
+    observer: Retained<ProtocolObject<dyn NSObjectProtocol>>,
 }
 
 impl Store {
     pub fn new() -> Result<Self> {
         let store = unsafe { EKEventStore::new() };
-        Ok(Self { store })
+        let changed = Arc::new(AtomicBool::new(false));
+        let flag = Arc::clone(&changed);
+        let block = RcBlock::new(move |_: NonNull<NSNotification>| {
+            flag.store(true, Ordering::Relaxed);
+        });
+        let observer = unsafe {
+            NSNotificationCenter::defaultCenter().addObserverForName_object_queue_usingBlock(
+                Some(EKEventStoreChangedNotification),
+                Some(&store),
+                None,
+                &block,
+            )
+        };
+        Ok(Self { store, changed, observer })
+    }
+
+    /// Whether the calendar database changed since the last call. Runs the
+    /// main run loop briefly, since EventKit delivers the notification there.
+    pub fn take_changes(&self) -> bool {
+        let until = NSDate::dateWithTimeIntervalSinceNow(0.0);
+        let _ran = unsafe {
+            NSRunLoop::currentRunLoop().runMode_beforeDate(objc2_foundation::NSDefaultRunLoopMode, &until)
+        };
+        self.changed.swap(false, Ordering::Relaxed)
+    }
+
+    /// Start a sync of every account and subscribed calendar.
+    pub fn refresh_sources(&self) {
+        unsafe { self.store.refreshSourcesIfNecessary() };
     }
 
     pub fn authorization_status() -> EKAuthorizationStatus {
         unsafe { EKEventStore::authorizationStatusForEntityType(EKEntityType::Event) }
     }
 
-    #[allow(dead_code)]
     pub fn reminder_authorization_status() -> EKAuthorizationStatus {
         unsafe { EKEventStore::authorizationStatusForEntityType(EKEntityType::Reminder) }
     }
 
+    pub fn permissions(&self) -> Permissions {
+        Permissions {
+            events: access(Self::authorization_status()),
+            reminders: access(Self::reminder_authorization_status()),
+        }
+    }
+
     pub fn request_access(&self) -> Result<bool> {
-        let event_access = self.request_entity_access(|store, block| unsafe {
-            store.requestFullAccessToEventsWithCompletion(block);
-        })?;
+        self.request_access_waiting(&mut || {})
+    }
+
+    /// Like [`request_access`](Self::request_access), calling `waiting`
+    /// every tenth of a second while a permission prompt is up.
+    pub fn request_access_waiting(&self, waiting: &mut dyn FnMut()) -> Result<bool> {
+        let event_access = self.request_entity_access(
+            Self::authorization_status(),
+            |store, block| unsafe {
+                store.requestFullAccessToEventsWithCompletion(block);
+            },
+            waiting,
+        )?;
 
         // Also request reminder access
-        let _reminder_access = self.request_entity_access(|store, block| unsafe {
-            store.requestFullAccessToRemindersWithCompletion(block);
-        })?;
+        let _reminder_access = self.request_entity_access(
+            Self::reminder_authorization_status(),
+            |store, block| unsafe {
+                store.requestFullAccessToRemindersWithCompletion(block);
+            },
+            waiting,
+        )?;
 
         Ok(event_access)
     }
 
     fn request_entity_access(
         &self,
+        status: EKAuthorizationStatus,
         request_fn: impl FnOnce(&EKEventStore, *mut block2::Block<dyn Fn(Bool, *mut NSError)>),
+        waiting: &mut dyn FnMut(),
     ) -> Result<bool> {
-        // Check if already authorized for events
-        let status = Self::authorization_status();
+        // Skip the prompt when the user already decided
         match status {
             EKAuthorizationStatus::FullAccess => return Ok(true),
             EKAuthorizationStatus::Denied | EKAuthorizationStatus::Restricted => {
@@ -79,6 +148,7 @@ impl Store {
                     return Err(eyre!("Access request failed"));
                 }
                 Err(mpsc::TryRecvError::Empty) => {
+                    waiting();
                     let until = NSDate::dateWithTimeIntervalSinceNow(0.1);
                     let _ran = unsafe {
                         run_loop.runMode_beforeDate(
@@ -100,7 +170,6 @@ impl Store {
         convert_calendars(&ek_calendars)
     }
 
-    #[allow(dead_code)]
     pub fn reminder_calendars(&self) -> Vec<CalendarInfo> {
         let ek_calendars = unsafe {
             self.store.calendarsForEntityType(EKEntityType::Reminder)
@@ -110,46 +179,7 @@ impl Store {
 
     // ── Event queries ──
 
-    pub fn events_for_date(&self, date: NaiveDate) -> Vec<CalendarEvent> {
-        let start_of_day = date.and_hms_opt(0, 0, 0).expect("valid time");
-        let end_of_day = date.and_hms_opt(23, 59, 59).expect("valid time");
-
-        let start_dt = Local.from_local_datetime(&start_of_day).single().expect("valid");
-        let end_dt = Local.from_local_datetime(&end_of_day).single().expect("valid");
-
-        self.events_in_range(start_dt, end_dt)
-    }
-
-    pub fn events_for_week(&self, date: NaiveDate) -> Vec<CalendarEvent> {
-        let days_since_sunday = date.weekday().num_days_from_sunday();
-        let week_start = date - chrono::Duration::days(days_since_sunday as i64);
-        let week_end = week_start + chrono::Duration::days(7);
-
-        let start_dt = Local.from_local_datetime(&week_start.and_hms_opt(0, 0, 0).unwrap())
-            .single().expect("valid");
-        let end_dt = Local.from_local_datetime(&week_end.and_hms_opt(0, 0, 0).unwrap())
-            .single().expect("valid");
-
-        self.events_in_range(start_dt, end_dt)
-    }
-
-    pub fn events_for_month(&self, year: i32, month: u32) -> Vec<CalendarEvent> {
-        let start = NaiveDate::from_ymd_opt(year, month, 1).expect("valid date");
-        let end = if month == 12 {
-            NaiveDate::from_ymd_opt(year + 1, 1, 1).expect("valid date")
-        } else {
-            NaiveDate::from_ymd_opt(year, month + 1, 1).expect("valid date")
-        };
-
-        let start_dt = Local.from_local_datetime(&start.and_hms_opt(0, 0, 0).unwrap())
-            .single().expect("valid");
-        let end_dt = Local.from_local_datetime(&end.and_hms_opt(0, 0, 0).unwrap())
-            .single().expect("valid");
-
-        self.events_in_range(start_dt, end_dt)
-    }
-
-    fn events_in_range(&self, start: DateTime<Local>, end: DateTime<Local>) -> Vec<CalendarEvent> {
+    pub fn events_in_range(&self, start: DateTime<Local>, end: DateTime<Local>) -> Vec<CalendarEvent> {
         let ns_start = datetime_to_nsdate(&start);
         let ns_end = datetime_to_nsdate(&end);
 
@@ -165,7 +195,10 @@ impl Store {
 
         for i in 0..count {
             let ev = ek_events.objectAtIndex(i);
-            if let Some(event) = convert_event(&ev) {
+            if let Some(mut event) = convert_event(&ev) {
+                if is_birthday_event(&ev) {
+                    event.origin_year = self.first_occurrence_year(&ev);
+                }
                 events.push(event);
             }
         }
@@ -174,34 +207,41 @@ impl Store {
         events
     }
 
+    /// Year of the first occurrence of a recurring event (the birth year for birthdays).
+    fn first_occurrence_year(&self, ev: &EKEvent) -> Option<i32> {
+        let id = unsafe { ev.eventIdentifier() }?;
+        // eventWithIdentifier returns the first occurrence of a recurring series
+        let first = unsafe { self.store.eventWithIdentifier(&id) }?;
+        let start = unsafe { first.startDate() };
+        let year = nsdate_to_datetime(&start).year();
+        // Contacts without a known birth year use 1604 as a placeholder
+        (year > 1604).then_some(year)
+    }
+
     // ── Event write operations ──
 
-    pub fn create_event(
-        &self,
-        title: &str,
-        date: NaiveDate,
-        start_time: NaiveTime,
-        end_time: NaiveTime,
-        is_all_day: bool,
-        calendar_id: Option<&str>,
-    ) -> Result<()> {
+    pub fn create_event(&self, new: &NewEvent) -> Result<()> {
         let event = unsafe { EKEvent::eventWithEventStore(&self.store) };
 
-        let ns_title = NSString::from_str(title);
+        let ns_title = NSString::from_str(&new.title);
         unsafe { event.setTitle(Some(&ns_title)) };
 
-        if is_all_day {
+        let date = new.date;
+        if new.is_all_day {
             unsafe { event.setAllDay(true) };
             let start = date.and_hms_opt(0, 0, 0).unwrap();
+            let end = new.end_date.and_hms_opt(0, 0, 0).unwrap();
             let start_dt = Local.from_local_datetime(&start).single().expect("valid");
+            let end_dt = Local.from_local_datetime(&end).single().expect("valid");
             let ns_start = datetime_to_nsdate(&start_dt);
+            let ns_end = datetime_to_nsdate(&end_dt);
             unsafe {
                 event.setStartDate(Some(&ns_start));
-                event.setEndDate(Some(&ns_start));
+                event.setEndDate(Some(&ns_end));
             };
         } else {
-            let start = date.and_time(start_time);
-            let end = date.and_time(end_time);
+            let start = date.and_time(new.start_time);
+            let end = new.end_date.and_time(new.end_time);
             let start_dt = Local.from_local_datetime(&start).single().expect("valid");
             let end_dt = Local.from_local_datetime(&end).single().expect("valid");
             let ns_start = datetime_to_nsdate(&start_dt);
@@ -212,8 +252,29 @@ impl Store {
             };
         }
 
+        if let Some(ref location) = new.location {
+            unsafe { event.setLocation(Some(&NSString::from_str(location))) };
+        }
+        if let Some(ref notes) = new.notes {
+            unsafe { event.setNotes(Some(&NSString::from_str(notes))) };
+        }
+        if let Some(ref url) = new.url {
+            let ns_url = NSURL::URLWithString(&NSString::from_str(url))
+                .ok_or_else(|| eyre!("Invalid URL: {}", url))?;
+            unsafe { event.setURL(Some(&ns_url)) };
+        }
+
+        if new.busy {
+            unsafe { event.setAvailability(EKEventAvailability::Busy) };
+        }
+
+        if let Some(minutes) = new.alarm_minutes {
+            let alarm = unsafe { EKAlarm::alarmWithRelativeOffset(-(minutes as f64) * 60.0) };
+            unsafe { event.addAlarm(&alarm) };
+        }
+
         // Set calendar
-        if let Some(cal_id) = calendar_id {
+        if let Some(ref cal_id) = new.calendar_id {
             let ns_cal_id = NSString::from_str(cal_id);
             if let Some(cal) = unsafe { self.store.calendarWithIdentifier(&ns_cal_id) } {
                 unsafe { event.setCalendar(Some(&cal)) };
@@ -243,6 +304,90 @@ impl Store {
         Ok(())
     }
 
+    /// Move an event by whole days, keeping its times of day.
+    pub fn move_event(&self, event_id: &str, days: i64) -> Result<()> {
+        let ns_id = NSString::from_str(event_id);
+        let event = unsafe { self.store.eventWithIdentifier(&ns_id) }
+            .ok_or_else(|| eyre!("Event not found"))?;
+
+        let (start, end) = unsafe { (event.startDate(), event.endDate()) };
+        let start = shift_days(nsdate_to_datetime(&start), days).ok_or_else(|| eyre!("Invalid start time"))?;
+        let end = shift_days(nsdate_to_datetime(&end), days).ok_or_else(|| eyre!("Invalid end time"))?;
+        unsafe {
+            event.setStartDate(Some(&datetime_to_nsdate(&start)));
+            event.setEndDate(Some(&datetime_to_nsdate(&end)));
+            self.store.saveEvent_span_error(&event, EKSpan::ThisEvent)
+                .map_err(|e| eyre!("Failed to move event: {:?}", e))?;
+        }
+
+        Ok(())
+    }
+
+    /// Set an event's start and end times.
+    pub fn reschedule_event(&self, event_id: &str, start: DateTime<Local>, end: DateTime<Local>) -> Result<()> {
+        let ns_id = NSString::from_str(event_id);
+        let event = unsafe { self.store.eventWithIdentifier(&ns_id) }
+            .ok_or_else(|| eyre!("Event not found"))?;
+
+        unsafe {
+            event.setStartDate(Some(&datetime_to_nsdate(&start)));
+            event.setEndDate(Some(&datetime_to_nsdate(&end)));
+            self.store.saveEvent_span_error(&event, EKSpan::ThisEvent)
+                .map_err(|e| eyre!("Failed to reschedule event: {:?}", e))?;
+        }
+
+        Ok(())
+    }
+
+    /// Remove events in one batch: each removal is staged without committing,
+    /// then the store commits once. On failure nothing staged is kept, so a
+    /// later save can't apply part of the batch. Ids already gone are skipped.
+    pub fn delete_events(&self, event_ids: &[String]) -> Result<usize> {
+        let mut staged = 0;
+        let removed = event_ids.iter().try_for_each(|id| {
+            let ns_id = NSString::from_str(id);
+            let Some(event) = (unsafe { self.store.eventWithIdentifier(&ns_id) }) else {
+                return Ok(());
+            };
+            unsafe {
+                self.store.removeEvent_span_commit_error(&event, EKSpan::ThisEvent, false)
+                    .map_err(|e| eyre!("Failed to delete event: {:?}", e))?;
+            }
+            staged += 1;
+            Ok(())
+        });
+        let committed = removed.and_then(|()| unsafe {
+            self.store.commit().map_err(|e| eyre!("Failed to commit deletions: {:?}", e))
+        });
+        if committed.is_err() {
+            unsafe { self.store.reset() };
+        }
+        committed.map(|()| staged)
+    }
+
+    /// Give several events new times as one change: each save is staged
+    /// without committing, and a failure discards everything staged.
+    pub fn reschedule_events(&self, changes: &[Reschedule]) -> Result<()> {
+        let staged = changes.iter().try_for_each(|change| {
+            let ns_id = NSString::from_str(&change.event_id);
+            let event = unsafe { self.store.eventWithIdentifier(&ns_id) }
+                .ok_or_else(|| eyre!("Event not found: {}", change.event_id))?;
+            unsafe {
+                event.setStartDate(Some(&datetime_to_nsdate(&change.start)));
+                event.setEndDate(Some(&datetime_to_nsdate(&change.end)));
+                self.store.saveEvent_span_commit_error(&event, EKSpan::ThisEvent, false)
+                    .map_err(|e| eyre!("Failed to reschedule event: {:?}", e))
+            }
+        });
+        let committed = staged.and_then(|()| unsafe {
+            self.store.commit().map_err(|e| eyre!("Failed to commit changes: {:?}", e))
+        });
+        if committed.is_err() {
+            unsafe { self.store.reset() };
+        }
+        committed
+    }
+
     // ── Reminder queries ──
 
     #[allow(dead_code)]
@@ -394,6 +539,33 @@ impl Store {
 
     // ── Reminder write operations ──
 
+    pub fn create_reminder(&self, title: &str, due_date: Option<NaiveDate>, calendar_id: Option<&str>) -> Result<String> {
+        let reminder = unsafe { EKReminder::reminderWithEventStore(&self.store) };
+        unsafe { reminder.setTitle(Some(&NSString::from_str(title))) };
+
+        if let Some(date) = due_date {
+            let components = NSDateComponents::new();
+            components.setYear(date.year() as isize);
+            components.setMonth(date.month() as isize);
+            components.setDay(date.day() as isize);
+            unsafe { reminder.setDueDateComponents(Some(&components)) };
+        }
+
+        let calendar = match calendar_id {
+            Some(cal_id) => unsafe { self.store.calendarWithIdentifier(&NSString::from_str(cal_id)) },
+            None => unsafe { self.store.defaultCalendarForNewReminders() },
+        }
+        .ok_or_else(|| eyre!("No reminder list available"))?;
+        unsafe { reminder.setCalendar(Some(&calendar)) };
+
+        unsafe {
+            self.store.saveReminder_commit_error(&reminder, true)
+                .map_err(|e| eyre!("Failed to save reminder: {:?}", e))?;
+        }
+
+        Ok(unsafe { reminder.calendarItemIdentifier() }.to_string())
+    }
+
     pub fn toggle_reminder(&self, reminder_id: &str) -> Result<bool> {
         let ns_id = NSString::from_str(reminder_id);
         let item = unsafe { self.store.calendarItemWithIdentifier(&ns_id) }
@@ -413,6 +585,249 @@ impl Store {
 
         Ok(new_state)
     }
+
+    /// Mark a recurring reminder's current occurrence done. EventKit then
+    /// moves the series on to its next due date, which is read back here.
+    pub fn complete_occurrence(&self, reminder_id: &str) -> Result<Option<DateTime<Local>>> {
+        let ns_id = NSString::from_str(reminder_id);
+        let item = unsafe { self.store.calendarItemWithIdentifier(&ns_id) }
+            .ok_or_else(|| eyre!("Reminder not found"))?;
+        let reminder: &EKReminder = unsafe { &*((&*item) as *const _ as *const EKReminder) };
+
+        unsafe { reminder.setCompleted(true) };
+        unsafe {
+            self.store.saveReminder_commit_error(reminder, true)
+                .map_err(|e| eyre!("Failed to save reminder: {:?}", e))?;
+        }
+
+        let next = unsafe { self.store.calendarItemWithIdentifier(&ns_id) }.and_then(|item| {
+            let reminder: &EKReminder = unsafe { &*((&*item) as *const _ as *const EKReminder) };
+            convert_reminder(reminder).and_then(|r| r.due_date)
+        });
+        Ok(next)
+    }
+
+    pub fn delete_reminder(&self, reminder_id: &str) -> Result<()> {
+        let ns_id = NSString::from_str(reminder_id);
+        let item = unsafe { self.store.calendarItemWithIdentifier(&ns_id) }
+            .ok_or_else(|| eyre!("Reminder not found"))?;
+        let reminder: &EKReminder = unsafe { &*((&*item) as *const _ as *const EKReminder) };
+
+        unsafe {
+            self.store.removeReminder_commit_error(reminder, true)
+                .map_err(|e| eyre!("Failed to delete reminder: {:?}", e))?;
+        }
+        Ok(())
+    }
+
+    pub fn update_reminder(
+        &self,
+        reminder_id: &str,
+        title: &str,
+        due_date: Option<NaiveDate>,
+        priority: u8,
+        calendar_id: Option<&str>,
+    ) -> Result<()> {
+        let ns_id = NSString::from_str(reminder_id);
+        let item = unsafe { self.store.calendarItemWithIdentifier(&ns_id) }
+            .ok_or_else(|| eyre!("Reminder not found"))?;
+
+        // Cast to EKReminder
+        let reminder: &EKReminder = unsafe { &*((&*item) as *const _ as *const EKReminder) };
+
+        let ns_title = NSString::from_str(title);
+        unsafe {
+            reminder.setTitle(Some(&ns_title));
+            reminder.setPriority(priority as usize);
+        }
+
+        match due_date {
+            Some(date) => {
+                let components = NSDateComponents::new();
+                components.setYear(date.year() as isize);
+                components.setMonth(date.month() as isize);
+                components.setDay(date.day() as isize);
+                // Only the day is edited here; keep a due time if it has one
+                if let Some(old) = unsafe { reminder.dueDateComponents() } {
+                    components.setHour(old.hour());
+                    components.setMinute(old.minute());
+                    components.setTimeZone(old.timeZone().as_deref());
+                }
+                unsafe { reminder.setDueDateComponents(Some(&components)) };
+            }
+            None => unsafe { reminder.setDueDateComponents(None) },
+        }
+
+        if let Some(cal_id) = calendar_id {
+            let ns_cal_id = NSString::from_str(cal_id);
+            if let Some(cal) = unsafe { self.store.calendarWithIdentifier(&ns_cal_id) } {
+                unsafe { reminder.setCalendar(Some(&cal)) };
+            }
+        }
+
+        unsafe {
+            self.store.saveReminder_commit_error(reminder, true)
+                .map_err(|e| eyre!("Failed to save reminder: {:?}", e))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for Store {
+    fn drop(&mut self) {
+        unsafe { NSNotificationCenter::defaultCenter().removeObserver(self.observer.as_ref()) };
+    }
+}
+
+impl Backend for Store {
+    #[instrument(level = "debug", skip_all, ret, err)]
+    fn request_access(&self) -> Result<bool> {
+        Store::request_access(self)
+    }
+
+    #[instrument(level = "debug", skip_all, ret, err)]
+    fn request_access_waiting(&self, waiting: &mut dyn FnMut()) -> Result<bool> {
+        Store::request_access_waiting(self, waiting)
+    }
+
+    fn permissions(&self) -> Permissions {
+        Store::permissions(self)
+    }
+
+    fn calendars(&self) -> Vec<CalendarInfo> {
+        Store::calendars(self)
+    }
+
+    fn take_changes(&self) -> bool {
+        let changed = Store::take_changes(self);
+        if changed {
+            debug!("store changed");
+        }
+        changed
+    }
+
+    #[instrument(level = "debug", skip_all)]
+    fn refresh_sources(&self) {
+        Store::refresh_sources(self)
+    }
+
+    fn reminder_calendars(&self) -> Vec<CalendarInfo> {
+        Store::reminder_calendars(self)
+    }
+
+    fn prefetcher(&self) -> Option<Prefetcher> {
+        Some(Prefetcher::spawn(|| {
+            // EventKit objects stay on the thread that made them, so the
+            // worker opens a store of its own
+            let store = Store::new().ok()?;
+            let mut generation = None;
+            Some(move |request: &Request| {
+                // Forget what the store cached before events changed
+                if generation.replace(request.generation).is_some_and(|g| g != request.generation) {
+                    unsafe { store.store.reset() };
+                }
+                objc2::rc::autoreleasepool(|_| store.query_events(request.start, request.end))
+            })
+        }))
+    }
+
+    fn events_in_range(&self, start: DateTime<Local>, end: DateTime<Local>) -> Vec<CalendarEvent> {
+        let events = Store::events_in_range(self, start, end);
+        debug!(%start, %end, count = events.len(), "events_in_range");
+        events
+    }
+
+    fn query_events(&self, start: DateTime<Local>, end: DateTime<Local>) -> EventQuery {
+        let mut gaps = Vec::new();
+        let limit = start + chrono::Duration::days(MAX_QUERY_DAYS);
+        if end > limit {
+            gaps.push(format!(
+                "EventKit searches four years at most; nothing after {} was loaded",
+                limit.format("%b %-d, %Y")
+            ));
+        }
+        if !self.permissions().events.can_read() {
+            gaps.push("Events can't be read without full calendar access".to_string());
+        }
+        let events = Store::events_in_range(self, start, end.min(limit));
+        debug!(%start, %end, count = events.len(), ?gaps, "query_events");
+        EventQuery { events, gaps }
+    }
+
+    #[instrument(level = "debug", skip_all, fields(all_day = event.is_all_day, calendar = ?event.calendar_id), err)]
+    fn create_event(&self, event: &NewEvent) -> Result<()> {
+        Store::create_event(self, event)
+    }
+
+    #[instrument(level = "debug", skip(self), err)]
+    fn delete_event(&self, event_id: &str) -> Result<()> {
+        Store::delete_event(self, event_id)
+    }
+
+    #[instrument(level = "debug", skip_all, fields(count = event_ids.len()), ret, err)]
+    fn delete_events(&self, event_ids: &[String]) -> Result<usize> {
+        Store::delete_events(self, event_ids)
+    }
+
+    #[instrument(level = "debug", skip(self), err)]
+    fn move_event(&self, event_id: &str, days: i64) -> Result<()> {
+        Store::move_event(self, event_id, days)
+    }
+
+    #[instrument(level = "debug", skip(self), fields(%start, %end), err)]
+    fn reschedule_event(&self, event_id: &str, start: DateTime<Local>, end: DateTime<Local>) -> Result<()> {
+        Store::reschedule_event(self, event_id, start, end)
+    }
+
+    #[instrument(level = "debug", skip_all, fields(count = changes.len()), err)]
+    fn reschedule_events(&self, changes: &[Reschedule]) -> Result<()> {
+        Store::reschedule_events(self, changes)
+    }
+
+    fn fetch_incomplete_reminders(&self) -> Vec<Reminder> {
+        let reminders = Store::fetch_incomplete_reminders(self);
+        debug!(count = reminders.len(), "fetch_incomplete_reminders");
+        reminders
+    }
+
+    fn fetch_completed_reminders(&self) -> Vec<Reminder> {
+        let reminders = Store::fetch_completed_reminders(self);
+        debug!(count = reminders.len(), "fetch_completed_reminders");
+        reminders
+    }
+
+    #[instrument(level = "debug", skip(self, title), ret, err)]
+    fn create_reminder(&self, title: &str, due_date: Option<NaiveDate>, calendar_id: Option<&str>) -> Result<String> {
+        Store::create_reminder(self, title, due_date, calendar_id)
+    }
+
+    #[instrument(level = "debug", skip(self), ret, err)]
+    fn toggle_reminder(&self, reminder_id: &str) -> Result<bool> {
+        Store::toggle_reminder(self, reminder_id)
+    }
+
+    #[instrument(level = "debug", skip(self), ret, err)]
+    fn complete_occurrence(&self, reminder_id: &str) -> Result<Option<DateTime<Local>>> {
+        Store::complete_occurrence(self, reminder_id)
+    }
+
+    #[instrument(level = "debug", skip(self), err)]
+    fn delete_reminder(&self, reminder_id: &str) -> Result<()> {
+        Store::delete_reminder(self, reminder_id)
+    }
+
+    #[instrument(level = "debug", skip(self, title), err)]
+    fn update_reminder(
+        &self,
+        reminder_id: &str,
+        title: &str,
+        due_date: Option<NaiveDate>,
+        priority: u8,
+        calendar_id: Option<&str>,
+    ) -> Result<()> {
+        Store::update_reminder(self, reminder_id, title, due_date, priority, calendar_id)
+    }
 }
 
 // ── Helper functions ──
@@ -438,7 +853,8 @@ fn convert_calendars(ek_calendars: &NSArray<objc2_event_kit::EKCalendar>) -> Vec
     calendars
 }
 
-fn convert_event(ev: &EKEvent) -> Option<CalendarEvent> {
+/// Convert an EventKit event into the app model (without store lookups).
+pub fn convert_event(ev: &EKEvent) -> Option<CalendarEvent> {
     let id = unsafe {
         ev.eventIdentifier()
             .map(|s| s.to_string())
@@ -450,18 +866,158 @@ fn convert_event(ev: &EKEvent) -> Option<CalendarEvent> {
     let is_all_day = unsafe { ev.isAllDay() };
     let location = unsafe { ev.location().map(|s| s.to_string()) };
     let notes = unsafe { ev.notes().map(|s| s.to_string()) };
-    let (calendar_name, calendar_color) = unsafe {
+    let url = unsafe { ev.URL() }.and_then(|u| u.absoluteString()).map(|s| s.to_string());
+    let uid = unsafe { ev.calendarItemExternalIdentifier() }.map(|s| s.to_string());
+    let (calendar_id, calendar_name, calendar_color) = unsafe {
         ev.calendar()
-            .map(|cal| (cal.title().to_string(), calendar_color(&cal)))
-            .unwrap_or(("Unknown".to_string(), Color::White))
+            .map(|cal| {
+                (
+                    cal.calendarIdentifier().to_string(),
+                    cal.title().to_string(),
+                    calendar_color(&cal),
+                )
+            })
+            .unwrap_or((String::new(), "Unknown".to_string(), Color::White))
     };
 
+    let origin = event_origin(ev, &calendar_name);
+    let attendees: Vec<Participant> = unsafe { ev.attendees() }
+        .map(|list| list.iter().map(|p| convert_participant(&p)).collect())
+        .unwrap_or_default();
+    let organizer = unsafe { ev.organizer() }.map(|p| convert_participant(&p));
+    let declined = attendees.iter().any(|a| a.is_me && a.status == ParticipantStatus::Declined);
+    let recurrence = recurrence_of(ev);
+    let occurrence_start = recurrence
+        .as_ref()
+        .and_then(|_| unsafe { ev.occurrenceDate() })
+        .map(|d| nsdate_to_datetime(&d));
+
     Some(CalendarEvent {
         id, title, start, end, is_all_day,
-        calendar_name, calendar_color, location, notes,
+        calendar_id, calendar_name, calendar_color, location, url, notes,
+        origin_year: None,
+        origin,
+        declined,
+        recurrence,
+        organizer,
+        attendees,
+        uid,
+        occurrence_start,
     })
 }
 
+fn access(status: EKAuthorizationStatus) -> Access {
+    match status {
+        EKAuthorizationStatus::FullAccess => Access::Full,
+        EKAuthorizationStatus::WriteOnly => Access::WriteOnly,
+        EKAuthorizationStatus::Denied => Access::Denied,
+        EKAuthorizationStatus::Restricted => Access::Restricted,
+        _ => Access::NotDetermined,
+    }
+}
+
+fn convert_participant(p: &EKParticipant) -> Participant {
+    let url = unsafe { p.URL() };
+    let email = url
+        .resourceSpecifier()
+        .map(|s| s.to_string())
+        .filter(|_| url.scheme().is_some_and(|s| s.to_string().eq_ignore_ascii_case("mailto")));
+    let name = unsafe { p.name() }
+        .map(|n| n.to_string())
+        .filter(|n| !n.is_empty())
+        .or_else(|| email.clone())
+        .unwrap_or_else(|| "Unknown".to_string());
+    let status = match unsafe { p.participantStatus() } {
+        EKParticipantStatus::Accepted => ParticipantStatus::Accepted,
+        EKParticipantStatus::Declined => ParticipantStatus::Declined,
+        EKParticipantStatus::Tentative => ParticipantStatus::Tentative,
+        _ => ParticipantStatus::Pending,
+    };
+    Participant { name, email, status, is_me: unsafe { p.isCurrentUser() } }
+}
+
+/// First recurrence rule of the event's series, if any. Calendar.app only
+/// ever creates one, so the rest are ignored.
+fn recurrence_of(ev: &EKEvent) -> Option<Recurrence> {
+    let rules = unsafe { ev.recurrenceRules() }?;
+    let rule = rules.iter().next()?;
+    let frequency = match unsafe { rule.frequency() } {
+        EKRecurrenceFrequency::Daily => Frequency::Daily,
+        EKRecurrenceFrequency::Weekly => Frequency::Weekly,
+        EKRecurrenceFrequency::Monthly => Frequency::Monthly,
+        EKRecurrenceFrequency::Yearly => Frequency::Yearly,
+        _ => return None,
+    };
+    let numbers = |list: Option<Retained<NSArray<NSNumber>>>| -> Vec<i32> {
+        list.map(|l| l.iter().map(|n| n.intValue()).collect()).unwrap_or_default()
+    };
+
+    let mut rec = Recurrence::new(frequency);
+    rec.interval = unsafe { rule.interval() }.max(1) as u32;
+    rec.by_day = unsafe { rule.daysOfTheWeek() }
+        .map(|days| {
+            days.iter()
+                .filter_map(|d| {
+                    // EKWeekday counts from Sunday = 1
+                    let weekday = match unsafe { d.dayOfTheWeek() }.0 {
+                        1 => Weekday::Sun,
+                        n @ 2..=7 => Weekday::try_from(n as u8 - 2).ok()?,
+                        _ => return None,
+                    };
+                    let nth = match unsafe { d.weekNumber() } {
+                        0 => None,
+                        n => Some(n as i32),
+                    };
+                    Some(DayOfWeek { weekday, nth })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    rec.by_month_day = numbers(unsafe { rule.daysOfTheMonth() });
+    rec.by_month = numbers(unsafe { rule.monthsOfTheYear() })
+        .into_iter()
+        .map(|m| m as u32)
+        .collect();
+    rec.by_set_pos = numbers(unsafe { rule.setPositions() });
+    if let Some(end) = unsafe { rule.recurrenceEnd() } {
+        match unsafe { end.endDate() } {
+            Some(date) => rec.until = Some(nsdate_to_datetime(&date).date_naive()),
+            None => rec.count = Some(unsafe { end.occurrenceCount() } as u32),
+        }
+    }
+    Some(rec)
+}
+
+/// Best guess at where an event came from. EventKit has no explicit
+/// "added by" field, so this combines the calendar type with the organizer.
+fn event_origin(ev: &EKEvent, calendar_name: &str) -> EventOrigin {
+    // Siri-found events live in their own calendar, which has no distinct
+    // type or source, so only its name tells it apart. These are the English
+    // names; with the system in another language the calendar is localized
+    // and its events fall through to the checks below, usually as
+    // `UserCreated`.
+    if matches!(calendar_name, "Siri Suggestions" | "Found in Apps") {
+        return EventOrigin::Suggestion;
+    }
+    match unsafe { ev.calendar() }.map(|cal| unsafe { cal.r#type() }) {
+        Some(EKCalendarType::Birthday) => return EventOrigin::Birthday,
+        Some(EKCalendarType::Subscription) => return EventOrigin::Subscription,
+        _ => {}
+    }
+    match unsafe { ev.organizer() } {
+        Some(org) if unsafe { org.isCurrentUser() } => EventOrigin::Organized,
+        Some(org) => EventOrigin::Invitation {
+            organizer: unsafe { org.name() }.map(|n| n.to_string()).unwrap_or_default(),
+        },
+        None => EventOrigin::UserCreated,
+    }
+}
+
+fn is_birthday_event(ev: &EKEvent) -> bool {
+    unsafe { ev.calendar() }
+        .is_some_and(|cal| unsafe { cal.r#type() } == EKCalendarType::Birthday)
+}
+
 fn convert_reminder(r: &EKReminder) -> Option<Reminder> {
     let id = unsafe {
         // EKReminder inherits from EKCalendarItem which has calendarItemIdentifier
@@ -472,6 +1028,7 @@ fn convert_reminder(r: &EKReminder) -> Option<Reminder> {
     let title = unsafe { r.title().to_string() };
     let is_completed = unsafe { r.isCompleted() };
     let priority = unsafe { r.priority() } as u8;
+    let is_recurring = unsafe { r.hasRecurrenceRules() };
 
     let due_date = unsafe {
         r.dueDateComponents().and_then(|components| {
@@ -491,15 +1048,21 @@ fn convert_reminder(r: &EKReminder) -> Option<Reminder> {
         })
     };
 
-    let (calendar_name, calendar_color) = unsafe {
+    let (calendar_id, calendar_name, calendar_color) = unsafe {
         r.calendar()
-            .map(|cal| (cal.title().to_string(), calendar_color(&cal)))
-            .unwrap_or(("Unknown".to_string(), Color::White))
+            .map(|cal| {
+                (
+                    cal.calendarIdentifier().to_string(),
+                    cal.title().to_string(),
+                    calendar_color(&cal),
+                )
+            })
+            .unwrap_or((String::new(), "Unknown".to_string(), Color::White))
     };
 
     Some(Reminder {
         id, title, is_completed, due_date,
-        calendar_name, calendar_color, priority,
+        calendar_id, calendar_name, calendar_color, priority, is_recurring,
     })
 }
 
@@ -530,7 +1093,7 @@ fn calendar_color(cal: &objc2_event_kit::EKCalendar) -> Color {
     Color::White
 }
 
-fn datetime_to_nsdate(dt: &DateTime<Local>) -> Retained<NSDate> {
+pub(super) fn datetime_to_nsdate(dt: &DateTime<Local>) -> Retained<NSDate> {
     let unix_ts = dt.timestamp() as f64;
     let nsdate_ts = unix_ts - NSDATE_UNIX_OFFSET;
     NSDate::dateWithTimeIntervalSinceReferenceDate(nsdate_ts)
@@ -0,0 +1,165 @@
+//! Clock format used wherever times are shown or typed.
+
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+
+use chrono::{DateTime, Duration, Local, NaiveDateTime, NaiveTime, TimeZone, Timelike};
+use serde::{Deserialize, Serialize};
+
+static TWELVE_HOUR: AtomicBool = AtomicBool::new(false);
+static HOUR_LABELS: AtomicU8 = AtomicU8::new(0);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum TimeFormat {
+    /// "14:30"
+    #[default]
+    #[serde(rename = "24h")]
+    H24,
+    /// "2:30 PM"
+    #[serde(rename = "12h")]
+    H12,
+}
+
+impl TimeFormat {
+    /// The format in effect, as set from the config at startup.
+    pub fn current() -> Self {
+        if TWELVE_HOUR.load(Ordering::Relaxed) {
+            TimeFormat::H12
+        } else {
+            TimeFormat::H24
+        }
+    }
+
+    pub fn set_current(self) {
+        TWELVE_HOUR.store(self == TimeFormat::H12, Ordering::Relaxed);
+    }
+
+    pub fn format(self, time: NaiveTime) -> String {
+        match self {
+            TimeFormat::H24 => time.format("%H:%M").to_string(),
+            TimeFormat::H12 => time.format("%-I:%M %p").to_string(),
+        }
+    }
+
+    /// Hour label for time grids: "9:00" or "9 AM". `short` drops to
+    /// "9" / "9a" for narrow columns.
+    pub fn hour_label(self, hour: u32, short: bool) -> String {
+        match (self, short) {
+            (TimeFormat::H24, false) => format!("{}:00", hour),
+            (TimeFormat::H24, true) => hour.to_string(),
+            (TimeFormat::H12, _) => {
+                let h12 = match hour % 12 {
+                    0 => 12,
+                    h => h,
+                };
+                let (am, pm) = if short { ("a", "p") } else { (" AM", " PM") };
+                format!("{}{}", h12, if hour % 24 < 12 { am } else { pm })
+            }
+        }
+    }
+
+    /// Parse a typed time. Both clocks are accepted regardless of the
+    /// format: "14:30", "2:30 PM", "2:30pm", "2pm".
+    pub fn parse(input: &str) -> Option<NaiveTime> {
+        let input = input.trim();
+        if let Ok(t) = NaiveTime::parse_from_str(input, "%H:%M") {
+            return Some(t);
+        }
+        let lower = input.to_ascii_lowercase();
+        let (rest, pm) = if let Some(rest) = lower.strip_suffix("pm") {
+            (rest, true)
+        } else if let Some(rest) = lower.strip_suffix("am") {
+            (rest, false)
+        } else {
+            return None;
+        };
+        let rest = rest.trim();
+        let (hour, minute) = match rest.split_once(':') {
+            Some((h, m)) if m.len() == 2 => (h.parse::<u32>().ok()?, m.parse::<u32>().ok()?),
+            Some(_) => return None,
+            None => (rest.parse::<u32>().ok()?, 0),
+        };
+        if !(1..=12).contains(&hour) {
+            return None;
+        }
+        let hour = hour % 12 + if pm { 12 } else { 0 };
+        NaiveTime::from_hms_opt(hour, minute, 0)
+    }
+
+    /// Parse a typed hour: a bare "14" or "9", or any time
+    /// [`parse`](Self::parse) accepts, whose minutes are dropped.
+    pub fn parse_hour(input: &str) -> Option<u32> {
+        match input.trim().parse::<u32>() {
+            Ok(hour) => (hour < 24).then_some(hour),
+            Err(_) => Self::parse(input).map(|t| t.hour()),
+        }
+    }
+}
+
+/// Style of the hour labels on time grids (week view, day timeline).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HourLabels {
+    /// Follow `time_format`, shortening in narrow columns.
+    #[default]
+    Auto,
+    /// "14:00"
+    #[serde(rename = "24h")]
+    H24,
+    /// "2 PM"
+    #[serde(rename = "12h")]
+    H12,
+    /// "2p" or "14", whatever the width.
+    Compact,
+}
+
+impl HourLabels {
+    const ALL: [HourLabels; 4] = [HourLabels::Auto, HourLabels::H24, HourLabels::H12, HourLabels::Compact];
+
+    /// The style in effect, as set from the config at startup.
+    pub fn current() -> Self {
+        Self::ALL[HOUR_LABELS.load(Ordering::Relaxed) as usize % Self::ALL.len()]
+    }
+
+    pub fn set_current(self) {
+        let idx = Self::ALL.iter().position(|&l| l == self).unwrap_or(0);
+        HOUR_LABELS.store(idx as u8, Ordering::Relaxed);
+    }
+
+    /// Label for `hour`; `narrow` asks for the short form where the style
+    /// allows it.
+    pub fn label(self, hour: u32, narrow: bool) -> String {
+        match self {
+            HourLabels::Auto => TimeFormat::current().hour_label(hour, narrow),
+            HourLabels::H24 => TimeFormat::H24.hour_label(hour, narrow),
+            HourLabels::H12 => TimeFormat::H12.hour_label(hour, narrow),
+            HourLabels::Compact => TimeFormat::current().hour_label(hour, true),
+        }
+    }
+}
+
+/// Format a time with the current [`TimeFormat`].
+pub fn format_time(time: impl Timelike) -> String {
+    let time = NaiveTime::from_hms_opt(time.hour(), time.minute(), 0).unwrap_or_default();
+    TimeFormat::current().format(time)
+}
+
+/// Move `time` by whole days, keeping its wall-clock time across DST
+/// changes. `None` when that time does not exist on the new day or the day
+/// is out of range.
+pub fn shift_days(time: DateTime<Local>, days: i64) -> Option<DateTime<Local>> {
+    let moved = time.naive_local().checked_add_signed(Duration::try_days(days)?)?;
+    Local.from_local_datetime(&moved).earliest()
+}
+
+/// Round up to the next multiple of `step_minutes` past the hour; times
+/// already on a step are kept. May roll over into the next day.
+pub fn snap_up(time: NaiveDateTime, step_minutes: u32) -> NaiveDateTime {
+    let step = step_minutes.max(1) as i64;
+    let trimmed = time.with_second(0).and_then(|t| t.with_nanosecond(0)).unwrap_or(time);
+    let minutes = (trimmed.hour() * 60 + trimmed.minute()) as i64;
+    let mut snapped = trimmed + Duration::minutes((step - minutes % step) % step);
+    if snapped < time {
+        snapped += Duration::minutes(step);
+    }
+    snapped
+}
@@ -0,0 +1,63 @@
+//! Finding video-call join links in event text.
+
+/// Conferencing services whose join links are recognized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VideoService {
+    Zoom,
+    Meet,
+    Teams,
+    Webex,
+}
+
+impl VideoService {
+    pub fn label(self) -> &'static str {
+        match self {
+            VideoService::Zoom => "Zoom",
+            VideoService::Meet => "Google Meet",
+            VideoService::Teams => "Microsoft Teams",
+            VideoService::Webex => "Webex",
+        }
+    }
+
+    /// The service a link with this host and path joins, if any.
+    fn of(host: &str, path: &str) -> Option<Self> {
+        let under = |domain: &str| host == domain || host.ends_with(&format!(".{}", domain));
+        if (under("zoom.us") || under("zoomgov.com"))
+            && ["/j/", "/my/", "/w/", "/s/"].iter().any(|p| path.starts_with(p))
+        {
+            Some(VideoService::Zoom)
+        } else if host == "meet.google.com" && path.len() > 1 {
+            Some(VideoService::Meet)
+        } else if (host == "teams.microsoft.com" && path.starts_with("/l/meetup-join"))
+            || (host == "teams.live.com" && path.starts_with("/meet"))
+        {
+            Some(VideoService::Teams)
+        } else if under("webex.com") && path.len() > 1 {
+            Some(VideoService::Webex)
+        } else {
+            None
+        }
+    }
+}
+
+/// A join link and the service it belongs to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VideoLink {
+    pub service: VideoService,
+    pub url: String,
+}
+
+/// The first video-call link in `text`. Links may be wrapped in `<>` or
+/// quotes, as invitation bodies often do.
+pub fn find_link(text: &str) -> Option<VideoLink> {
+    text.split(|c: char| c.is_whitespace() || matches!(c, '<' | '>' | '"' | '\'' | '(' | ')'))
+        .filter_map(|word| {
+            let url = word.trim_end_matches(['.', ',', ';', ':', '!']);
+            let rest = url.strip_prefix("https://").or_else(|| url.strip_prefix("http://"))?;
+            let split = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+            let host = rest[..split].split(':').next()?.to_ascii_lowercase();
+            let service = VideoService::of(&host, &rest[split..])?;
+            Some(VideoLink { service, url: url.to_string() })
+        })
+        .next()
+}
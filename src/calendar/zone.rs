@@ -0,0 +1,90 @@
+//! Named timezones from the system database, used by travel mode to show
+//! the calendar as it reads in another city.
+
+use chrono::{DateTime, Duration, Local, NaiveDateTime, TimeZone, Utc};
+use color_eyre::eyre::{eyre, Result};
+use objc2::rc::Retained;
+use objc2_foundation::{NSString, NSTimeZone};
+
+use super::event::{CalendarEvent, NewEvent};
+use super::store::datetime_to_nsdate;
+
+/// A timezone such as `America/New_York`.
+#[derive(Debug, Clone)]
+pub struct Zone {
+    name: String,
+    tz: Retained<NSTimeZone>,
+}
+
+impl Zone {
+    /// Look up `America/New_York` style names, falling back to
+    /// abbreviations such as `PST`.
+    pub fn named(name: &str) -> Result<Self> {
+        let ns_name = NSString::from_str(name.trim());
+        let tz = NSTimeZone::timeZoneWithName(&ns_name)
+            .or_else(|| NSTimeZone::timeZoneWithAbbreviation(&ns_name))
+            .ok_or_else(|| eyre!("Unknown timezone: {}", name))?;
+        Ok(Self { name: tz.name().to_string(), tz })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Offset from UTC at `at`, following the zone's daylight saving rules.
+    pub fn offset_at(&self, at: DateTime<Utc>) -> Duration {
+        let date = datetime_to_nsdate(&at.with_timezone(&Local));
+        Duration::seconds(self.tz.secondsFromGMTForDate(&date) as i64)
+    }
+
+    /// `UTC+05:30` style label for the offset at `at`.
+    pub fn offset_label(&self, at: DateTime<Utc>) -> String {
+        let minutes = self.offset_at(at).num_minutes();
+        let sign = if minutes < 0 { '-' } else { '+' };
+        format!("UTC{}{:02}:{:02}", sign, minutes.abs() / 60, minutes.abs() % 60)
+    }
+
+    /// The local time whose clock reading matches `time` as read in this zone.
+    pub fn to_display(&self, time: DateTime<Local>) -> DateTime<Local> {
+        let utc = time.with_timezone(&Utc);
+        Local
+            .from_local_datetime(&(utc.naive_utc() + self.offset_at(utc)))
+            .earliest()
+            .unwrap_or(time)
+    }
+
+    /// Inverse of [`to_display`](Self::to_display): the real local time shown as `time`.
+    pub fn to_local(&self, time: DateTime<Local>) -> DateTime<Local> {
+        self.local_at(time.naive_local())
+    }
+
+    /// Shift a timed event's start and end into this zone. All-day events
+    /// are dates, not instants, and stay put.
+    pub fn shift_event(&self, ev: &mut CalendarEvent) {
+        if !ev.is_all_day {
+            ev.start = self.to_display(ev.start);
+            ev.end = self.to_display(ev.end);
+        }
+    }
+
+    /// Read the times of an event entered in travel mode as this zone's clock.
+    pub fn new_event_from_display(&self, new: &NewEvent) -> NewEvent {
+        let mut real = new.clone();
+        if new.is_all_day {
+            return real;
+        }
+        let start = self.local_at(new.date.and_time(new.start_time));
+        let end = self.local_at(new.end_date.and_time(new.end_time));
+        (real.date, real.start_time) = (start.date_naive(), start.time());
+        (real.end_date, real.end_time) = (end.date_naive(), end.time());
+        real
+    }
+
+    /// The local time of `wall` read on this zone's clock. The offset is
+    /// taken twice so a wall time near a DST change lands on the right side.
+    fn local_at(&self, wall: NaiveDateTime) -> DateTime<Local> {
+        let guess = Utc.from_utc_datetime(&wall);
+        let first = guess - self.offset_at(guess);
+        (guess - self.offset_at(first)).with_timezone(&Local)
+    }
+}
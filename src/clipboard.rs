@@ -0,0 +1,31 @@
+//! System clipboard access through the macOS `pbpaste` and
+//! `pbcopy` tools.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use color_eyre::eyre::{eyre, Result};
+
+/// Current text contents of the clipboard.
+pub fn read_text() -> Result<String> {
+    let output = Command::new("pbpaste").output()?;
+    if !output.status.success() {
+        return Err(eyre!("pbpaste exited with {}", output.status));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Replace the clipboard contents with `text`.
+pub fn write_text(text: &str) -> Result<()> {
+    let mut child = Command::new("pbcopy").stdin(Stdio::piped()).spawn()?;
+    child
+        .stdin
+        .take()
+        .ok_or_else(|| eyre!("pbcopy has no stdin"))?
+        .write_all(text.as_bytes())?;
+    let status = child.wait()?;
+    if !status.success() {
+        return Err(eyre!("pbcopy exited with {}", status));
+    }
+    Ok(())
+}
@@ -0,0 +1,305 @@
+use std::path::PathBuf;
+
+use chrono::{Datelike, NaiveDate};
+use color_eyre::eyre::{eyre, Result};
+
+use crate::app::ViewMode;
+use crate::calendar::{CalendarInfo, TimeFormat};
+use crate::config::{self, Filter};
+use crate::shift;
+use crate::theme;
+
+/// Maximum number of entries kept in the history file.
+const HISTORY_LIMIT: usize = 500;
+
+/// Weeks looked at by `:officehours` without an argument.
+const DEFAULT_OFFICE_HOURS_WEEKS: u32 = 4;
+
+/// Most weeks `:officehours` will look at, a year ahead.
+pub const MAX_OFFICE_HOURS_WEEKS: u32 = 52;
+
+/// Age in days used by `:cleanup` without an argument.
+const DEFAULT_CLEANUP_DAYS: i64 = 30;
+
+/// Command names accepted on the `:` command line.
+pub const COMMANDS: &[&str] = &[
+    "cleanup", "duplicates", "filter", "focus", "goto", "hide", "officehours", "paste", "quit",
+    "shift", "show", "status", "template", "theme", "today", "travel", "view",
+];
+
+const VIEWS: &[&str] = &["day", "month", "week"];
+const DATE_WORDS: &[&str] = &["today", "tomorrow", "yesterday"];
+
+/// A parsed `:` command.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    Quit,
+    Today,
+    Goto(NaiveDate),
+    View(ViewMode),
+    Hide(String),
+    Show(String),
+    /// Switch to a saved filter; `None` clears it.
+    Filter(Option<String>),
+    /// Review stale suggested and declined events older than N days.
+    Cleanup(i64),
+    /// Review events duplicated across calendars.
+    Duplicates,
+    /// Open the event form filled in from the clipboard text.
+    Paste,
+    /// Block the selected day with an all-day busy "Focus day" event.
+    Focus,
+    /// Copy the weekly slots free in each of the next N weeks.
+    OfficeHours(u32),
+    /// Show times in another timezone; `None` returns to local time.
+    Travel(Option<String>),
+    /// Show permissions, accounts and the last fetch time.
+    Status,
+    /// Move the events starting from one date through another by a number
+    /// of minutes.
+    Shift { from: NaiveDate, to: NaiveDate, minutes: i64 },
+    /// Copy the events of a day, or of its week, onto each day or week
+    /// from `target` through `until`.
+    Template { week: bool, source: NaiveDate, target: NaiveDate, until: NaiveDate },
+    /// Bring an hour of the selected day into view on the time grid, as
+    /// in `@14` or `@2pm`.
+    Hour(u32),
+    /// Switch to a built-in theme until the next launch.
+    Theme(String),
+}
+
+/// Parse a command line (without the leading `:`).
+pub fn parse(input: &str, today: NaiveDate) -> Result<Command> {
+    let input = input.trim();
+    let (name, arg) = match input.split_once(char::is_whitespace) {
+        Some((name, arg)) => (name, arg.trim()),
+        None => (input, ""),
+    };
+
+    match name {
+        "q" | "quit" => Ok(Command::Quit),
+        "today" => Ok(Command::Today),
+        "goto" | "g" => parse_date(arg, today)
+            .map(Command::Goto)
+            .ok_or_else(|| eyre!("Invalid date: {}", arg)),
+        "view" if arg.starts_with("as ") => Ok(Command::Travel(Some(arg[3..].trim().to_string()))),
+        "view" => match arg {
+            "month" | "m" => Ok(Command::View(ViewMode::Month)),
+            "week" | "w" => Ok(Command::View(ViewMode::Week)),
+            "day" | "d" => Ok(Command::View(ViewMode::Day)),
+            _ => Err(eyre!("Unknown view: {}", arg)),
+        },
+        "hide" if !arg.is_empty() => Ok(Command::Hide(arg.to_string())),
+        "show" if !arg.is_empty() => Ok(Command::Show(arg.to_string())),
+        "hide" | "show" => Err(eyre!("Usage: {} <calendar>", name)),
+        "filter" | "f" => match arg {
+            "" | "off" | "none" => Ok(Command::Filter(None)),
+            _ => Ok(Command::Filter(Some(arg.to_string()))),
+        },
+        "cleanup" => match arg {
+            "" => Ok(Command::Cleanup(DEFAULT_CLEANUP_DAYS)),
+            _ => arg
+                .parse::<i64>()
+                .ok()
+                .filter(|&n| n >= 0)
+                .map(Command::Cleanup)
+                .ok_or_else(|| eyre!("Usage: cleanup [days]")),
+        },
+        "duplicates" | "dups" => Ok(Command::Duplicates),
+        "paste" => Ok(Command::Paste),
+        "focus" => Ok(Command::Focus),
+        "status" | "health" => Ok(Command::Status),
+        "officehours" | "oh" => match arg {
+            "" => Ok(Command::OfficeHours(DEFAULT_OFFICE_HOURS_WEEKS)),
+            _ => arg
+                .parse::<u32>()
+                .ok()
+                .filter(|n| (1..=MAX_OFFICE_HOURS_WEEKS).contains(n))
+                .map(Command::OfficeHours)
+                .ok_or_else(|| eyre!("Usage: officehours [1-{} weeks]", MAX_OFFICE_HOURS_WEEKS)),
+        },
+        "travel" | "tz" => match arg {
+            "" => Err(eyre!("Usage: travel <timezone>|off")),
+            "off" | "local" | "home" => Ok(Command::Travel(None)),
+            _ => Ok(Command::Travel(Some(arg.to_string()))),
+        },
+        "theme" => match arg {
+            "" => Err(eyre!("Usage: theme <{}>", theme::PRESETS.join("|"))),
+            _ if theme::PRESETS.contains(&arg) => Ok(Command::Theme(arg.to_string())),
+            _ => Err(eyre!("Unknown theme: {}", arg)),
+        },
+        "shift" => parse_shift(arg, today).ok_or_else(|| eyre!("Usage: shift <date> [<end date>] <+1h|-30m|+7d>")),
+        "template" => parse_template(arg, today)
+            .ok_or_else(|| eyre!("Usage: template [week] <source date> <target date> [<until date>]")),
+        _ if name.starts_with('@') => TimeFormat::parse_hour(&input[1..])
+            .map(Command::Hour)
+            .ok_or_else(|| eyre!("Usage: @<hour>, e.g. @14 or @2pm")),
+        "" => Err(eyre!("Empty command")),
+        _ => Err(eyre!("Unknown command: {}", name)),
+    }
+}
+
+/// `<date> [<end date>] <delta>`; the range includes both dates.
+fn parse_shift(arg: &str, today: NaiveDate) -> Option<Command> {
+    let words: Vec<&str> = arg.split_whitespace().collect();
+    let (delta, dates) = words.split_last()?;
+    let minutes = shift::parse_delta(delta)?;
+    let (from, to) = match dates {
+        [date] => (parse_date(date, today)?, parse_date(date, today)?),
+        [from, to] => (parse_date(from, today)?, parse_date(to, today)?),
+        _ => return None,
+    };
+    (from <= to).then_some(Command::Shift { from, to, minutes })
+}
+
+/// `[week] <source> <target> [<until>]`.
+fn parse_template(arg: &str, today: NaiveDate) -> Option<Command> {
+    let (week, arg) = match arg.strip_prefix("week ") {
+        Some(rest) => (true, rest),
+        None => (false, arg),
+    };
+    let dates = arg
+        .split_whitespace()
+        .map(|word| parse_date(word, today))
+        .collect::<Option<Vec<_>>>()?;
+    let (source, target, until) = match dates[..] {
+        [source, target] => (source, target, target),
+        [source, target, until] => (source, target, until),
+        _ => return None,
+    };
+    (target <= until).then_some(Command::Template { week, source, target, until })
+}
+
+/// Parse a date argument: `YYYY-MM-DD`, `MM-DD` (this year), `today`,
+/// `tomorrow`, `yesterday`, or a relative offset like `+3` / `-7`.
+pub fn parse_date(s: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let s = s.trim();
+    match s {
+        "today" => return Some(today),
+        "tomorrow" => return today.succ_opt(),
+        "yesterday" => return today.pred_opt(),
+        _ => {}
+    }
+    // Offsets past the calendar's range give `None` rather than overflowing
+    if let Some(days) = s.strip_prefix('+').and_then(|n| n.parse::<i64>().ok()) {
+        return today.checked_add_signed(chrono::Duration::try_days(days)?);
+    }
+    if let Some(days) = s.strip_prefix('-').and_then(|n| n.parse::<i64>().ok()) {
+        return today.checked_sub_signed(chrono::Duration::try_days(days)?);
+    }
+    NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .ok()
+        .or_else(|| NaiveDate::parse_from_str(&format!("{}-{}", today.year(), s), "%Y-%m-%d").ok())
+}
+
+/// Full-line completion candidates for a partially typed command line.
+pub fn completions(input: &str, calendars: &[CalendarInfo], filters: &[Filter]) -> Vec<String> {
+    match input.split_once(' ') {
+        None => COMMANDS
+            .iter()
+            .filter(|c| c.starts_with(input))
+            .map(|c| c.to_string())
+            .collect(),
+        Some((name, arg)) => {
+            let args: Vec<&str> = match name {
+                "view" => VIEWS.to_vec(),
+                "goto" | "g" => DATE_WORDS.to_vec(),
+                "travel" | "tz" => vec!["off"],
+                "theme" => theme::PRESETS.to_vec(),
+                "hide" | "show" => calendars.iter().map(|c| c.title.as_str()).collect(),
+                "filter" | "f" => filters
+                    .iter()
+                    .map(|f| f.name.as_str())
+                    .chain(std::iter::once("off"))
+                    .collect(),
+                _ => Vec::new(),
+            };
+            let arg_lower = arg.to_lowercase();
+            args.into_iter()
+                .filter(|a| a.to_lowercase().starts_with(&arg_lower))
+                .map(|a| format!("{} {}", name, a))
+                .collect()
+        }
+    }
+}
+
+/// Command line history, persisted to `~/.config/calendar-tui/history`.
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    entries: Vec<String>,
+    /// Position while browsing with Up/Down; `None` when editing a fresh line.
+    cursor: Option<usize>,
+}
+
+impl History {
+    pub fn load() -> Self {
+        let entries = history_path()
+            .and_then(|p| std::fs::read_to_string(p).ok())
+            .map(|content| {
+                content
+                    .lines()
+                    .filter(|l| !l.trim().is_empty())
+                    .map(|l| l.to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self { entries, cursor: None }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = history_path().ok_or_else(|| eyre!("No home directory"))?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let start = self.entries.len().saturating_sub(HISTORY_LIMIT);
+        let mut content = self.entries[start..].join("\n");
+        content.push('\n');
+        std::fs::write(&path, content)?;
+        Ok(())
+    }
+
+    /// Record an executed command, skipping immediate repeats.
+    pub fn push(&mut self, line: &str) {
+        self.cursor = None;
+        let line = line.trim();
+        if line.is_empty() || self.entries.last().map(|l| l.as_str()) == Some(line) {
+            return;
+        }
+        self.entries.push(line.to_string());
+        if self.entries.len() > HISTORY_LIMIT {
+            self.entries.remove(0);
+        }
+    }
+
+    /// Step back to an older entry.
+    pub fn older(&mut self) -> Option<&str> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        let idx = match self.cursor {
+            Some(i) => i.saturating_sub(1),
+            None => self.entries.len() - 1,
+        };
+        self.cursor = Some(idx);
+        self.entries.get(idx).map(|s| s.as_str())
+    }
+
+    /// Step forward to a newer entry; `None` once past the newest.
+    pub fn newer(&mut self) -> Option<&str> {
+        let idx = self.cursor? + 1;
+        if idx >= self.entries.len() {
+            self.cursor = None;
+            return None;
+        }
+        self.cursor = Some(idx);
+        self.entries.get(idx).map(|s| s.as_str())
+    }
+
+    pub fn reset_cursor(&mut self) {
+        self.cursor = None;
+    }
+}
+
+fn history_path() -> Option<PathBuf> {
+    config::config_dir().map(|d| d.join("history"))
+}
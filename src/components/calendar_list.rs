@@ -0,0 +1,71 @@
+use std::collections::HashSet;
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+
+use crate::calendar::CalendarInfo;
+use crate::theme;
+
+/// Popup for toggling which calendars are visible.
+pub struct CalendarList;
+
+impl CalendarList {
+    pub fn render(
+        frame: &mut Frame,
+        area: Rect,
+        calendars: &[CalendarInfo],
+        hidden: &HashSet<String>,
+        selected: usize,
+    ) {
+        let popup_w = area.width.clamp(30, 50);
+        let popup_h = (calendars.len() as u16 + 3).clamp(6, area.height.max(6));
+        let x = area.x + (area.width.saturating_sub(popup_w)) / 2;
+        let y = area.y + (area.height.saturating_sub(popup_h)) / 2;
+        let popup_area = Rect::new(x, y, popup_w, popup_h);
+
+        frame.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title(" Calendars ")
+            .title_style(Style::default().fg(Color::Green).add_modifier(Modifier::BOLD))
+            .title_bottom(Line::from(Span::styled(
+                " Space:Toggle Esc:Close ",
+                theme::current().dim,
+            )))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Green));
+
+        let items: Vec<ListItem> = calendars
+            .iter()
+            .map(|cal| {
+                let is_hidden = hidden.contains(&cal.id);
+                let checkbox = if is_hidden { " [ ] " } else { " [x] " };
+                let title_style = if is_hidden {
+                    theme::current().dim
+                } else {
+                    Style::default()
+                };
+                let mut spans = vec![
+                    Span::styled("  ", Style::default().bg(cal.color)),
+                    Span::raw(checkbox),
+                    Span::styled(cal.title.clone(), title_style),
+                ];
+                if !cal.source.is_empty() {
+                    spans.push(Span::styled(format!(" ({})", cal.source), theme::current().dim));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        let mut state = ListState::default().with_selected(Some(selected));
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(theme::current().highlight);
+        frame.render_stateful_widget(list, popup_area, &mut state);
+    }
+}
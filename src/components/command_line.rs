@@ -0,0 +1,76 @@
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::theme;
+
+/// Input state of the `:` command line.
+#[derive(Debug, Clone, Default)]
+pub struct CommandLineState {
+    pub input: String,
+    /// Candidates from the last Tab press, cycled by repeated presses.
+    pub completions: Vec<String>,
+    pub completion_index: Option<usize>,
+}
+
+impl CommandLineState {
+    pub fn input_char(&mut self, c: char) {
+        self.input.push(c);
+        self.clear_completions();
+    }
+
+    pub fn backspace(&mut self) {
+        self.input.pop();
+        self.clear_completions();
+    }
+
+    pub fn set_input(&mut self, input: &str) {
+        self.input = input.to_string();
+        self.clear_completions();
+    }
+
+    /// Apply the next completion candidate. `candidates` is only used when a
+    /// new completion cycle starts.
+    pub fn complete(&mut self, candidates: impl FnOnce(&str) -> Vec<String>) {
+        if self.completion_index.is_none() {
+            self.completions = candidates(&self.input);
+        }
+        if self.completions.is_empty() {
+            return;
+        }
+        let idx = self
+            .completion_index
+            .map(|i| (i + 1) % self.completions.len())
+            .unwrap_or(0);
+        self.completion_index = Some(idx);
+        self.input = self.completions[idx].clone();
+    }
+
+    fn clear_completions(&mut self) {
+        self.completions.clear();
+        self.completion_index = None;
+    }
+}
+
+pub struct CommandLine;
+
+impl CommandLine {
+    pub fn render(frame: &mut Frame, area: Rect, state: &CommandLineState) {
+        let mut spans = vec![
+            Span::styled(":", theme::current().header),
+            Span::raw(state.input.clone()),
+            Span::styled("_", theme::current().dim),
+        ];
+        if state.completions.len() > 1 {
+            let idx = state.completion_index.unwrap_or(0);
+            spans.push(Span::styled(
+                format!("  ({}/{})", idx + 1, state.completions.len()),
+                theme::current().dim,
+            ));
+        }
+        frame.render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+}
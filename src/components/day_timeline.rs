@@ -0,0 +1,192 @@
+use chrono::{Local, NaiveDate, Timelike};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::calendar::time::format_time;
+use crate::calendar::{CalendarEvent, HourLabels};
+use crate::components::day_view::{busy_badge, hidden_label};
+use crate::layout;
+use crate::text;
+use crate::theme;
+
+/// Width of the hour gutter, e.g. "12 PM ".
+const GUTTER_W: u16 = 6;
+
+/// Day view drawn as a single-column timeline, with event blocks placed
+/// and sized by start time and duration.
+pub struct DayTimeline;
+
+impl DayTimeline {
+    #[allow(clippy::too_many_arguments)]
+    pub fn render(
+        frame: &mut Frame,
+        area: Rect,
+        date: NaiveDate,
+        events: &[CalendarEvent],
+        selected_event: Option<&CalendarEvent>,
+        hours: (u32, u32),
+        hidden: usize,
+        busy_limit: Option<u32>,
+    ) {
+        let (hour_start, hour_end) = follow_selection(hours, date, selected_event);
+        let footer = if hidden > 0 {
+            format!(" Timeline {} ", hidden_label(hidden))
+        } else {
+            " Timeline ".to_string()
+        };
+        let mut title = vec![Span::raw(format!(" {} ", date.format("%A, %B %d, %Y")))];
+        title.extend(busy_badge(events, date, busy_limit));
+        let block = Block::default()
+            .title(Line::from(title))
+            .title_style(theme::current().header)
+            .title_bottom(Line::from(Span::styled(footer, theme::current().dim)))
+            .borders(Borders::ALL)
+            .border_style(theme::current().border);
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+        if inner.width <= GUTTER_W + 2 || inner.height < 3 {
+            return;
+        }
+
+        // All-day events share one strip above the grid
+        let all_day: Vec<&CalendarEvent> =
+            events.iter().filter(|ev| ev.is_all_day && ev.occurs_on(date)).collect();
+        let grid = if all_day.is_empty() {
+            inner
+        } else {
+            let parts = Layout::vertical([Constraint::Length(1), Constraint::Min(1)]).split(inner);
+            let mut spans = vec![Span::styled(format!("{:<w$}", "all-day", w = GUTTER_W as usize), theme::current().dim)];
+            for ev in all_day {
+                spans.push(Span::styled(
+                    format!(" {} ", ev.title),
+                    block_style(ev, selected_event),
+                ));
+                spans.push(Span::raw(" "));
+            }
+            frame.render_widget(Paragraph::new(Line::from(spans)), parts[0]);
+            parts[1]
+        };
+
+        let cols = Layout::horizontal([Constraint::Length(GUTTER_W), Constraint::Min(1)]).split(grid);
+        let (gutter, column) = (cols[0], cols[1]);
+
+        let first_min = hour_start * 60;
+        let span_min = (hour_end.saturating_sub(hour_start).max(1)) * 60;
+        let rows = column.height as u32;
+        // Row of a minute of the day, relative to the grid top
+        let row_of = |min: u32| (min.saturating_sub(first_min) * rows / span_min).min(rows);
+
+        for hour in hour_start..hour_end {
+            let y = row_of(hour * 60) as u16;
+            if y >= gutter.height {
+                break;
+            }
+            let label = format!(
+                "{:>w$} ",
+                HourLabels::current().label(hour, false),
+                w = GUTTER_W as usize - 1
+            );
+            frame.render_widget(
+                Paragraph::new(Line::from(Span::styled(label, theme::current().dim))),
+                Rect::new(gutter.x, gutter.y + y, gutter.width, 1),
+            );
+            frame.render_widget(
+                Paragraph::new(Line::from(Span::styled(
+                    "\u{2504}".repeat(column.width as usize),
+                    theme::current().dim,
+                ))),
+                Rect::new(column.x, column.y + y, column.width, 1),
+            );
+        }
+
+        for b in layout::timeline_blocks(events, date) {
+            if b.end_min <= first_min || b.start_min >= first_min + span_min {
+                continue;
+            }
+            let ev = &events[b.index];
+            let top = row_of(b.start_min);
+            let bottom = row_of(b.end_min).max(top + 1).min(rows);
+            if top >= rows {
+                continue;
+            }
+            let sub_w = column.width / b.columns as u16;
+            if sub_w == 0 {
+                continue;
+            }
+            let x = column.x + sub_w * b.column as u16;
+            // The last column absorbs the rounding remainder
+            let w = if b.column + 1 == b.columns { column.x + column.width - x } else { sub_w };
+            let rect = Rect::new(x, column.y + top as u16, w, (bottom - top) as u16);
+
+            let style = block_style(ev, selected_event);
+            let mut lines = vec![Line::from(Span::styled(
+                format!("{:<w$}", text::truncate(&ev.title, w as usize), w = w as usize),
+                style.add_modifier(Modifier::BOLD),
+            ))];
+            if rect.height >= 2 {
+                lines.push(Line::from(Span::styled(
+                    format!("{:<w$}", ev.time_range_on(date), w = w as usize),
+                    style,
+                )));
+            }
+            while lines.len() < rect.height as usize {
+                lines.push(Line::from(Span::styled(" ".repeat(w as usize), style)));
+            }
+            frame.render_widget(Paragraph::new(lines), rect);
+        }
+
+        // Current time line
+        let now = Local::now();
+        let now_min = now.hour() * 60 + now.minute();
+        if now.date_naive() == date && now_min >= first_min && now_min < first_min + span_min {
+            let y = grid.y + row_of(now_min).min(rows - 1) as u16;
+            let label = format!("{:<w$}", format_time(now), w = GUTTER_W as usize);
+            let red = Style::default().fg(Color::Red);
+            frame.render_widget(
+                Paragraph::new(Line::from(Span::styled(label, red.add_modifier(Modifier::BOLD)))),
+                Rect::new(gutter.x, y, gutter.width, 1),
+            );
+            frame.render_widget(
+                Paragraph::new(Line::from(Span::styled("\u{2500}".repeat(column.width as usize), red))),
+                Rect::new(column.x, y, column.width, 1),
+            );
+        }
+    }
+}
+
+/// Shift the hour window so the selected event's start is on screen,
+/// keeping the window's length.
+fn follow_selection(
+    (start, end): (u32, u32),
+    date: NaiveDate,
+    selected_event: Option<&CalendarEvent>,
+) -> (u32, u32) {
+    let Some(ev) = selected_event.filter(|ev| !ev.is_all_day) else {
+        return (start, end);
+    };
+    let hour = if ev.start.date_naive() < date { 0 } else { ev.start.hour() };
+    let span = end - start;
+    if hour < start {
+        (hour, hour + span)
+    } else if hour >= end {
+        let start = (hour + 1).saturating_sub(span);
+        (start, start + span)
+    } else {
+        (start, end)
+    }
+}
+
+fn block_style(ev: &CalendarEvent, selected_event: Option<&CalendarEvent>) -> Style {
+    let style = Style::default().fg(Color::Black).bg(ev.calendar_color);
+    if selected_event.is_some_and(|sel| sel.id == ev.id && sel.start == ev.start) {
+        style.add_modifier(Modifier::REVERSED)
+    } else {
+        style
+    }
+}
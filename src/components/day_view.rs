@@ -1,28 +1,45 @@
-use chrono::NaiveDate;
+use chrono::{DateTime, Local, NaiveDate};
 use ratatui::{
-    layout::Rect,
+    layout::{Constraint, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+    widgets::{
+        Block, Borders, Clear, List, ListItem, ListState, Paragraph, Scrollbar, ScrollbarOrientation,
+        ScrollbarState, Wrap,
+    },
     Frame,
 };
 
 use crate::app::DayAction;
-use crate::calendar::{CalendarEvent, Reminder};
+use crate::calendar::time::format_time;
+use crate::calendar::{CalendarEvent, Participant, ParticipantStatus, Reminder};
+use crate::components::reminder_form::priority_label;
+use crate::layout::{self, DayItems, DayRow, DaySection};
+use crate::text;
 use crate::theme;
 
+/// Gap between two places below which the time to get there is flagged.
+const TIGHT_TRAVEL_MINUTES: i64 = 30;
+
+/// Narrowest pane whose header lists reminders per list.
+const MIN_LIST_BREAKDOWN_W: usize = 50;
+
 pub struct DayView;
 
 impl DayView {
+    #[allow(clippy::too_many_arguments)]
     pub fn render(
         frame: &mut Frame,
         area: Rect,
         date: NaiveDate,
-        events: &[CalendarEvent],
-        reminders: &[Reminder],
+        day: &DayItems,
         selected: usize,
         reminder_progress: Option<(usize, usize)>, // (completed, total)
+        hidden: usize,
+        footer: Option<&str>,
+        busy_limit: Option<u32>,
     ) {
+        let DayItems { events, reminders, now, .. } = *day;
         let w = area.width as usize;
 
         let title = if w >= 30 {
@@ -42,144 +59,275 @@ impl DayView {
             let n = reminders.len();
             counts.push(format!("{} reminder{}", n, if n == 1 { "" } else { "s" }));
         }
+        if hidden > 0 {
+            counts.push(hidden_label(hidden));
+        }
         let count_str = if counts.is_empty() {
             String::new()
         } else {
             format!(" {} ", counts.join(", "))
         };
 
-        let block = Block::default()
-            .title(title)
+        let mut title = vec![Span::raw(title)];
+        title.extend(busy_badge(events, date, busy_limit));
+        if w >= MIN_LIST_BREAKDOWN_W {
+            title.extend(reminder_breakdown(reminders));
+        }
+        let inner_w = area.width.saturating_sub(4) as usize; // account for highlight symbol
+        let rows = layout::day_rows(day);
+
+        let mut block = Block::default()
+            .title(Line::from(title))
             .title_style(theme::current().header)
             .title_bottom(Line::from(Span::styled(count_str, theme::current().dim)))
             .borders(Borders::ALL)
             .border_style(theme::current().border);
+        // The whole title of a shortened selected event
+        if let Some(DayRow::Event(i)) = rows.get(selected) {
+            let ev = &events[*i];
+            if text::overflows(&ev.title, title_width(ev, inner_w, date)) {
+                let full = Span::styled(format!(" {} ", ev.title), theme::current().dim);
+                block = block.title_bottom(Line::from(full).right_aligned());
+            }
+        }
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        // Optional summary line pinned to the bottom of the pane
+        let list_area = match footer {
+            Some(text) if inner.height > 2 => {
+                let parts = Layout::vertical([Constraint::Min(1), Constraint::Length(1)])
+                    .split(inner);
+                frame.render_widget(
+                    Paragraph::new(text.to_string()).style(theme::current().dim),
+                    parts[1],
+                );
+                parts[0]
+            }
+            _ => inner,
+        };
 
         if events.is_empty() && reminders.is_empty() {
-            let inner = block.inner(area);
-            frame.render_widget(block, area);
             let msg = Paragraph::new("No events or reminders").style(theme::current().dim);
-            frame.render_widget(msg, inner);
+            frame.render_widget(msg, list_area);
             return;
         }
 
-        let inner_w = area.width.saturating_sub(4) as usize; // account for highlight symbol
+        let section_style = Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+        let items: Vec<ListItem> = rows
+            .into_iter()
+            .enumerate()
+            .map(|(idx, row)| (idx == selected, row))
+            .map(|(is_selected, row)| match row {
+                DayRow::Header(DaySection::Reminders) => {
+                    ListItem::new(reminder_header(reminder_progress, inner_w, section_style))
+                }
+                DayRow::Header(section) => {
+                    ListItem::new(Line::from(Span::styled(section.title(), section_style)))
+                }
+                DayRow::Spacer => ListItem::new(Line::from("")),
+                DayRow::Event(i) => {
+                    let ev = &events[i];
+                    selectable(format_event(ev, inner_w, date), is_selected)
+                }
+                DayRow::Reminder(i) => {
+                    selectable(format_reminder(&reminders[i], inner_w, date), is_selected)
+                }
+                DayRow::Now => now_line(now, inner_w),
+                DayRow::Break(minutes) => break_line(minutes),
+            })
+            .collect();
+
+        // ListState scrolls the viewport to the cursor, keeping a row of
+        // context around it; headers stay visible while the list fits
+        let total = items.len();
+        let overflows = total > list_area.height as usize;
+        let list_area = if overflows {
+            let parts = Layout::horizontal([Constraint::Min(1), Constraint::Length(1)]).split(list_area);
+            let mut scrollbar = ScrollbarState::new(total).position(selected);
+            frame.render_stateful_widget(
+                Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                    .begin_symbol(None)
+                    .end_symbol(None)
+                    .style(theme::current().dim),
+                parts[1],
+                &mut scrollbar,
+            );
+            parts[0]
+        } else {
+            list_area
+        };
+        let mut state = ListState::default().with_selected(Some(selected));
+        let list = List::new(items)
+            .highlight_symbol("> ")
+            .highlight_style(theme::current().selected)
+            .scroll_padding(1);
+        frame.render_stateful_widget(list, list_area, &mut state);
+    }
+}
 
-        let all_day: Vec<&CalendarEvent> = events.iter().filter(|e| e.is_all_day).collect();
-        let timed: Vec<&CalendarEvent> = events.iter().filter(|e| !e.is_all_day).collect();
+/// "(+4 hidden)", for footers of views with filters in effect.
+pub fn hidden_label(hidden: usize) -> String {
+    format!("(+{} hidden)", hidden)
+}
 
-        let mut items: Vec<ListItem> = Vec::new();
+/// "● Work 2 ● Home 1": reminders per list in each list's color, when
+/// they come from more than one list.
+fn reminder_breakdown(reminders: &[Reminder]) -> Vec<Span<'static>> {
+    let lists = layout::reminder_lists(reminders);
+    if lists.len() < 2 {
+        return Vec::new();
+    }
+    let mut spans = Vec::new();
+    for list in lists {
+        spans.push(Span::styled("\u{25cf}", Style::default().fg(list.color)));
+        spans.push(Span::styled(format!(" {} {} ", list.name, list.count), theme::current().dim));
+    }
+    spans
+}
 
-        // All-day events section
-        if !all_day.is_empty() {
-            items.push(ListItem::new(Line::from(Span::styled(
-                "All Day",
-                Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
-            ))));
-            for ev in &all_day {
-                items.push(format_event(ev, inner_w, true));
-            }
-            if !timed.is_empty() || !reminders.is_empty() {
-                items.push(ListItem::new(Line::from("")));
-            }
-        }
+/// Warning like "! 7h 30m busy" when meetings on `date` pass `limit` minutes.
+pub fn busy_badge(events: &[CalendarEvent], date: NaiveDate, limit: Option<u32>) -> Option<Span<'static>> {
+    let busy = layout::busy_minutes(events, date);
+    (busy > limit?).then(|| {
+        Span::styled(
+            format!("! {} busy ", layout::busy_label(busy)),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )
+    })
+}
 
-        // Reminders section with progress bar
-        if !reminders.is_empty() {
-            let header_line = if let Some((completed, total)) = reminder_progress {
-                if total > 0 {
-                    let bar_w = (inner_w).saturating_sub(16).min(20).max(4);
-                    let filled = if total > 0 {
-                        (completed * bar_w) / total
-                    } else {
-                        0
-                    };
-                    let empty = bar_w - filled;
-                    let bar = format!(
-                        "\u{2588}{}{}",
-                        "\u{2588}".repeat(filled),
-                        "\u{2591}".repeat(empty),
-                    );
-                    Line::from(vec![
-                        Span::styled(
-                            "Reminders ",
-                            Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
-                        ),
-                        Span::styled(bar, Style::default().fg(Color::Green)),
-                        Span::styled(
-                            format!(" {}/{}", completed, total),
-                            theme::current().dim,
-                        ),
-                    ])
-                } else {
-                    Line::from(Span::styled(
-                        "Reminders",
-                        Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
-                    ))
-                }
-            } else {
-                Line::from(Span::styled(
-                    "Reminders",
-                    Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
-                ))
-            };
-            items.push(ListItem::new(header_line));
-            for rem in reminders {
-                items.push(format_reminder(rem, inner_w, date));
-            }
-            if !timed.is_empty() {
-                items.push(ListItem::new(Line::from("")));
-            }
-        }
+/// Dim row for free time between meetings, e.g. "15 min break".
+fn break_line(minutes: u32) -> ListItem<'static> {
+    let label = if minutes < 60 {
+        format!("{} min break", minutes)
+    } else {
+        format!("{} break", layout::busy_label(minutes))
+    };
+    ListItem::new(Line::from(Span::styled(
+        format!("    {}", label),
+        theme::current().dim.add_modifier(Modifier::ITALIC),
+    )))
+}
 
-        // Timed events
-        for ev in &timed {
-            items.push(format_event(ev, inner_w, false));
-        }
+/// Red rule with the current time, e.g. "── 14:05 ─────".
+fn now_line(now: Option<DateTime<Local>>, inner_w: usize) -> ListItem<'static> {
+    let label = format!(" {} ", now.map(format_time).unwrap_or_default());
+    let rest = inner_w.saturating_sub(label.chars().count() + 2);
+    ListItem::new(Line::from(Span::styled(
+        format!("\u{2500}\u{2500}{}{}", label, "\u{2500}".repeat(rest)),
+        Style::default().fg(Color::Red),
+    )))
+}
 
-        // Use ListState for selection highlight with auto-scroll
-        let mut state = ListState::default().with_selected(Some(selected));
-        let list = List::new(items)
-            .block(block)
-            .highlight_symbol("> ")
-            .highlight_style(theme::current().highlight);
-        frame.render_stateful_widget(list, area, &mut state);
+/// "Reminders" header, with a progress bar when there is a total to show.
+fn reminder_header(progress: Option<(usize, usize)>, inner_w: usize, style: Style) -> Line<'static> {
+    let Some((completed, total)) = progress.filter(|&(_, total)| total > 0) else {
+        return Line::from(Span::styled("Reminders", style));
+    };
+    let bar_w = inner_w.saturating_sub(16).clamp(4, 20);
+    let filled = (completed * bar_w) / total;
+    let empty = bar_w - filled;
+    let bar = format!(
+        "\u{2588}{}{}",
+        "\u{2588}".repeat(filled),
+        "\u{2591}".repeat(empty),
+    );
+    Line::from(vec![
+        Span::styled("Reminders ", style),
+        Span::styled(bar, Style::default().fg(Color::Green)),
+        Span::styled(format!(" {}/{}", completed, total), theme::current().dim),
+    ])
+}
+
+/// Wrap an item row, restyling the cursor row in `theme.selected`. The
+/// calendar swatch keeps its colour; dimmed and coloured text would be
+/// unreadable on the selection background.
+fn selectable(line: Line<'static>, is_selected: bool) -> ListItem<'static> {
+    if !is_selected {
+        return ListItem::new(line);
     }
+    let selected = theme::current().selected;
+    let spans = line
+        .spans
+        .into_iter()
+        .enumerate()
+        .map(|(i, span)| {
+            if i == 0 {
+                return span;
+            }
+            let modifiers = span.style.add_modifier - Modifier::DIM;
+            Span::styled(span.content, selected.add_modifier(modifiers))
+        })
+        .collect::<Vec<_>>();
+    ListItem::new(Line::from(spans))
 }
 
-fn format_event(ev: &CalendarEvent, max_width: usize, is_all_day: bool) -> ListItem<'static> {
+fn format_event(ev: &CalendarEvent, max_width: usize, date: NaiveDate) -> Line<'static> {
     let cal_indicator = Span::styled("  ", Style::default().bg(ev.calendar_color));
 
-    let time_str = if is_all_day {
-        String::new()
-    } else {
-        format!(" {} ", ev.duration_display())
-    };
+    let time_str = event_time(ev, date);
     let time_span = Span::styled(
         time_str.clone(),
         Style::default().add_modifier(Modifier::DIM),
     );
 
-    let title_span = Span::styled(ev.title.clone(), Style::default());
+    let suffix = event_suffix(ev, date);
+    let title = text::truncate(&ev.title, title_width(ev, max_width, date));
+    let used = 2 + time_str.chars().count() + title.chars().count() + Span::raw(suffix.as_str()).width();
+    let title_span = Span::styled(title, Style::default());
 
     let mut spans = vec![cal_indicator, time_span, title_span];
+    if !suffix.is_empty() {
+        spans.push(Span::styled(suffix, theme::current().dim));
+    }
 
     // Only show location if there's room
-    let used = 2 + time_str.len() + ev.title.len();
     if let Some(ref loc) = ev.location {
-        if !loc.is_empty() && used + 4 + loc.len() <= max_width {
+        if !loc.is_empty() && used + 3 + loc.chars().count() <= max_width {
             spans.push(Span::styled(format!(" @ {}", loc), theme::current().dim));
         }
     }
 
-    ListItem::new(Line::from(spans))
+    Line::from(spans)
+}
+
+/// " 09:00 - 10:00 " before a timed event's title; empty for all-day events.
+fn event_time(ev: &CalendarEvent, date: NaiveDate) -> String {
+    if ev.is_all_day {
+        String::new()
+    } else {
+        format!(" {} ", ev.time_range_on(date))
+    }
+}
+
+/// Markers and labels after the title, e.g. " 📹 ↻ (day 2/3)".
+fn event_suffix(ev: &CalendarEvent, date: NaiveDate) -> String {
+    let mut suffix = String::new();
+    if ev.video_link().is_some() {
+        suffix.push_str(" \u{1f4f9}");
+    }
+    if let Some(marker) = ev.repeat_marker() {
+        suffix.push_str(&format!(" {}", marker));
+    }
+    for label in [ev.anniversary_label(), ev.day_of_span(date)].into_iter().flatten() {
+        suffix.push_str(&format!(" ({})", label));
+    }
+    suffix
+}
+
+/// Room for the title of `ev` in a day list row `max_width` wide.
+fn title_width(ev: &CalendarEvent, max_width: usize, date: NaiveDate) -> usize {
+    let fixed = 2 + event_time(ev, date).chars().count() + Span::raw(event_suffix(ev, date)).width();
+    max_width.saturating_sub(fixed)
 }
 
 fn format_reminder(
     rem: &Reminder,
     _max_width: usize,
     _current_date: NaiveDate,
-) -> ListItem<'static> {
+) -> Line<'static> {
     let cal_indicator = Span::styled("  ", Style::default().bg(rem.calendar_color));
 
     let checkbox = if rem.is_completed {
@@ -204,19 +352,21 @@ fn format_reminder(
         theme::current().dim,
     ));
 
-    ListItem::new(Line::from(spans))
+    Line::from(spans)
 }
 
-/// Render an event/reminder detail popup overlay.
+/// Render an event/reminder detail popup overlay. `left` is the minutes
+/// left in the event if it is in progress.
 pub fn render_detail_popup(
     frame: &mut Frame,
     area: Rect,
     detail: &DayAction,
     events: &[CalendarEvent],
     reminders: &[Reminder],
+    left: Option<u32>,
 ) {
     let popup_w = area.width.min(60).max(30);
-    let popup_h = area.height.min(16).max(8);
+    let popup_h = area.height.clamp(8, 20);
     let x = area.x + (area.width.saturating_sub(popup_w)) / 2;
     let y = area.y + (area.height.saturating_sub(popup_h)) / 2;
     let popup_area = Rect::new(x, y, popup_w, popup_h);
@@ -226,7 +376,7 @@ pub fn render_detail_popup(
     match detail {
         DayAction::Event(idx) => {
             if let Some(ev) = events.get(*idx) {
-                render_event_detail(frame, popup_area, ev);
+                render_event_detail(frame, popup_area, ev, events, left);
             }
         }
         DayAction::Reminder(idx) => {
@@ -238,7 +388,31 @@ pub fn render_detail_popup(
     }
 }
 
-fn render_event_detail(frame: &mut Frame, area: Rect, ev: &CalendarEvent) {
+/// "  ✓ Ana Lima (you)" with the reply glyph colored by status.
+fn participant_line(p: &Participant) -> Line<'static> {
+    let color = match p.status {
+        ParticipantStatus::Accepted => Color::Green,
+        ParticipantStatus::Declined => Color::Red,
+        ParticipantStatus::Tentative => Color::Yellow,
+        ParticipantStatus::Pending => Color::DarkGray,
+    };
+    let mut spans = vec![
+        Span::styled(format!("  {} ", p.status.glyph()), Style::default().fg(color).add_modifier(Modifier::BOLD)),
+        Span::styled(p.name.clone(), Style::default()),
+    ];
+    if p.is_me {
+        spans.push(Span::styled(format!(" (you, {})", p.status.label()), theme::current().dim));
+    }
+    Line::from(spans)
+}
+
+fn render_event_detail(
+    frame: &mut Frame,
+    area: Rect,
+    ev: &CalendarEvent,
+    day_events: &[CalendarEvent],
+    left: Option<u32>,
+) {
     let block = Block::default()
         .title(format!(" {} ", ev.title))
         .title_style(
@@ -265,28 +439,101 @@ fn render_event_detail(frame: &mut Frame, area: Rect, ev: &CalendarEvent) {
     if ev.is_all_day {
         lines.push(Line::from(Span::styled("All day", theme::current().dim)));
     } else {
-        lines.push(Line::from(vec![
+        let mut time = vec![
             Span::styled("Time: ", theme::current().dim),
             Span::styled(ev.duration_display(), Style::default()),
-        ]));
+        ];
+        if let Some(left) = left {
+            time.push(Span::styled(
+                format!(" \u{00b7} {}", layout::time_left_label(left)),
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+        lines.push(Line::from(time));
     }
 
     // Date
+    let date_str = if ev.spans_days() {
+        format!("{} - {}", ev.start.format("%a, %b %d"), ev.last_day().format("%a, %b %d, %Y"))
+    } else {
+        ev.start.format("%A, %B %d, %Y").to_string()
+    };
     lines.push(Line::from(vec![
         Span::styled("Date: ", theme::current().dim),
-        Span::styled(
-            ev.start.format("%A, %B %d, %Y").to_string(),
-            Style::default(),
-        ),
+        Span::styled(date_str, Style::default()),
     ]));
 
-    // Location
-    if let Some(ref loc) = ev.location {
-        if !loc.is_empty() {
-            lines.push(Line::from(""));
+    if let Some(rec) = &ev.recurrence {
+        lines.push(Line::from(vec![
+            Span::styled("Repeats: ", theme::current().dim),
+            Span::styled(format!("{} {}", ev.repeat_marker().unwrap_or_default(), rec.describe()), Style::default()),
+        ]));
+    }
+
+    lines.push(Line::from(vec![
+        Span::styled("Source: ", theme::current().dim),
+        Span::styled(ev.origin.label(), Style::default()),
+    ]));
+
+    let video = ev.video_link();
+    if let Some(link) = &video {
+        lines.push(Line::from(vec![
+            Span::styled("Video: ", theme::current().dim),
+            Span::styled(format!("\u{1f4f9} {}", link.service.label()), Style::default()),
+        ]));
+    }
+
+    if let Some(org) = ev.organizer.as_ref().filter(|_| !ev.attendees.is_empty()) {
+        let name = if org.is_me { format!("{} (you)", org.name) } else { org.name.clone() };
+        lines.push(Line::from(vec![
+            Span::styled("Organizer: ", theme::current().dim),
+            Span::styled(name, Style::default()),
+        ]));
+    }
+    if !ev.attendees.is_empty() {
+        lines.push(Line::from(Span::styled(
+            format!("Attendees ({}):", ev.attendees.len()),
+            theme::current().dim,
+        )));
+        for a in &ev.attendees {
+            lines.push(participant_line(a));
+        }
+    }
+
+    // Age / anniversary count for Birthdays-calendar events
+    if let (Some(n), Some(year)) = (ev.anniversary_count(), ev.origin_year) {
+        let (key, value) = if ev.is_anniversary() {
+            ("Anniversary: ", format!("{} years (since {})", n, year))
+        } else {
+            ("Age: ", format!("{} (born {})", n, year))
+        };
+        lines.push(Line::from(vec![
+            Span::styled(key, theme::current().dim),
+            Span::styled(value, Style::default()),
+        ]));
+    }
+
+    // Location, and where you are coming from if that is somewhere else
+    let has_location = ev.location.as_ref().is_some_and(|loc| !loc.is_empty());
+    if let Some(loc) = ev.location.as_ref().filter(|_| has_location) {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled("Location: ", theme::current().dim),
+            Span::styled(loc.clone(), Style::default()),
+        ]));
+        if let Some((prev, gap)) = layout::coming_from(day_events, ev) {
+            let gap_style = if gap < TIGHT_TRAVEL_MINUTES {
+                Style::default().fg(Color::Yellow)
+            } else {
+                theme::current().dim
+            };
             lines.push(Line::from(vec![
-                Span::styled("Location: ", theme::current().dim),
-                Span::styled(loc.clone(), Style::default()),
+                Span::styled("Coming from: ", theme::current().dim),
+                Span::styled(prev.location.clone().unwrap_or_default(), Style::default()),
+                Span::styled(
+                    format!(" ({} free after {})", layout::busy_label(gap as u32), prev.title),
+                    gap_style,
+                ),
             ]));
         }
     }
@@ -304,10 +551,22 @@ fn render_event_detail(frame: &mut Frame, area: Rect, ev: &CalendarEvent) {
 
     // Footer hint
     lines.push(Line::from(""));
-    lines.push(Line::from(Span::styled(
-        "Press Esc to close",
-        theme::current().dim,
-    )));
+    let mut actions = Vec::new();
+    if video.is_some() {
+        actions.push("o:Join");
+    }
+    if has_location {
+        actions.push("m:Map");
+    }
+    if ev.awaits_reply() {
+        actions.push("a:Accept  d:Decline  t:Maybe");
+    }
+    let hint = if actions.is_empty() {
+        "Press Esc to close".to_string()
+    } else {
+        format!("{}  Esc:Close", actions.join("  "))
+    };
+    lines.push(Line::from(Span::styled(hint, theme::current().dim)));
 
     let para = Paragraph::new(lines).wrap(Wrap { trim: false });
     frame.render_widget(para, inner);
@@ -367,22 +626,20 @@ fn render_reminder_detail(frame: &mut Frame, area: Rect, rem: &Reminder) {
 
     // Priority
     if rem.priority > 0 {
-        let priority_str = match rem.priority {
-            1..=4 => "High",
-            5 => "Medium",
-            6..=9 => "Low",
-            _ => "None",
-        };
         lines.push(Line::from(vec![
             Span::styled("Priority: ", theme::current().dim),
-            Span::styled(priority_str, Style::default()),
+            Span::styled(priority_label(rem.priority), Style::default()),
         ]));
     }
 
+    if rem.is_recurring {
+        lines.push(Line::from(Span::styled("Repeats", theme::current().dim)));
+    }
+
     // Footer
     lines.push(Line::from(""));
     lines.push(Line::from(Span::styled(
-        "Press Esc to close",
+        "e:Edit  Esc:Close",
         theme::current().dim,
     )));
 
@@ -0,0 +1,126 @@
+use color_eyre::Report;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::theme;
+
+/// A failure shown in full, with the chain of causes behind it.
+#[derive(Debug, Clone)]
+pub struct ErrorState {
+    /// What was being done, e.g. "Deleting event".
+    pub title: String,
+    pub lines: Vec<String>,
+    pub scroll: usize,
+}
+
+impl ErrorState {
+    pub fn new(title: impl Into<String>, err: &Report) -> Self {
+        let mut lines: Vec<String> = err.to_string().lines().map(str::to_string).collect();
+        let causes: Vec<String> = err.chain().skip(1).map(|c| c.to_string()).collect();
+        if !causes.is_empty() {
+            lines.push(String::new());
+            lines.push("Caused by:".to_string());
+            for cause in causes {
+                lines.extend(cause.lines().map(|l| format!("  {}", l)));
+            }
+        }
+        Self { title: title.into(), lines, scroll: 0 }
+    }
+
+    /// The whole error as plain text, for copying.
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Scroll one row, stopping once the last row shows in a popup over `area`.
+    pub fn down(&mut self, area: Rect) {
+        let (wrapped, visible) = self.rows(area);
+        if self.scroll + visible < wrapped.len() {
+            self.scroll += 1;
+        }
+    }
+
+    pub fn up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+
+    /// Width of the popup over `area`.
+    fn popup_width(area: Rect) -> u16 {
+        area.width.clamp(30, 72)
+    }
+
+    /// The lines wrapped to the popup over `area`, and how many of them fit.
+    fn rows(&self, area: Rect) -> (Vec<String>, usize) {
+        let text_w = Self::popup_width(area).saturating_sub(4) as usize;
+        let wrapped: Vec<String> = self.lines.iter().flat_map(|l| wrap(l, text_w)).collect();
+        let popup_h = (wrapped.len() as u16 + 4).max(6).min(area.height);
+        let visible = popup_h.saturating_sub(4) as usize;
+        (wrapped, visible)
+    }
+}
+
+/// Popup with the full text of an error, scrolled with j/k.
+pub struct ErrorPopup;
+
+impl ErrorPopup {
+    pub fn render(frame: &mut Frame, area: Rect, state: &ErrorState) {
+        let popup_w = ErrorState::popup_width(area);
+        let (wrapped, visible) = state.rows(area);
+        let popup_h = (visible as u16 + 4).max(6).min(area.height);
+        let x = area.x + (area.width.saturating_sub(popup_w)) / 2;
+        let y = area.y + (area.height.saturating_sub(popup_h)) / 2;
+        let popup_area = Rect::new(x, y, popup_w, popup_h);
+
+        frame.render_widget(Clear, popup_area);
+
+        let scroll = state.scroll.min(wrapped.len().saturating_sub(visible));
+        let more = scroll + visible < wrapped.len();
+        let footer = if scroll > 0 || more { " jk:Scroll y:Copy Esc:Close " } else { " y:Copy Esc:Close " };
+        let block = Block::default()
+            .title(format!(" {} failed ", state.title))
+            .title_style(Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
+            .title_bottom(Line::from(Span::styled(footer, theme::current().dim)))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Red));
+
+        let inner = block.inner(popup_area);
+        frame.render_widget(block, popup_area);
+
+        let lines: Vec<Line> = wrapped.into_iter().skip(scroll).take(visible).map(Line::from).collect();
+        let text_area = Rect::new(inner.x + 1, inner.y + 1, inner.width.saturating_sub(2), inner.height.saturating_sub(2));
+        frame.render_widget(Paragraph::new(lines), text_area);
+    }
+}
+
+/// Break `line` into rows of at most `width` characters, between words
+/// where possible.
+fn wrap(line: &str, width: usize) -> Vec<String> {
+    if width == 0 || line.chars().count() <= width {
+        return vec![line.to_string()];
+    }
+    let mut rows = Vec::new();
+    let mut row = String::new();
+    for (i, word) in line.split(' ').enumerate() {
+        let row_len = row.chars().count();
+        if row_len > 0 && row_len + 1 + word.chars().count() > width {
+            rows.push(std::mem::take(&mut row));
+        } else if i > 0 {
+            // Keeps runs of spaces, such as the indent of a cause
+            row.push(' ');
+        }
+        row.push_str(word);
+        // Words longer than a row are split wherever they reach the edge
+        while row.chars().count() > width {
+            let split = row.char_indices().nth(width).map(|(i, _)| i).unwrap_or(row.len());
+            rows.push(row[..split].to_string());
+            row = row[split..].to_string();
+        }
+    }
+    rows.push(row);
+    rows
+}
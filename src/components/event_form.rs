@@ -1,4 +1,4 @@
-use chrono::NaiveDate;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 use ratatui::{
     layout::{Constraint, Layout, Rect},
     style::{Modifier, Style},
@@ -7,17 +7,39 @@ use ratatui::{
     Frame,
 };
 
-use crate::calendar::CalendarInfo;
+use crate::calendar::{CalendarInfo, NewEvent, TimeFormat};
 use crate::theme;
 
+/// Alert choices offered by the form, in minutes before the start.
+pub const ALARM_OPTIONS: &[Option<u32>] = &[
+    None,
+    Some(0),
+    Some(5),
+    Some(10),
+    Some(15),
+    Some(30),
+    Some(60),
+    Some(120),
+    Some(1440),
+];
+
+/// Rows reserved for the multi-line notes field.
+const NOTES_ROWS: u16 = 3;
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum FormField {
     Title,
     Date,
     StartTime,
     EndTime,
+    EndDate,
     AllDay,
     Calendar,
+    Alarm,
+    Location,
+    Url,
+    Attendees,
+    Notes,
 }
 
 impl FormField {
@@ -26,20 +48,32 @@ impl FormField {
             FormField::Title => FormField::Date,
             FormField::Date => FormField::StartTime,
             FormField::StartTime => FormField::EndTime,
-            FormField::EndTime => FormField::AllDay,
+            FormField::EndTime => FormField::EndDate,
+            FormField::EndDate => FormField::AllDay,
             FormField::AllDay => FormField::Calendar,
-            FormField::Calendar => FormField::Title,
+            FormField::Calendar => FormField::Alarm,
+            FormField::Alarm => FormField::Location,
+            FormField::Location => FormField::Url,
+            FormField::Url => FormField::Attendees,
+            FormField::Attendees => FormField::Notes,
+            FormField::Notes => FormField::Title,
         }
     }
 
     pub fn prev(&self) -> Self {
         match self {
-            FormField::Title => FormField::Calendar,
+            FormField::Title => FormField::Notes,
             FormField::Date => FormField::Title,
             FormField::StartTime => FormField::Date,
             FormField::EndTime => FormField::StartTime,
-            FormField::AllDay => FormField::EndTime,
+            FormField::EndDate => FormField::EndTime,
+            FormField::AllDay => FormField::EndDate,
             FormField::Calendar => FormField::AllDay,
+            FormField::Alarm => FormField::Calendar,
+            FormField::Location => FormField::Alarm,
+            FormField::Url => FormField::Location,
+            FormField::Attendees => FormField::Url,
+            FormField::Notes => FormField::Attendees,
         }
     }
 }
@@ -50,8 +84,18 @@ pub struct EventFormState {
     pub date: String,
     pub start_time: String,
     pub end_time: String,
+    /// Last day of a multi-day event; empty means the same day as `date`.
+    pub end_date: String,
     pub is_all_day: bool,
     pub calendar_index: usize,
+    /// Index into [`ALARM_OPTIONS`].
+    pub alarm_index: usize,
+    pub location: String,
+    pub url: String,
+    /// Comma-separated email addresses to invite.
+    pub attendees: String,
+    /// Free text; may span several lines.
+    pub notes: String,
     pub active_field: FormField,
 }
 
@@ -60,24 +104,50 @@ impl EventFormState {
         Self {
             title: String::new(),
             date: date.format("%Y-%m-%d").to_string(),
-            start_time: "09:00".to_string(),
-            end_time: "10:00".to_string(),
+            start_time: TimeFormat::current().format(NaiveTime::from_hms_opt(9, 0, 0).unwrap()),
+            end_time: TimeFormat::current().format(NaiveTime::from_hms_opt(10, 0, 0).unwrap()),
+            end_date: String::new(),
             is_all_day: false,
             calendar_index: 0,
+            alarm_index: 0,
+            location: String::new(),
+            url: String::new(),
+            attendees: String::new(),
+            notes: String::new(),
             active_field: FormField::Title,
         }
     }
 
+    /// A form prefilled with a timed slot, which may end on a later day.
+    pub fn with_times(start: NaiveDateTime, end: NaiveDateTime) -> Self {
+        let format = TimeFormat::current();
+        let mut state = Self::new(start.date());
+        state.start_time = format.format(start.time());
+        state.end_time = format.format(end.time());
+        if end.date() != start.date() {
+            state.end_date = end.date().format("%Y-%m-%d").to_string();
+        }
+        state
+    }
+
     pub fn parsed_date(&self) -> Option<NaiveDate> {
         NaiveDate::parse_from_str(&self.date, "%Y-%m-%d").ok()
     }
 
-    pub fn parsed_start_time(&self) -> Option<chrono::NaiveTime> {
-        chrono::NaiveTime::parse_from_str(&self.start_time, "%H:%M").ok()
+    /// The end date, defaulting to the start date when left empty.
+    pub fn parsed_end_date(&self) -> Option<NaiveDate> {
+        if self.end_date.trim().is_empty() {
+            return self.parsed_date();
+        }
+        NaiveDate::parse_from_str(&self.end_date, "%Y-%m-%d").ok()
+    }
+
+    pub fn parsed_start_time(&self) -> Option<NaiveTime> {
+        TimeFormat::parse(&self.start_time)
     }
 
-    pub fn parsed_end_time(&self) -> Option<chrono::NaiveTime> {
-        chrono::NaiveTime::parse_from_str(&self.end_time, "%H:%M").ok()
+    pub fn parsed_end_time(&self) -> Option<NaiveTime> {
+        TimeFormat::parse(&self.end_time)
     }
 
     pub fn input_char(&mut self, c: char) {
@@ -86,7 +156,12 @@ impl EventFormState {
             FormField::Date => self.date.push(c),
             FormField::StartTime => self.start_time.push(c),
             FormField::EndTime => self.end_time.push(c),
-            FormField::AllDay | FormField::Calendar => {}
+            FormField::EndDate => self.end_date.push(c),
+            FormField::Location => self.location.push(c),
+            FormField::Url => self.url.push(c),
+            FormField::Attendees => self.attendees.push(c),
+            FormField::Notes => self.notes.push(c),
+            FormField::AllDay | FormField::Calendar | FormField::Alarm => {}
         }
     }
 
@@ -96,7 +171,12 @@ impl EventFormState {
             FormField::Date => { self.date.pop(); }
             FormField::StartTime => { self.start_time.pop(); }
             FormField::EndTime => { self.end_time.pop(); }
-            FormField::AllDay | FormField::Calendar => {}
+            FormField::EndDate => { self.end_date.pop(); }
+            FormField::Location => { self.location.pop(); }
+            FormField::Url => { self.url.pop(); }
+            FormField::Attendees => { self.attendees.pop(); }
+            FormField::Notes => { self.notes.pop(); }
+            FormField::AllDay | FormField::Calendar | FormField::Alarm => {}
         }
     }
 
@@ -110,11 +190,72 @@ impl EventFormState {
         }
     }
 
+    pub fn next_alarm(&mut self) {
+        self.alarm_index = (self.alarm_index + 1) % ALARM_OPTIONS.len();
+    }
+
+    /// Pick the alert `minutes` before the start. Returns false when the
+    /// form doesn't offer it.
+    pub fn set_alarm(&mut self, minutes: Option<u32>) -> bool {
+        match ALARM_OPTIONS.iter().position(|m| *m == minutes) {
+            Some(idx) => {
+                self.alarm_index = idx;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn alarm_minutes(&self) -> Option<u32> {
+        ALARM_OPTIONS.get(self.alarm_index).copied().flatten()
+    }
+
+    /// Attendee addresses, split on commas, semicolons and whitespace.
+    pub fn attendee_list(&self) -> Vec<String> {
+        self.attendees
+            .split([',', ';', ' ', '\t'])
+            .map(str::trim)
+            .filter(|a| !a.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Build the event to save. Only call once [`is_valid`](Self::is_valid) holds.
+    pub fn to_new_event(&self, calendar_id: Option<String>) -> NewEvent {
+        let non_empty = |s: &str| {
+            let s = s.trim();
+            (!s.is_empty()).then(|| s.to_string())
+        };
+        NewEvent {
+            title: self.title.clone(),
+            date: self.parsed_date().expect("validated date"),
+            start_time: self.parsed_start_time().unwrap_or(NaiveTime::from_hms_opt(9, 0, 0).unwrap()),
+            end_date: self.parsed_end_date().expect("validated end date"),
+            end_time: self.parsed_end_time().unwrap_or(NaiveTime::from_hms_opt(10, 0, 0).unwrap()),
+            is_all_day: self.is_all_day,
+            calendar_id,
+            location: non_empty(&self.location),
+            url: non_empty(&self.url),
+            notes: non_empty(&self.notes),
+            alarm_minutes: self.alarm_minutes(),
+            busy: false,
+        }
+    }
+
     pub fn is_valid(&self) -> bool {
-        !self.title.is_empty()
-            && self.parsed_date().is_some()
-            && (self.is_all_day
-                || (self.parsed_start_time().is_some() && self.parsed_end_time().is_some()))
+        let (Some(date), Some(end_date)) = (self.parsed_date(), self.parsed_end_date()) else {
+            return false;
+        };
+        if self.title.is_empty() || end_date < date {
+            return false;
+        }
+        if self.is_all_day {
+            return true;
+        }
+        match (self.parsed_start_time(), self.parsed_end_time()) {
+            (Some(start), Some(end)) => end_date.and_time(end) > date.and_time(start),
+            _ => false,
+        }
     }
 }
 
@@ -129,7 +270,7 @@ impl EventForm {
     ) {
         // Center the form popup
         let form_w = area.width.min(50).max(30);
-        let form_h = area.height.min(14).max(10);
+        let form_h = area.height.clamp(10, 22);
         let x = area.x + (area.width.saturating_sub(form_w)) / 2;
         let y = area.y + (area.height.saturating_sub(form_h)) / 2;
         let form_area = Rect::new(x, y, form_w, form_h);
@@ -151,8 +292,14 @@ impl EventForm {
             Constraint::Length(1), // date
             Constraint::Length(1), // start time
             Constraint::Length(1), // end time
+            Constraint::Length(1), // end date
             Constraint::Length(1), // all day
             Constraint::Length(1), // calendar
+            Constraint::Length(1), // alarm
+            Constraint::Length(1), // location
+            Constraint::Length(1), // url
+            Constraint::Length(1), // attendees
+            Constraint::Length(NOTES_ROWS), // notes
             Constraint::Length(1), // spacer
             Constraint::Length(1), // help
             Constraint::Min(0),
@@ -170,27 +317,78 @@ impl EventForm {
             render_field(frame, rows[3], "End:", &state.end_time, state.active_field == FormField::EndTime);
         }
 
+        let until = if state.end_date.is_empty() && state.active_field != FormField::EndDate {
+            "same day"
+        } else {
+            state.end_date.as_str()
+        };
+        render_field(frame, rows[4], "Until:", until, state.active_field == FormField::EndDate);
+
         let all_day_val = if state.is_all_day { "[x] All Day" } else { "[ ] All Day" };
-        render_field(frame, rows[4], "", all_day_val, state.active_field == FormField::AllDay);
+        render_field(frame, rows[5], "", all_day_val, state.active_field == FormField::AllDay);
 
         let cal_name = calendars
             .get(state.calendar_index)
             .map(|c| c.title.as_str())
             .unwrap_or("Default");
-        render_field(frame, rows[5], "Cal:", cal_name, state.active_field == FormField::Calendar);
+        render_field(frame, rows[6], "Cal:", cal_name, state.active_field == FormField::Calendar);
+        render_field(frame, rows[7], "Alert:", &alarm_label(state.alarm_minutes()), state.active_field == FormField::Alarm);
+        render_field(frame, rows[8], "Where:", &state.location, state.active_field == FormField::Location);
+        render_field(frame, rows[9], "URL:", &state.url, state.active_field == FormField::Url);
+        render_field(frame, rows[10], "Invite:", &state.attendees, state.active_field == FormField::Attendees);
+        render_notes(frame, rows[11], &state.notes, state.active_field == FormField::Notes);
+
+        let on_notes = state.active_field == FormField::Notes;
 
         let help = Line::from(vec![
             Span::styled("Tab", Style::default().add_modifier(Modifier::BOLD)),
             Span::styled(":Next ", theme::current().dim),
-            Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled(if on_notes { "C-s" } else { "Enter" }, Style::default().add_modifier(Modifier::BOLD)),
             Span::styled(":Save ", theme::current().dim),
             Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
             Span::styled(":Cancel", theme::current().dim),
         ]);
-        frame.render_widget(Paragraph::new(help), rows[7]);
+        frame.render_widget(Paragraph::new(help), rows[13]);
+    }
+}
+
+/// "none", "at start", "10m before", "1h before", "1d before".
+pub fn alarm_label(minutes: Option<u32>) -> String {
+    match minutes {
+        None => "none".to_string(),
+        Some(0) => "at start".to_string(),
+        Some(m) if m % 1440 == 0 => format!("{}d before", m / 1440),
+        Some(m) if m % 60 == 0 => format!("{}h before", m / 60),
+        Some(m) => format!("{}m before", m),
     }
 }
 
+/// Show the last `NOTES_ROWS` lines of the notes so the cursor stays visible.
+fn render_notes(frame: &mut Frame, area: Rect, notes: &str, active: bool) {
+    let style = if active {
+        Style::default().fg(ratatui::style::Color::Cyan)
+    } else {
+        Style::default()
+    };
+    let cursor = if active { "_" } else { "" };
+    let text = format!("{}{}", notes, cursor);
+    let all: Vec<&str> = text.split('\n').collect();
+    let skip = all.len().saturating_sub(NOTES_ROWS as usize);
+
+    let lines: Vec<Line> = all[skip..]
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let label = if i == 0 && skip == 0 { "Notes:" } else { "" };
+            Line::from(vec![
+                Span::styled(format!("{:<7}", label), theme::current().dim),
+                Span::styled(line.to_string(), style),
+            ])
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), area);
+}
+
 fn render_field(frame: &mut Frame, area: Rect, label: &str, value: &str, active: bool) {
     let label_w = if label.is_empty() { 0 } else { 7 };
     let cursor = if active { "_" } else { "" };
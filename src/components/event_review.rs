@@ -0,0 +1,171 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+
+use crate::calendar::CalendarEvent;
+use crate::theme;
+
+/// What confirming a review does with the marked events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewAction {
+    Delete,
+    /// Create the listed events, which are previews of copies.
+    Create,
+}
+
+impl ReviewAction {
+    fn verb(self) -> &'static str {
+        match self {
+            ReviewAction::Delete => "Delete",
+            ReviewAction::Create => "Create",
+        }
+    }
+}
+
+/// A list of events proposed for deletion or creation, each of which can be
+/// unmarked before confirming.
+#[derive(Debug, Clone)]
+pub struct EventReviewState {
+    pub title: String,
+    pub action: ReviewAction,
+    pub events: Vec<CalendarEvent>,
+    /// Why each event is listed, e.g. "declined".
+    pub reasons: Vec<String>,
+    pub marked: Vec<bool>,
+    /// The other copy for each entry, when the user may swap which is deleted.
+    pub alternates: Vec<Option<(CalendarEvent, String)>>,
+    pub cursor: usize,
+    /// Waiting for y/n after Enter.
+    pub confirming: bool,
+}
+
+impl EventReviewState {
+    /// Every event starts marked for deletion.
+    pub fn new(title: impl Into<String>, items: Vec<(CalendarEvent, String)>) -> Self {
+        let marked = vec![true; items.len()];
+        let alternates = vec![None; items.len()];
+        let (events, reasons) = items.into_iter().unzip();
+        Self {
+            title: title.into(),
+            action: ReviewAction::Delete,
+            events,
+            reasons,
+            marked,
+            alternates,
+            cursor: 0,
+            confirming: false,
+        }
+    }
+
+    /// Like [`new`](Self::new), with a swappable alternative per entry.
+    pub fn with_alternates(
+        title: impl Into<String>,
+        items: Vec<((CalendarEvent, String), (CalendarEvent, String))>,
+    ) -> Self {
+        let (items, alternates): (Vec<_>, Vec<_>) = items.into_iter().unzip();
+        let mut state = Self::new(title, items);
+        state.alternates = alternates.into_iter().map(Some).collect();
+        state
+    }
+
+    /// Every event starts marked for creation.
+    pub fn create(title: impl Into<String>, items: Vec<(CalendarEvent, String)>) -> Self {
+        Self { action: ReviewAction::Create, ..Self::new(title, items) }
+    }
+
+    /// Delete the other copy of the selected entry instead.
+    pub fn swap(&mut self) {
+        let idx = self.cursor;
+        if let Some(Some(alt)) = self.alternates.get_mut(idx) {
+            let current = (self.events[idx].clone(), self.reasons[idx].clone());
+            let (ev, reason) = std::mem::replace(alt, current);
+            self.events[idx] = ev;
+            self.reasons[idx] = reason;
+        }
+    }
+
+    pub fn down(&mut self) {
+        if self.cursor + 1 < self.events.len() {
+            self.cursor += 1;
+        }
+    }
+
+    pub fn up(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    pub fn toggle(&mut self) {
+        if let Some(m) = self.marked.get_mut(self.cursor) {
+            *m = !*m;
+        }
+    }
+
+    pub fn marked_ids(&self) -> Vec<String> {
+        self.marked_events().map(|ev| ev.id.clone()).collect()
+    }
+
+    pub fn marked_events(&self) -> impl Iterator<Item = &CalendarEvent> {
+        self.events.iter().zip(&self.marked).filter(|(_, &m)| m).map(|(ev, _)| ev)
+    }
+}
+
+pub struct EventReview;
+
+impl EventReview {
+    pub fn render(frame: &mut Frame, area: Rect, state: &EventReviewState) {
+        let popup_w = area.width.clamp(30, 70);
+        let popup_h = (state.events.len() as u16 + 2).clamp(6, area.height.max(6));
+        let x = area.x + (area.width.saturating_sub(popup_w)) / 2;
+        let y = area.y + (area.height.saturating_sub(popup_h)) / 2;
+        let popup_area = Rect::new(x, y, popup_w, popup_h);
+
+        frame.render_widget(Clear, popup_area);
+
+        let footer = if state.confirming {
+            let n = state.marked.iter().filter(|&&m| m).count();
+            let color = if state.action == ReviewAction::Delete { Color::Red } else { Color::Green };
+            Span::styled(
+                format!(" {} {} event{}? y:Yes n:No ", state.action.verb(), n, if n == 1 { "" } else { "s" }),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            )
+        } else if state.alternates.iter().any(|a| a.is_some()) {
+            Span::styled(format!(" Space:Toggle s:Swap Enter:{} Esc:Close ", state.action.verb()), theme::current().dim)
+        } else {
+            Span::styled(format!(" Space:Toggle Enter:{} Esc:Close ", state.action.verb()), theme::current().dim)
+        };
+
+        let block = Block::default()
+            .title(format!(" {} ({}) ", state.title, state.events.len()))
+            .title_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .title_bottom(Line::from(footer))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow));
+
+        let items: Vec<ListItem> = state
+            .events
+            .iter()
+            .zip(&state.reasons)
+            .zip(&state.marked)
+            .map(|((ev, reason), &marked)| {
+                let checkbox = if marked { " [x] " } else { " [ ] " };
+                ListItem::new(Line::from(vec![
+                    Span::styled("  ", Style::default().bg(ev.calendar_color)),
+                    Span::raw(checkbox),
+                    Span::styled(format!("{} ", ev.start.format("%Y-%m-%d")), theme::current().dim),
+                    Span::raw(ev.title.clone()),
+                    Span::styled(format!("  ({}, {})", ev.calendar_name, reason), theme::current().dim),
+                ]))
+            })
+            .collect();
+
+        let mut list_state = ListState::default().with_selected(Some(state.cursor));
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(theme::current().highlight);
+        frame.render_stateful_widget(list, popup_area, &mut list_state);
+    }
+}
@@ -0,0 +1,87 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::calendar::Access;
+use crate::health::Health;
+use crate::theme;
+
+/// Popup summarizing permissions, accounts and the last fetch.
+pub struct HealthPanel;
+
+impl HealthPanel {
+    pub fn render(frame: &mut Frame, area: Rect, health: &Health) {
+        let issues = health.issues();
+        let popup_w = area.width.clamp(30, 64);
+        let popup_h = (health.sources.len() as u16 + issues.len() as u16 + 10).min(area.height);
+        let x = area.x + (area.width.saturating_sub(popup_w)) / 2;
+        let y = area.y + (area.height.saturating_sub(popup_h)) / 2;
+        let popup_area = Rect::new(x, y, popup_w, popup_h);
+
+        frame.render_widget(Clear, popup_area);
+
+        let color = if issues.is_empty() { Color::Green } else { Color::Yellow };
+        let block = Block::default()
+            .title(" Status ")
+            .title_style(Style::default().fg(color).add_modifier(Modifier::BOLD))
+            .title_bottom(Line::from(Span::styled(" Esc:Close ", theme::current().dim)))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(color));
+
+        let access_line = |label: &str, access: Access| {
+            let style = if access.can_read() {
+                Style::default().fg(Color::Green)
+            } else {
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+            };
+            Line::from(vec![
+                Span::styled(format!("{:<11}", label), theme::current().dim),
+                Span::styled(access.label(), style),
+            ])
+        };
+
+        let mut lines = vec![
+            access_line("Calendars:", health.permissions.events),
+            access_line("Reminders:", health.permissions.reminders),
+            Line::from(vec![
+                Span::styled(format!("{:<11}", "Loaded:"), theme::current().dim),
+                Span::raw(
+                    health
+                        .last_fetch
+                        .map(|t| t.format("%a %b %-d, %H:%M:%S").to_string())
+                        .unwrap_or_else(|| "never".to_string()),
+                ),
+            ]),
+            Line::from(""),
+        ];
+
+        if health.sources.is_empty() {
+            lines.push(Line::from(Span::styled("No accounts", theme::current().dim)));
+        }
+        for source in &health.sources {
+            let mut counts = format!("{} calendars", source.calendars);
+            if source.hidden > 0 {
+                counts.push_str(&format!(" ({} hidden)", source.hidden));
+            }
+            counts.push_str(&format!(", {} reminder lists", source.reminder_lists));
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {}: ", source.source), Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(counts),
+            ]));
+        }
+
+        if !issues.is_empty() {
+            lines.push(Line::from(""));
+            for issue in issues {
+                lines.push(Line::from(Span::styled(format!("! {}", issue), Style::default().fg(Color::Yellow))));
+            }
+        }
+
+        let para = Paragraph::new(lines).block(block).wrap(Wrap { trim: false });
+        frame.render_widget(para, popup_area);
+    }
+}
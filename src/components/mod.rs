@@ -1,12 +1,34 @@
+pub mod calendar_list;
+pub mod command_line;
+pub mod day_timeline;
 pub mod day_view;
+pub mod error_popup;
 pub mod event_form;
+pub mod event_review;
+pub mod health_panel;
 pub mod month_view;
+pub mod prompt;
 #[allow(dead_code)]
 pub mod reminder_list;
+pub mod reminder_checklist;
+pub mod reminder_form;
+pub mod start_alert;
 pub mod status_bar;
+pub mod toast;
 pub mod week_view;
 
+pub use calendar_list::CalendarList;
+pub use command_line::CommandLine;
+pub use day_timeline::DayTimeline;
 pub use day_view::DayView;
+pub use error_popup::ErrorPopup;
 pub use event_form::EventForm;
+pub use event_review::EventReview;
+pub use health_panel::HealthPanel;
 pub use month_view::MonthView;
+pub use prompt::Prompt;
+pub use reminder_checklist::ReminderChecklist;
+pub use reminder_form::ReminderForm;
+pub use start_alert::StartAlert;
+pub use toast::ToastStack;
 pub use week_view::WeekView;
@@ -1,29 +1,48 @@
-use std::collections::HashSet;
+use std::collections::HashMap;
 
 use chrono::{Datelike, NaiveDate};
 use ratatui::{
     layout::{Constraint, Layout, Rect},
-    style::{Modifier, Style},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
 
+use crate::calendar::CalendarEvent;
+use crate::layout;
+use crate::text;
 use crate::theme;
 
+/// Narrowest cell that lists event titles instead of only markers.
+const MIN_TITLE_CELL_W: usize = 10;
+/// Narrowest cell with room for an event count badge.
+const MIN_BADGE_CELL_W: usize = 6;
+/// Width of the ISO week number column, e.g. "38 ".
+const WEEK_GUTTER_W: usize = 3;
+/// Narrowest grid that still makes room for week numbers.
+const MIN_WEEK_NUMBERS_W: usize = 24;
+/// Most title lines shown per day.
+const MAX_TITLES: usize = 3;
+
 const DAY_NAMES_SHORT: [&str; 7] = ["S", "M", "T", "W", "T", "F", "S"];
 const DAY_NAMES_MED: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
 
 pub struct MonthView;
 
 impl MonthView {
+    #[allow(clippy::too_many_arguments)]
     pub fn render(
         frame: &mut Frame,
         area: Rect,
         selected_date: NaiveDate,
         today: NaiveDate,
-        days_with_events: &HashSet<u32>,
-        days_with_reminders: &HashSet<u32>,
+        day_event_counts: &HashMap<u32, usize>,
+        reminder_colors: &HashMap<u32, Color>,
+        events: &[CalendarEvent],
+        week_selected: bool,
+        week_numbers: bool,
+        busy_limit: Option<u32>,
     ) {
         let year = selected_date.year();
         let month = selected_date.month();
@@ -32,7 +51,9 @@ impl MonthView {
         // Adaptive cell width based on available space
         // border takes 2 chars, 7 columns needed
         let inner_w = w.saturating_sub(2);
-        let cell_w = (inner_w / 7).max(2);
+        let week_numbers = week_numbers && inner_w >= MIN_WEEK_NUMBERS_W;
+        let gutter_w = if week_numbers { WEEK_GUTTER_W } else { 0 };
+        let cell_w = ((inner_w - gutter_w) / 7).max(2);
         let compact = cell_w < 4;
 
         let title = if w >= 22 {
@@ -56,7 +77,7 @@ impl MonthView {
 
         // Header row
         let day_names = if compact { &DAY_NAMES_SHORT } else { &DAY_NAMES_MED };
-        let header_cells: Vec<Span> = day_names
+        let mut header_cells: Vec<Span> = day_names
             .iter()
             .map(|d| {
                 let formatted = if compact {
@@ -67,6 +88,9 @@ impl MonthView {
                 Span::styled(formatted, theme::current().header)
             })
             .collect();
+        if week_numbers {
+            header_cells.insert(0, Span::styled(format!("{:<WEEK_GUTTER_W$}", "Wk"), theme::current().dim));
+        }
         let header = Line::from(header_cells);
 
         // Calculate grid
@@ -76,18 +100,30 @@ impl MonthView {
 
         // Build weeks
         let mut weeks: Vec<Line> = Vec::new();
+        let mut week_dates: Vec<[Option<NaiveDate>; 7]> = Vec::new();
         let mut current_day: i32 = 1 - first_weekday as i32;
 
         while current_day <= dim as i32 {
+            let row_selected = week_selected && (current_day..current_day + 7).contains(&(selected_date.day() as i32));
             let mut cells: Vec<Span> = Vec::new();
-            for _ in 0..7 {
+            if week_numbers {
+                // Rows run Sunday to Saturday; ISO weeks are numbered by their Monday
+                let monday = first_day + chrono::Duration::days(current_day as i64);
+                let label = format!("{:>2} ", monday.iso_week().week());
+                cells.push(Span::styled(label, theme::current().dim));
+            }
+            let mut dates = [None; 7];
+            for slot in dates.iter_mut() {
                 if current_day < 1 || current_day > dim as i32 {
                     cells.push(Span::raw(" ".repeat(cell_w)));
                 } else {
                     let day = current_day as u32;
                     let date = NaiveDate::from_ymd_opt(year, month, day).unwrap();
-                    let has_event = days_with_events.contains(&day);
-                    let has_reminder = days_with_reminders.contains(&day);
+                    *slot = Some(date);
+                    let count = day_event_counts.get(&day).copied().unwrap_or(0);
+                    let has_event = count > 0;
+                    let reminder_color = reminder_colors.get(&day).copied();
+                    let has_reminder = reminder_color.is_some();
 
                     // Marker: * for events, . for reminders, + for both
                     let marker = match (has_event, has_reminder) {
@@ -97,6 +133,44 @@ impl MonthView {
                         (false, false) => " ",
                     };
 
+                    let t = theme::current();
+                    let style = if date == today && date == selected_date {
+                        t.today.add_modifier(Modifier::BOLD)
+                    } else if date == selected_date || row_selected {
+                        t.selected
+                    } else if date == today {
+                        t.today
+                    } else {
+                        Style::default()
+                    };
+
+                    // Wide cells trade the event marker for a count badge, and
+                    // color the reminder marker by its main list
+                    if cell_w >= MIN_BADGE_CELL_W {
+                        let num = format!(" {:>2}", day);
+                        let reminder_marker = match reminder_color {
+                            Some(color) => Span::styled(".", style.fg(color).add_modifier(Modifier::BOLD)),
+                            None => Span::styled(" ", style),
+                        };
+                        let badge = match count {
+                            0 => String::new(),
+                            1..=9 => count.to_string(),
+                            _ => "9+".to_string(),
+                        };
+                        let over = busy_limit.is_some_and(|limit| layout::busy_minutes(events, date) > limit);
+                        let (badge, badge_style) = if over {
+                            (format!("!{}", badge), style.fg(Color::Red).add_modifier(Modifier::BOLD))
+                        } else {
+                            (badge, style.patch(t.dim))
+                        };
+                        let rest = cell_w - num.len() - 2;
+                        cells.push(Span::styled(num, style));
+                        cells.push(reminder_marker);
+                        cells.push(Span::styled(format!("{:>rest$} ", badge), badge_style));
+                        current_day += 1;
+                        continue;
+                    }
+
                     let day_str = if compact {
                         if marker != " " {
                             format!("{:>width$}", format!("{}{}", day, marker), width = cell_w)
@@ -108,22 +182,12 @@ impl MonthView {
                         format!("{:^width$}", num, width = cell_w)
                     };
 
-                    let t = theme::current();
-                    let style = if date == today && date == selected_date {
-                        t.today.add_modifier(Modifier::BOLD)
-                    } else if date == selected_date {
-                        t.selected
-                    } else if date == today {
-                        t.today
-                    } else {
-                        Style::default()
-                    };
-
                     cells.push(Span::styled(day_str, style));
                 }
                 current_day += 1;
             }
             weeks.push(Line::from(cells));
+            week_dates.push(dates);
         }
 
         // Layout: header + weeks, adapt row height to fill space
@@ -143,15 +207,61 @@ impl MonthView {
 
         let rows = Layout::vertical(constraints).split(inner);
 
+        // Wide cells with spare rows list event titles under the day number
+        let title_lines = if cell_w >= MIN_TITLE_CELL_W {
+            (row_height - 1).min(MAX_TITLES)
+        } else {
+            0
+        };
+
         frame.render_widget(Paragraph::new(header), rows[0]);
         for (i, week) in weeks.iter().enumerate() {
             if i + 1 < rows.len() {
-                frame.render_widget(Paragraph::new(week.clone()), rows[i + 1]);
+                let mut lines = vec![week.clone()];
+                for k in 0..title_lines {
+                    let mut line = title_line(&week_dates[i], events, k, title_lines, cell_w);
+                    line.spans.insert(0, Span::raw(" ".repeat(gutter_w)));
+                    lines.push(line);
+                }
+                frame.render_widget(Paragraph::new(lines), rows[i + 1]);
             }
         }
     }
 }
 
+/// Line `k` of the inline event titles for one week: the k-th event of each
+/// day, or "+N more" on the last line when a day has more than fit.
+fn title_line(
+    dates: &[Option<NaiveDate>; 7],
+    events: &[CalendarEvent],
+    k: usize,
+    lines: usize,
+    cell_w: usize,
+) -> Line<'static> {
+    let text_w = cell_w - 1;
+    let spans = dates
+        .iter()
+        .map(|date| {
+            let Some(date) = date else {
+                return Span::raw(" ".repeat(cell_w));
+            };
+            let day_events: Vec<&CalendarEvent> = events.iter().filter(|ev| ev.occurs_on(*date)).collect();
+            if k + 1 == lines && day_events.len() > lines {
+                let more = format!("+{} more", day_events.len() - k);
+                return Span::styled(format!(" {:<text_w$}", more), theme::current().dim);
+            }
+            match day_events.get(k) {
+                Some(ev) => {
+                    let title = text::truncate(&ev.title, text_w);
+                    Span::styled(format!(" {:<text_w$}", title), Style::default().fg(ev.calendar_color))
+                }
+                None => Span::raw(" ".repeat(cell_w)),
+            }
+        })
+        .collect::<Vec<_>>();
+    Line::from(spans)
+}
+
 fn days_in_month(year: i32, month: u32) -> u32 {
     if month == 12 {
         NaiveDate::from_ymd_opt(year + 1, 1, 1)
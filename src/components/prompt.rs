@@ -0,0 +1,46 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::theme;
+
+/// Small centered popup asking the user to pick one of a few keyed choices.
+pub struct Prompt;
+
+impl Prompt {
+    /// `choices` are `(key, description)` pairs, one per line. `message` may
+    /// span several lines.
+    pub fn render(frame: &mut Frame, area: Rect, title: &str, message: &str, choices: &[(&str, &str)]) {
+        let popup_w = area.width.clamp(30, 50);
+        let message_h = message.lines().count().max(1) as u16;
+        let popup_h = (choices.len() as u16 + message_h + 4).min(area.height);
+        let x = area.x + (area.width.saturating_sub(popup_w)) / 2;
+        let y = area.y + (area.height.saturating_sub(popup_h)) / 2;
+        let popup_area = Rect::new(x, y, popup_w, popup_h);
+
+        frame.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title(format!(" {} ", title))
+            .title_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow));
+
+        let key_style = Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+        let mut lines: Vec<Line> = message.lines().map(|l| Line::from(l.to_string())).collect();
+        lines.push(Line::from(""));
+        for (key, desc) in choices {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {:<5}", key), key_style),
+                Span::styled(desc.to_string(), theme::current().dim),
+            ]));
+        }
+
+        let para = Paragraph::new(lines).block(block).wrap(Wrap { trim: false });
+        frame.render_widget(para, popup_area);
+    }
+}
@@ -0,0 +1,61 @@
+use chrono::NaiveDate;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+
+use crate::calendar::Reminder;
+use crate::theme;
+
+/// Popup listing one day's reminders as a checklist, opened from the
+/// month grid.
+pub struct ReminderChecklist;
+
+impl ReminderChecklist {
+    pub fn render(frame: &mut Frame, area: Rect, date: NaiveDate, reminders: &[&Reminder], selected: usize) {
+        let popup_w = area.width.clamp(30, 50);
+        let popup_h = (reminders.len() as u16 + 3).clamp(6, area.height.max(6));
+        let x = area.x + (area.width.saturating_sub(popup_w)) / 2;
+        let y = area.y + (area.height.saturating_sub(popup_h)) / 2;
+        let popup_area = Rect::new(x, y, popup_w, popup_h);
+
+        frame.render_widget(Clear, popup_area);
+
+        let done = reminders.iter().filter(|r| r.is_completed).count();
+        let block = Block::default()
+            .title(format!(" {} \u{00b7} {}/{} done ", date.format("%a %b %-d"), done, reminders.len()))
+            .title_style(Style::default().fg(Color::Green).add_modifier(Modifier::BOLD))
+            .title_bottom(Line::from(Span::styled(
+                " Space:Toggle Esc:Close ",
+                theme::current().dim,
+            )))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Green));
+
+        let items: Vec<ListItem> = reminders
+            .iter()
+            .map(|rem| {
+                let checkbox = if rem.is_completed { " [x] " } else { " [ ] " };
+                let title_style = if rem.is_completed {
+                    theme::current().dim.add_modifier(Modifier::CROSSED_OUT)
+                } else {
+                    Style::default()
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled("  ", Style::default().bg(rem.calendar_color)),
+                    Span::raw(checkbox),
+                    Span::styled(rem.title.clone(), title_style),
+                ]))
+            })
+            .collect();
+
+        let mut state = ListState::default().with_selected(Some(selected));
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(theme::current().highlight);
+        frame.render_stateful_widget(list, popup_area, &mut state);
+    }
+}
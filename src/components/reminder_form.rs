@@ -0,0 +1,205 @@
+use chrono::NaiveDate;
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::calendar::{CalendarInfo, Reminder};
+use crate::theme;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReminderField {
+    Title,
+    Due,
+    Priority,
+    List,
+}
+
+impl ReminderField {
+    pub fn next(&self) -> Self {
+        match self {
+            ReminderField::Title => ReminderField::Due,
+            ReminderField::Due => ReminderField::Priority,
+            ReminderField::Priority => ReminderField::List,
+            ReminderField::List => ReminderField::Title,
+        }
+    }
+
+    pub fn prev(&self) -> Self {
+        match self {
+            ReminderField::Title => ReminderField::List,
+            ReminderField::Due => ReminderField::Title,
+            ReminderField::Priority => ReminderField::Due,
+            ReminderField::List => ReminderField::Priority,
+        }
+    }
+}
+
+/// Editing state for an existing reminder.
+#[derive(Debug, Clone)]
+pub struct ReminderFormState {
+    pub reminder_id: String,
+    pub title: String,
+    /// Due date as typed ("YYYY-MM-DD"); empty means no due date.
+    pub due_date: String,
+    /// EventKit priority: 0 none, 1 high, 5 medium, 9 low.
+    pub priority: u8,
+    pub calendar_index: usize,
+    pub active_field: ReminderField,
+}
+
+impl ReminderFormState {
+    pub fn from_reminder(rem: &Reminder, lists: &[CalendarInfo]) -> Self {
+        Self {
+            reminder_id: rem.id.clone(),
+            title: rem.title.clone(),
+            due_date: rem
+                .due_date
+                .map(|d| d.format("%Y-%m-%d").to_string())
+                .unwrap_or_default(),
+            priority: rem.priority,
+            calendar_index: lists
+                .iter()
+                .position(|c| c.id == rem.calendar_id)
+                .unwrap_or(0),
+            active_field: ReminderField::Title,
+        }
+    }
+
+    pub fn parsed_due_date(&self) -> Option<NaiveDate> {
+        NaiveDate::parse_from_str(&self.due_date, "%Y-%m-%d").ok()
+    }
+
+    pub fn input_char(&mut self, c: char) {
+        match self.active_field {
+            ReminderField::Title => self.title.push(c),
+            ReminderField::Due => self.due_date.push(c),
+            ReminderField::Priority | ReminderField::List => {}
+        }
+    }
+
+    pub fn backspace(&mut self) {
+        match self.active_field {
+            ReminderField::Title => { self.title.pop(); }
+            ReminderField::Due => { self.due_date.pop(); }
+            ReminderField::Priority | ReminderField::List => {}
+        }
+    }
+
+    /// Cycle None → High → Medium → Low → None.
+    pub fn cycle_priority(&mut self) {
+        self.priority = match self.priority {
+            0 => 1,
+            1..=4 => 5,
+            5 => 9,
+            _ => 0,
+        };
+    }
+
+    pub fn next_list(&mut self, total: usize) {
+        if total > 0 {
+            self.calendar_index = (self.calendar_index + 1) % total;
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.title.is_empty() && (self.due_date.is_empty() || self.parsed_due_date().is_some())
+    }
+}
+
+pub fn priority_label(priority: u8) -> &'static str {
+    match priority {
+        1..=4 => "High",
+        5 => "Medium",
+        6..=9 => "Low",
+        _ => "None",
+    }
+}
+
+pub struct ReminderForm;
+
+impl ReminderForm {
+    pub fn render(
+        frame: &mut Frame,
+        area: Rect,
+        state: &ReminderFormState,
+        lists: &[CalendarInfo],
+    ) {
+        let form_w = area.width.clamp(30, 50);
+        let form_h = area.height.clamp(8, 10);
+        let x = area.x + (area.width.saturating_sub(form_w)) / 2;
+        let y = area.y + (area.height.saturating_sub(form_h)) / 2;
+        let form_area = Rect::new(x, y, form_w, form_h);
+
+        frame.render_widget(Clear, form_area);
+
+        let block = Block::default()
+            .title(" Edit Reminder ")
+            .title_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow));
+
+        let inner = block.inner(form_area);
+        frame.render_widget(block, form_area);
+
+        let rows = Layout::vertical([
+            Constraint::Length(1), // title
+            Constraint::Length(1), // due
+            Constraint::Length(1), // priority
+            Constraint::Length(1), // list
+            Constraint::Length(1), // spacer
+            Constraint::Length(1), // help
+            Constraint::Min(0),
+        ])
+        .split(inner);
+
+        render_field(frame, rows[0], "Title:", &state.title, state.active_field == ReminderField::Title);
+        let due = if state.due_date.is_empty() && state.active_field != ReminderField::Due {
+            "none"
+        } else {
+            state.due_date.as_str()
+        };
+        render_field(frame, rows[1], "Due:", due, state.active_field == ReminderField::Due);
+        render_field(
+            frame,
+            rows[2],
+            "Prio:",
+            priority_label(state.priority),
+            state.active_field == ReminderField::Priority,
+        );
+
+        let list_name = lists
+            .get(state.calendar_index)
+            .map(|c| c.title.as_str())
+            .unwrap_or("Default");
+        render_field(frame, rows[3], "List:", list_name, state.active_field == ReminderField::List);
+
+        let help = Line::from(vec![
+            Span::styled("Tab", Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled(":Next ", theme::current().dim),
+            Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled(":Save ", theme::current().dim),
+            Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled(":Cancel", theme::current().dim),
+        ]);
+        frame.render_widget(Paragraph::new(help), rows[5]);
+    }
+}
+
+fn render_field(frame: &mut Frame, area: Rect, label: &str, value: &str, active: bool) {
+    let cursor = if active { "_" } else { "" };
+    let style = if active {
+        Style::default().fg(Color::Cyan)
+    } else {
+        Style::default()
+    };
+
+    let spans = vec![
+        Span::styled(format!("{:<7}", label), theme::current().dim),
+        Span::styled(format!("{}{}", value, cursor), style),
+    ];
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
@@ -0,0 +1,64 @@
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::calendar::CalendarEvent;
+use crate::theme;
+
+/// Full-screen alarm for an event that just started.
+pub struct StartAlert;
+
+impl StartAlert {
+    /// `waiting` counts further alerts queued behind this one.
+    pub fn render(frame: &mut Frame, area: Rect, ev: &CalendarEvent, snooze_minutes: u32, waiting: usize) {
+        frame.render_widget(Clear, area);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(ev.calendar_color).add_modifier(Modifier::BOLD));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let key_style = Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+        let mut lines = vec![
+            Line::from(Span::styled("Starting now", theme::current().dim)),
+            Line::from(""),
+            Line::from(Span::styled(
+                ev.title.clone(),
+                Style::default().fg(ev.calendar_color).add_modifier(Modifier::BOLD),
+            )),
+            Line::from(ev.duration_display()),
+        ];
+        if let Some(loc) = ev.location.as_deref().filter(|loc| !loc.is_empty()) {
+            lines.push(Line::from(Span::styled(loc.to_string(), theme::current().dim)));
+        }
+        lines.push(Line::from(Span::styled(ev.calendar_name.clone(), theme::current().dim)));
+        lines.push(Line::from(""));
+
+        let mut keys = vec![
+            Span::styled("Enter", key_style),
+            Span::raw(" dismiss   "),
+            Span::styled("s", key_style),
+            Span::raw(format!(" snooze {} min", snooze_minutes)),
+        ];
+        if let Some(link) = ev.video_link() {
+            keys.push(Span::raw("   "));
+            keys.push(Span::styled("o", key_style));
+            keys.push(Span::raw(format!(" join {}", link.service.label())));
+        }
+        lines.push(Line::from(keys));
+        if waiting > 0 {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(format!("{} more starting", waiting), theme::current().dim)));
+        }
+
+        // Center the text vertically
+        let top = inner.height.saturating_sub(lines.len() as u16) / 2;
+        let text_area = Rect::new(inner.x, inner.y + top, inner.width, inner.height - top);
+        let para = Paragraph::new(lines).alignment(Alignment::Center).wrap(Wrap { trim: false });
+        frame.render_widget(para, text_area);
+    }
+}
@@ -0,0 +1,41 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::Span,
+    widgets::{Clear, Paragraph},
+    Frame,
+};
+
+use crate::text;
+use crate::theme;
+use crate::toast::{Severity, Toasts};
+
+/// Transient messages, newest at the bottom-right of `area`.
+pub struct ToastStack;
+
+impl ToastStack {
+    pub fn render(frame: &mut Frame, area: Rect, toasts: &Toasts) {
+        let mut y = area.bottom();
+        for toast in toasts.iter().collect::<Vec<_>>().into_iter().rev() {
+            if y <= area.y {
+                break;
+            }
+            y -= 1;
+            let room = area.width.saturating_sub(2) as usize;
+            let label = format!(" {} ", text::truncate(&toast.text, room));
+            let width = (Span::raw(label.as_str()).width() as u16).min(area.width);
+            let rect = Rect::new(area.right() - width, y, width, 1);
+            frame.render_widget(Clear, rect);
+            frame.render_widget(Paragraph::new(label).style(style(toast.severity)), rect);
+        }
+    }
+}
+
+fn style(severity: Severity) -> Style {
+    match severity {
+        Severity::Info => theme::current().status,
+        Severity::Success => Style::default().fg(Color::Black).bg(Color::Green),
+        Severity::Warning => Style::default().fg(Color::Black).bg(Color::Yellow),
+        Severity::Error => Style::default().fg(Color::White).bg(Color::Red),
+    }
+}
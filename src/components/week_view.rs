@@ -1,21 +1,38 @@
-use chrono::{Local, NaiveDate, Timelike};
+use std::collections::HashSet;
+
+use chrono::{Datelike, Local, NaiveDate, TimeZone, Timelike, Weekday};
 use ratatui::{
     layout::{Constraint, Layout, Rect},
-    style::{Modifier, Style},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
 
-use crate::calendar::CalendarEvent;
+use crate::calendar::time::format_time;
+use crate::calendar::{CalendarEvent, HourLabels};
+use crate::components::day_view::hidden_label;
+use crate::config::WeekendMode;
+use crate::layout;
+use crate::text;
 use crate::theme;
 
-const HOUR_START: u32 = 6;
-const HOUR_END: u32 = 23;
+/// Narrowest sub-column used when overlapping events share an hour cell.
+const MIN_SUBCOL_W: u16 = 4;
+/// Width of a weekend column in [`WeekendMode::Narrow`]: room for "dd".
+const NARROW_COL_W: u16 = 3;
+
+/// A horizontal band of each day column holding one account's events.
+#[derive(Debug, Clone)]
+pub struct Lane {
+    pub name: String,
+    pub calendar_ids: HashSet<String>,
+}
 
 pub struct WeekView;
 
 impl WeekView {
+    #[allow(clippy::too_many_arguments)]
     pub fn render(
         frame: &mut Frame,
         area: Rect,
@@ -23,16 +40,50 @@ impl WeekView {
         today: NaiveDate,
         week_start: NaiveDate,
         events: &[CalendarEvent],
+        selected_event: Option<&CalendarEvent>,
+        (hour_start, hour_end): (u32, u32),
+        weekend: WeekendMode,
+        lanes: &[Lane],
+        hidden: usize,
+        week_numbers: bool,
+        busy_limit: Option<u32>,
     ) {
-        let block = Block::default()
+        let lane_legend = if lanes.is_empty() {
+            String::new()
+        } else {
+            let names: Vec<&str> = lanes.iter().map(|l| l.name.as_str()).collect();
+            format!("\u{00b7} {} ", names.join(" | "))
+        };
+        // Weeks start on Sunday; a work week is titled by its Monday
+        let (heading, first_shown) = if weekend == WeekendMode::Hidden {
+            ("Work week", week_start + chrono::Duration::days(1))
+        } else {
+            ("Week", week_start)
+        };
+        let week_number = if week_numbers {
+            // ISO weeks are numbered by their Monday
+            let monday = week_start + chrono::Duration::days(1);
+            format!("\u{00b7} W{:02} ", monday.iso_week().week())
+        } else {
+            String::new()
+        };
+        let mut block = Block::default()
             .title(format!(
-                " Week of {} ",
-                week_start.format("%b %d, %Y")
+                " {} of {} {}{}",
+                heading,
+                first_shown.format("%b %d, %Y"),
+                week_number,
+                lane_legend
             ))
             .title_style(theme::current().header)
             .borders(Borders::ALL)
             .border_style(theme::current().border);
-
+        if hidden > 0 {
+            block = block.title_bottom(Line::from(Span::styled(
+                format!(" {} ", hidden_label(hidden)),
+                theme::current().dim,
+            )));
+        }
         let inner = block.inner(area);
         frame.render_widget(block, area);
 
@@ -40,18 +91,29 @@ impl WeekView {
             return;
         }
 
+        // Cells rarely fit a whole title, so the selected event gets an
+        // info line under the grid
+        let inner = match selected_event {
+            Some(ev) if inner.height > 4 => {
+                let parts = Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).split(inner);
+                frame.render_widget(Paragraph::new(info_line(ev, parts[1].width as usize)), parts[1]);
+                parts[0]
+            }
+            _ => inner,
+        };
+
         let inner_w = inner.width as usize;
         let inner_h = inner.height as usize;
 
         // Time label column width
         let time_col_w: u16 = if inner_w >= 70 { 6 } else { 4 };
         let day_cols_w = inner.width.saturating_sub(time_col_w);
-        let col_w = (day_cols_w / 7).max(1);
+        let col_widths = column_widths(week_start, day_cols_w, weekend);
 
         // Layout: time label | 7 day columns
         let mut col_constraints = vec![Constraint::Length(time_col_w)];
-        for _ in 0..7 {
-            col_constraints.push(Constraint::Length(col_w));
+        for &w in &col_widths {
+            col_constraints.push(Constraint::Length(w));
         }
         col_constraints.push(Constraint::Min(0)); // absorb remainder
 
@@ -60,10 +122,9 @@ impl WeekView {
         // Determine visible hours based on height
         // Reserve 1 row for day headers
         let content_rows = inner_h.saturating_sub(1);
-        let total_hours = (HOUR_END - HOUR_START) as usize;
+        let total_hours = hour_end.saturating_sub(hour_start).max(1) as usize;
         let rows_per_hour = (content_rows / total_hours).max(1);
         let visible_hours = (content_rows / rows_per_hour).min(total_hours);
-        let hour_start = HOUR_START;
         // Row layout: header + hour rows
         let mut row_constraints = vec![Constraint::Length(1)]; // day header
         for _ in 0..visible_hours {
@@ -80,6 +141,10 @@ impl WeekView {
             if col_idx >= cols.len() {
                 break;
             }
+            let col_w = col_widths[day_offset as usize];
+            if col_w == 0 {
+                continue;
+            }
 
             let day_label = if col_w >= 10 {
                 format!("{}", date.format("%a %d"))
@@ -100,10 +165,21 @@ impl WeekView {
                 t.header
             };
 
-            let label = Paragraph::new(Line::from(Span::styled(
-                format!("{:^width$}", day_label, width = col_w as usize),
-                style,
-            )));
+            // Over-scheduled days get a "!" after the label
+            let over = busy_limit.is_some_and(|limit| layout::busy_minutes(events, date) > limit);
+            let label = if over && col_w >= 3 {
+                let text = format!("{:^width$}", format!("{}!", day_label), width = col_w as usize);
+                let (name, rest) = text.split_at(text.rfind('!').unwrap_or(text.len()));
+                Paragraph::new(Line::from(vec![
+                    Span::styled(name.to_string(), style),
+                    Span::styled(rest.to_string(), style.fg(Color::Red).add_modifier(Modifier::BOLD)),
+                ]))
+            } else {
+                Paragraph::new(Line::from(Span::styled(
+                    format!("{:^width$}", day_label, width = col_w as usize),
+                    style,
+                )))
+            };
             frame.render_widget(label, cols[col_idx].intersection(rows[0]));
         }
 
@@ -116,11 +192,11 @@ impl WeekView {
             }
 
             // Time label
-            let time_label = if time_col_w >= 6 {
-                format!("{:>2}:00 ", hour)
-            } else {
-                format!("{:>2} ", hour)
-            };
+            let time_label = format!(
+                "{:>w$} ",
+                HourLabels::current().label(hour, time_col_w < 6),
+                w = time_col_w as usize - 1
+            );
             let time_para = Paragraph::new(Line::from(Span::styled(
                 time_label,
                 theme::current().dim,
@@ -144,45 +220,39 @@ impl WeekView {
                 let cell_events: Vec<&CalendarEvent> = events
                     .iter()
                     .filter(|ev| {
-                        let ev_date = ev.start.date_naive();
-                        if ev_date != date && !ev.is_all_day {
-                            // Check if multi-day or the event spans into this day
-                            let ev_end_date = ev.end.date_naive();
-                            if ev_end_date < date || ev_date > date {
-                                return false;
-                            }
-                        } else if ev_date != date {
-                            return false;
-                        }
-
                         if ev.is_all_day {
-                            return hour == hour_start; // show all-day at top
+                            return hour == hour_start && ev.occurs_on(date); // show all-day at top
                         }
-
-                        let ev_start_hour = ev.start.hour();
-                        let ev_end_hour = if ev.end.minute() > 0 {
-                            ev.end.hour()
-                        } else {
-                            ev.end.hour().saturating_sub(1)
-                        };
-                        hour >= ev_start_hour && hour <= ev_end_hour
+                        // Overlap with this hour slot, so events spanning midnight
+                        // fill the following day from the top
+                        let slot_start = date
+                            .and_hms_opt(hour, 0, 0)
+                            .and_then(|t| Local.from_local_datetime(&t).earliest());
+                        slot_start.is_some_and(|slot| {
+                            ev.start < slot + chrono::Duration::hours(1) && ev.end > slot
+                        })
                     })
                     .collect();
 
-                if !cell_events.is_empty() {
-                    let ev = cell_events[0];
-                    let max_title_len = cell_area.width as usize;
-                    let title: String = ev.title.chars().take(max_title_len).collect();
-                    let display = format!("{:<width$}", title, width = max_title_len);
-
-                    let style = Style::default()
-                        .fg(ratatui::style::Color::Black)
-                        .bg(ev.calendar_color);
+                if lanes.is_empty() {
+                    if !cell_events.is_empty() {
+                        render_cell(frame, cell_area, cell_events, selected_event);
+                    }
+                    continue;
+                }
 
-                    let lines: Vec<Line> = vec![Line::from(Span::styled(display, style))];
-                    // Fill remaining rows of the cell if rows_per_hour > 1
-                    let para = Paragraph::new(lines);
-                    frame.render_widget(para, cell_area);
+                // One sub-column per account, in lane order
+                let lane_constraints = vec![Constraint::Ratio(1, lanes.len() as u32); lanes.len()];
+                let lane_areas = Layout::horizontal(lane_constraints).split(cell_area);
+                for (lane, &lane_area) in lanes.iter().zip(lane_areas.iter()) {
+                    let lane_events: Vec<&CalendarEvent> = cell_events
+                        .iter()
+                        .copied()
+                        .filter(|ev| lane.calendar_ids.contains(&ev.calendar_id))
+                        .collect();
+                    if !lane_events.is_empty() {
+                        render_cell(frame, lane_area, lane_events, selected_event);
+                    }
                 }
             }
         }
@@ -224,7 +294,7 @@ impl WeekView {
                 // Also mark the time label
                 let time_area = cols[0];
                 let label_area = Rect::new(time_area.x, line_y, time_area.width, 1);
-                let now_time_str = format!("{:>2}:{:02}", now_hour, now_minute);
+                let now_time_str = format_time(now);
                 let padded = format!("{:<width$}", now_time_str, width = time_area.width as usize);
                 let time_label = Paragraph::new(Line::from(Span::styled(
                     padded,
@@ -237,3 +307,96 @@ impl WeekView {
         }
     }
 }
+
+/// Draw the events of one hour cell side by side, with a "+N" marker when
+/// there are more than fit.
+fn render_cell(
+    frame: &mut Frame,
+    area: Rect,
+    mut events: Vec<&CalendarEvent>,
+    selected_event: Option<&CalendarEvent>,
+) {
+    let is_selected =
+        |ev: &CalendarEvent| selected_event.is_some_and(|sel| ev.id == sel.id && ev.start == sel.start);
+
+    let max_cols = (area.width / MIN_SUBCOL_W).max(1) as usize;
+    let overflow = events.len() > max_cols;
+    let shown = if overflow { max_cols.saturating_sub(1).max(1) } else { events.len() };
+
+    // Keep the selected event visible so the cursor never disappears
+    if let Some(pos) = events.iter().position(|ev| is_selected(ev)) {
+        if pos >= shown {
+            let ev = events.remove(pos);
+            events.insert(shown - 1, ev);
+        }
+    }
+
+    let slots = if overflow && shown < max_cols { shown + 1 } else { shown };
+    let cols = Layout::horizontal(vec![Constraint::Ratio(1, slots as u32); slots]).split(area);
+
+    for (ev, &sub) in events.iter().take(shown).zip(cols.iter()) {
+        let width = sub.width as usize;
+        let mut title = text::truncate(&ev.title, width);
+        // The repeat marker only when it fits next to the whole title
+        if let Some(marker) = ev.repeat_marker().filter(|_| ev.title.chars().count() + 2 <= width) {
+            title = format!("{} {}", title, marker);
+        }
+        let display = format!("{:<width$}", title, width = width);
+
+        let mut style = Style::default()
+            .fg(ratatui::style::Color::Black)
+            .bg(ev.calendar_color);
+        if is_selected(ev) {
+            style = style.add_modifier(Modifier::BOLD | Modifier::REVERSED);
+        }
+        frame.render_widget(Paragraph::new(Line::from(Span::styled(display, style))), sub);
+    }
+
+    if overflow && slots > shown {
+        let more = format!("+{}", events.len() - shown);
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(more, theme::current().dim))),
+            cols[shown],
+        );
+    }
+}
+
+/// Per-day column widths for the week starting at `week_start`.
+fn column_widths(week_start: NaiveDate, total: u16, weekend: WeekendMode) -> [u16; 7] {
+    let is_weekend = |offset: usize| {
+        let day = week_start + chrono::Duration::days(offset as i64);
+        matches!(day.weekday(), Weekday::Sat | Weekday::Sun)
+    };
+    let weekend_w = match weekend {
+        WeekendMode::Full => None,
+        WeekendMode::Narrow => Some(NARROW_COL_W),
+        WeekendMode::Hidden => Some(0),
+    };
+
+    let mut widths = [0u16; 7];
+    let Some(weekend_w) = weekend_w else {
+        widths.fill((total / 7).max(1));
+        return widths;
+    };
+    let weekend_days = (0..7).filter(|&i| is_weekend(i)).count() as u16;
+    let weekday_w = (total.saturating_sub(weekend_w * weekend_days) / (7 - weekend_days)).max(1);
+    for (i, w) in widths.iter_mut().enumerate() {
+        *w = if is_weekend(i) { weekend_w } else { weekday_w };
+    }
+    widths
+}
+
+/// "▌ Design review · Tue 14:00 - 15:00 · Room 4" for the selected event.
+fn info_line(ev: &CalendarEvent, width: usize) -> Line<'static> {
+    let when = format!("{} {}", ev.start.format("%a"), ev.time_range_on(ev.start.date_naive()));
+    let mut details = format!(" \u{00b7} {}", when);
+    if let Some(loc) = ev.location.as_deref().filter(|loc| !loc.is_empty()) {
+        details.push_str(&format!(" \u{00b7} {}", loc));
+    }
+    let title_w = width.saturating_sub(2 + details.chars().count()).max(width / 2);
+    Line::from(vec![
+        Span::styled("\u{258c} ", Style::default().fg(ev.calendar_color)),
+        Span::styled(text::truncate(&ev.title, title_w), Style::default().add_modifier(Modifier::BOLD)),
+        Span::styled(details, theme::current().dim),
+    ])
+}
@@ -0,0 +1,343 @@
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::{eyre, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::calendar::{CalendarEvent, HourLabels, TimeFormat};
+use crate::layout::DaySection;
+use crate::text::Truncation;
+
+/// User settings stored in `~/.config/calendar-tui/config.toml`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Config {
+    /// Calendar identifiers whose events and reminders are not shown.
+    pub hidden_calendars: Vec<String>,
+    /// Event identifiers pinned to the top of the day list.
+    pub pinned_events: Vec<String>,
+    /// How Saturday and Sunday columns are drawn in week view.
+    pub weekend: WeekendMode,
+    /// Show only Monday to Friday in week view; shorthand for `weekend = "hidden"`.
+    pub work_week: bool,
+    /// Saved event filters, switched with `:filter <name>`, or `z1`-`z9`
+    /// by their place in the list.
+    pub filters: Vec<Filter>,
+    /// Shell commands added as actions, run with `:name` or their key.
+    pub actions: Vec<CustomAction>,
+    /// Keys pressed after `n` that open the event form on a given calendar,
+    /// e.g. `nw` for work.
+    pub new_event_shortcuts: Vec<EventShortcut>,
+    /// First hour shown in week view (0-23).
+    pub week_start_hour: u32,
+    /// Hour at which the week view grid ends (1-24, exclusive).
+    pub week_end_hour: u32,
+    /// First hour of the working day, searched by `:officehours`.
+    pub work_start_hour: u32,
+    /// Hour at which the working day ends (exclusive).
+    pub work_end_hour: u32,
+    /// Split week view day columns into one lane per account.
+    pub week_lanes: bool,
+    /// Draw the day view as an hour timeline instead of a list.
+    pub day_timeline: bool,
+    /// Sections of the day list, top to bottom: any of "pinned", "all_day",
+    /// "reminders" and "timed". Sections left out are not shown; unknown
+    /// names are skipped with a warning.
+    pub day_sections: Vec<DaySection>,
+    /// Leave out invitations you declined.
+    pub hide_declined: bool,
+    /// Clock used to show and enter times: "24h" or "12h".
+    pub time_format: TimeFormat,
+    /// Hour labels on the week view and day timeline: "auto", "24h", "12h"
+    /// or "compact".
+    pub hour_labels: HourLabels,
+    /// How titles too long for their cell are shortened: "end", "middle"
+    /// or "word".
+    pub truncation: Truncation,
+    /// Granularity in minutes for default start times of new events.
+    pub snap_minutes: u32,
+    /// Ask before deleting an event.
+    pub confirm_delete: bool,
+    /// Calendar (by title) that `:focus` puts focus days on; the first
+    /// calendar when unset.
+    pub work_calendar: Option<String>,
+    /// Your email address, written as the organizer of exported invites.
+    pub email: Option<String>,
+    /// Shortest gap between timed events, in minutes, shown as a break in
+    /// the day list; 0 shows no breaks.
+    pub min_break_minutes: u32,
+    /// Hours of meetings in a day past which the day is flagged as
+    /// over-scheduled; 0 turns the warning off.
+    pub busy_warning_hours: u32,
+    /// Show ISO week numbers in month view and the week view title.
+    pub show_week_numbers: bool,
+    /// Minutes without a key press before the calendar is hidden; 0 never hides it.
+    pub idle_minutes: u32,
+    /// What idle hiding does: "busy" replaces titles with "Busy", "blank"
+    /// clears the screen.
+    pub idle_mode: IdleMode,
+    /// Cue when a reminder is completed or an event is saved: "off", "bell"
+    /// or "flash".
+    pub feedback: Feedback,
+    /// Cover the screen with an alert when an event starts while the app
+    /// is open.
+    pub start_alerts: bool,
+    /// Minutes until a snoozed start alert returns.
+    pub snooze_minutes: u32,
+    /// Show the permissions and accounts summary on launch; `:status`
+    /// opens it at any time.
+    pub startup_check: bool,
+    /// Problems in the file that were worked around, shown at launch.
+    #[serde(skip)]
+    pub warnings: Vec<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            hidden_calendars: Vec::new(),
+            pinned_events: Vec::new(),
+            weekend: WeekendMode::default(),
+            work_week: false,
+            filters: Vec::new(),
+            actions: Vec::new(),
+            new_event_shortcuts: Vec::new(),
+            week_start_hour: 6,
+            week_end_hour: 23,
+            work_start_hour: 9,
+            work_end_hour: 17,
+            week_lanes: false,
+            day_timeline: false,
+            day_sections: DaySection::DEFAULT_ORDER.to_vec(),
+            hide_declined: false,
+            time_format: TimeFormat::default(),
+            hour_labels: HourLabels::default(),
+            truncation: Truncation::default(),
+            snap_minutes: 15,
+            confirm_delete: true,
+            work_calendar: None,
+            email: None,
+            min_break_minutes: 0,
+            busy_warning_hours: 6,
+            show_week_numbers: false,
+            idle_minutes: 0,
+            idle_mode: IdleMode::default(),
+            feedback: Feedback::default(),
+            start_alerts: false,
+            snooze_minutes: 5,
+            startup_check: true,
+            warnings: Vec::new(),
+        }
+    }
+}
+
+/// A named slice of the schedule, e.g. work calendars only.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Filter {
+    pub name: String,
+    /// Calendar titles to keep (case-insensitive); empty keeps every calendar.
+    pub calendars: Vec<String>,
+    /// Keep only events with a link in their URL, location or notes.
+    pub links_only: bool,
+}
+
+impl Filter {
+    pub fn matches(&self, ev: &CalendarEvent) -> bool {
+        let calendar_ok = self.calendars.is_empty()
+            || self
+                .calendars
+                .iter()
+                .any(|c| c.eq_ignore_ascii_case(&ev.calendar_name));
+        let link_ok = !self.links_only
+            || [&ev.url, &ev.location, &ev.notes]
+                .into_iter()
+                .flatten()
+                .any(|text| text.contains("://"));
+        calendar_ok && link_ok
+    }
+}
+
+/// A user-defined action running a shell command.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct CustomAction {
+    pub name: String,
+    /// Key that runs it from the calendar, if the app doesn't use it already.
+    pub key: Option<char>,
+    /// Shown in the help popup; the command itself when empty.
+    pub description: String,
+    /// Run with `sh -c`. The selected date is in `CAL_DATE` and the
+    /// selected event or reminder in the other `CAL_*` variables.
+    pub command: String,
+}
+
+/// A key that, pressed after `n`, opens the event form with a calendar
+/// and alert already picked.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct EventShortcut {
+    pub key: char,
+    /// Calendar title (case-insensitive).
+    pub calendar: String,
+    /// Minutes before the start to alert; no alert when unset.
+    pub alert_minutes: Option<u32>,
+}
+
+impl Config {
+    pub fn event_shortcut(&self, key: char) -> Option<&EventShortcut> {
+        self.new_event_shortcuts.iter().find(|s| s.key == key)
+    }
+}
+
+/// Week view treatment of weekend columns.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WeekendMode {
+    #[default]
+    Full,
+    /// Just wide enough for the day number.
+    Narrow,
+    Hidden,
+}
+
+impl WeekendMode {
+    pub fn next(self) -> Self {
+        match self {
+            WeekendMode::Full => WeekendMode::Narrow,
+            WeekendMode::Narrow => WeekendMode::Hidden,
+            WeekendMode::Hidden => WeekendMode::Full,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            WeekendMode::Full => "full",
+            WeekendMode::Narrow => "narrow",
+            WeekendMode::Hidden => "hidden",
+        }
+    }
+}
+
+/// Privacy treatment once the app has been idle for `idle_minutes`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IdleMode {
+    /// Keep the layout but show every event and reminder as "Busy".
+    #[default]
+    Busy,
+    /// Show nothing but a prompt to press a key.
+    Blank,
+}
+
+/// Confirmation cue after completing or saving something.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Feedback {
+    #[default]
+    Off,
+    /// Ring the terminal bell.
+    Bell,
+    /// Briefly invert the status bar.
+    Flash,
+}
+
+impl Config {
+    /// Load the config file, falling back to defaults if it is missing or invalid.
+    pub fn load() -> Self {
+        config_path()
+            .filter(|p| p.exists())
+            .and_then(|p| std::fs::read_to_string(p).ok())
+            .and_then(|content| Self::parse(&content))
+            .unwrap_or_default()
+    }
+
+    /// Read config text. Unknown `day_sections` names, e.g. from a newer
+    /// version, are left out with a warning instead of failing the file.
+    pub fn parse(content: &str) -> Option<Self> {
+        let mut table: toml::Table = toml::from_str(content).ok()?;
+        let mut warnings = Vec::new();
+        if let Some(toml::Value::Array(sections)) = table.get_mut("day_sections") {
+            sections.retain(|name| {
+                let known = name.clone().try_into::<DaySection>().is_ok();
+                if !known {
+                    warnings.push(format!("Unknown day section {} in config.toml skipped", name));
+                }
+                known
+            });
+        }
+        let mut config: Self = table.try_into().ok()?;
+        config.warnings = warnings;
+        Some(config)
+    }
+
+    /// Effective weekend treatment, honouring `work_week`.
+    pub fn weekend_mode(&self) -> WeekendMode {
+        if self.work_week {
+            WeekendMode::Hidden
+        } else {
+            self.weekend
+        }
+    }
+
+    /// The configured week view hours, falling back to the defaults when the
+    /// range is empty or out of bounds.
+    pub fn week_hours(&self) -> (u32, u32) {
+        if self.week_start_hour < self.week_end_hour && self.week_end_hour <= 24 {
+            (self.week_start_hour, self.week_end_hour)
+        } else {
+            let defaults = Config::default();
+            (defaults.week_start_hour, defaults.week_end_hour)
+        }
+    }
+
+    /// The configured snap granularity; values that do not divide an hour
+    /// evenly fall back to the default.
+    pub fn snap_minutes(&self) -> u32 {
+        if (1..=60).contains(&self.snap_minutes) && 60 % self.snap_minutes == 0 {
+            self.snap_minutes
+        } else {
+            Config::default().snap_minutes
+        }
+    }
+
+    /// Threshold for break rows in the day list, if they are on.
+    pub fn min_break(&self) -> Option<u32> {
+        (self.min_break_minutes > 0).then_some(self.min_break_minutes)
+    }
+
+    /// Busy minutes past which a day is over-scheduled, if the warning is on.
+    pub fn busy_limit(&self) -> Option<u32> {
+        (self.busy_warning_hours > 0).then(|| self.busy_warning_hours.min(24) * 60)
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = config_path().ok_or_else(|| eyre!("No home directory"))?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let content = toml::to_string_pretty(self)?;
+        write_atomic(&path, &content)?;
+        Ok(())
+    }
+}
+
+/// Write `content` through a temporary file and a rename, so another
+/// instance reading the file never sees it half written.
+pub fn write_atomic(path: &Path, content: &str) -> Result<()> {
+    let tmp = path.with_extension(format!("tmp.{}", std::process::id()));
+    std::fs::write(&tmp, content)?;
+    std::fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+/// Directory holding config and other persisted files (`~/.config/calendar-tui`).
+pub fn config_dir() -> Option<PathBuf> {
+    if let Some(home) = dirs::home_dir() {
+        return Some(home.join(".config").join("calendar-tui"));
+    }
+    dirs::config_dir().map(|d| d.join("calendar-tui"))
+}
+
+fn config_path() -> Option<PathBuf> {
+    config_dir().map(|d| d.join("config.toml"))
+}
@@ -2,6 +2,14 @@ use std::time::Duration;
 
 use crossterm::event::{self, Event, KeyEvent};
 
+/// What the main loop reacts to.
+pub enum Input {
+    Key(KeyEvent),
+    /// The terminal gained (`true`) or lost focus.
+    Focus(bool),
+    Resize,
+}
+
 pub fn poll_event(timeout: Duration) -> color_eyre::Result<Option<Event>> {
     if event::poll(timeout)? {
         Ok(Some(event::read()?))
@@ -10,10 +18,13 @@ pub fn poll_event(timeout: Duration) -> color_eyre::Result<Option<Event>> {
     }
 }
 
-pub fn next_key_event(timeout: Duration) -> color_eyre::Result<Option<KeyEvent>> {
+pub fn next_input(timeout: Duration) -> color_eyre::Result<Option<Input>> {
     loop {
         match poll_event(timeout)? {
-            Some(Event::Key(key)) => return Ok(Some(key)),
+            Some(Event::Key(key)) => return Ok(Some(Input::Key(key))),
+            Some(Event::FocusGained) => return Ok(Some(Input::Focus(true))),
+            Some(Event::FocusLost) => return Ok(Some(Input::Focus(false))),
+            Some(Event::Resize(..)) => return Ok(Some(Input::Resize)),
             Some(_) => continue,
             None => return Ok(None),
         }
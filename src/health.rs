@@ -0,0 +1,110 @@
+//! Startup summary of what the app can see: permissions for events and
+//! reminders, calendars per account and when data was last loaded.
+
+use std::collections::{BTreeMap, HashSet};
+
+use chrono::{DateTime, Local};
+
+use crate::calendar::{Access, CalendarInfo, Permissions};
+
+/// Where macOS keeps the calendar and reminder permissions.
+const SETTINGS_PATH: &str = "System Settings > Privacy & Security";
+
+/// Calendars and reminder lists of one account.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SourceCount {
+    pub source: String,
+    pub calendars: usize,
+    /// Calendars hidden with `c` or `:hide`.
+    pub hidden: usize,
+    pub reminder_lists: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Health {
+    pub permissions: Permissions,
+    pub sources: Vec<SourceCount>,
+    /// When events were last loaded; `None` if they never were.
+    pub last_fetch: Option<DateTime<Local>>,
+    /// Why the events on screen may be incomplete.
+    pub gaps: Vec<String>,
+}
+
+impl Health {
+    pub fn check(
+        permissions: Permissions,
+        calendars: &[CalendarInfo],
+        reminder_calendars: &[CalendarInfo],
+        hidden: &HashSet<String>,
+        last_fetch: Option<DateTime<Local>>,
+    ) -> Self {
+        let mut by_source: BTreeMap<String, SourceCount> = BTreeMap::new();
+        for cal in calendars {
+            let source = source_entry(&mut by_source, cal);
+            source.calendars += 1;
+            source.hidden += hidden.contains(&cal.id) as usize;
+        }
+        for cal in reminder_calendars {
+            source_entry(&mut by_source, cal).reminder_lists += 1;
+        }
+        Self { permissions, sources: by_source.into_values().collect(), last_fetch, gaps: Vec::new() }
+    }
+
+    /// Problems worth acting on, each with what to do about it.
+    pub fn issues(&self) -> Vec<String> {
+        let mut issues = Vec::new();
+        for (kind, access) in [("Calendars", self.permissions.events), ("Reminders", self.permissions.reminders)] {
+            match access {
+                Access::Full => {}
+                Access::WriteOnly => issues.push(format!(
+                    "{} access is add-only: choose Full Access in {} > {}",
+                    kind, SETTINGS_PATH, kind
+                )),
+                Access::Denied => issues.push(format!("{} access denied: allow it in {} > {}", kind, SETTINGS_PATH, kind)),
+                Access::Restricted => issues.push(format!("{} access is restricted by a device profile", kind)),
+                Access::NotDetermined => issues.push(format!("{} access was never granted: restart to be asked", kind)),
+            }
+        }
+        if self.permissions.events.can_read() && self.sources.iter().all(|s| s.calendars == 0) {
+            issues.push("No calendars found: add an account in System Settings > Internet Accounts".to_string());
+        }
+        for source in &self.sources {
+            if source.calendars > 0 && source.hidden == source.calendars {
+                issues.push(format!("Every {} calendar is hidden: press c to show some", source.source));
+            }
+        }
+        if self.permissions.events.can_read() && self.last_fetch.is_none() {
+            issues.push("Events have not loaded yet: press R to sync".to_string());
+        }
+        issues.extend(self.gaps.iter().map(|gap| format!("Possibly incomplete: {}", gap)));
+        issues
+    }
+}
+
+/// One-line warning when the app runs with only part of the access it
+/// needs, or `None`. Unreadable events get the full access screen instead.
+pub fn access_banner(permissions: Permissions) -> Option<String> {
+    match (permissions.events, permissions.reminders) {
+        (Access::WriteOnly, _) => Some(format!(
+            "Calendar access is add-only: new events are saved but not shown \u{2014} choose Full Access in {} > Calendars",
+            SETTINGS_PATH
+        )),
+        (Access::Full, Access::Full) | (Access::Full, Access::NotDetermined) => None,
+        (Access::Full, Access::Restricted) => {
+            Some("Reminders are restricted by a device profile \u{2014} showing events only".to_string())
+        }
+        (Access::Full, reminders) => Some(format!(
+            "Reminders access {} \u{2014} showing events only; allow it in {} > Reminders",
+            reminders.label(),
+            SETTINGS_PATH
+        )),
+        _ => None,
+    }
+}
+
+fn source_entry<'a>(sources: &'a mut BTreeMap<String, SourceCount>, cal: &CalendarInfo) -> &'a mut SourceCount {
+    let name = if cal.source.is_empty() { "Other".to_string() } else { cal.source.clone() };
+    sources
+        .entry(name.clone())
+        .or_insert_with(|| SourceCount { source: name, ..Default::default() })
+}
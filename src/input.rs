@@ -0,0 +1,400 @@
+//! Key handling: which of the app's popups, forms and modes a key press
+//! goes to, and what it does there.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::layout::Rect;
+
+use crate::action;
+use crate::app::{App, InputMode, ViewMode};
+use crate::calendar::ParticipantStatus;
+use crate::components::event_form::FormField;
+use crate::snapshot::SnapshotFormat;
+
+/// Act on a key press. Open popups take keys before the current input mode.
+pub fn handle_key(app: &mut App, key: KeyEvent) {
+    tracing::debug!(
+        code = %logged_code(app.input_mode, key.code),
+        modifiers = ?key.modifiers,
+        mode = ?app.input_mode,
+        view = ?app.view_mode,
+        "key"
+    );
+    // The first key after idling only brings the calendar back
+    if app.on_input() {
+        return;
+    }
+
+    // A start alert covers everything else
+    if !app.alert_queue.is_empty() {
+        match key.code {
+            KeyCode::Enter | KeyCode::Esc => app.dismiss_alert(),
+            KeyCode::Char('s') => app.snooze_alert(),
+            KeyCode::Char('o') => app.join_alert(),
+            _ => {}
+        }
+        return;
+    }
+
+    // The error popup stays until dismissed
+    if let Some(ref mut error) = app.error {
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => app.close_error(),
+            KeyCode::Char('j') | KeyCode::Down => {
+                let (width, height) = crossterm::terminal::size().unwrap_or((100, 30));
+                error.down(Rect::new(0, 0, width, height));
+            }
+            KeyCode::Char('k') | KeyCode::Up => error.up(),
+            KeyCode::Char('y') => app.copy_error(),
+            _ => {}
+        }
+        return;
+    }
+
+    // A running job only listens for Esc
+    if app.job.is_some() {
+        if key.code == KeyCode::Esc {
+            app.cancel_job();
+        }
+        return;
+    }
+
+    // Help overlay takes priority
+    if app.show_help {
+        if key.code == KeyCode::Esc || key.code == KeyCode::Char('?') {
+            app.show_help = false;
+        }
+        return;
+    }
+
+    // Bulk review takes priority
+    if let Some(ref review) = app.event_review {
+        match (review.confirming, key.code) {
+            (true, KeyCode::Char('y')) => app.review_apply(),
+            (true, KeyCode::Char('n')) | (_, KeyCode::Esc) => app.review_cancel(),
+            (false, KeyCode::Down | KeyCode::Char('j')) => app.review_down(),
+            (false, KeyCode::Up | KeyCode::Char('k')) => app.review_up(),
+            (false, KeyCode::Char(' ')) => app.review_toggle(),
+            (false, KeyCode::Char('s')) => app.review_swap(),
+            (false, KeyCode::Enter) => app.review_confirm(),
+            _ => {}
+        }
+        return;
+    }
+
+    // Delete confirmation takes priority
+    if app.delete_prompt.is_some() {
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Enter => app.confirm_delete(),
+            KeyCode::Char('n') | KeyCode::Esc => app.close_delete_prompt(),
+            _ => {}
+        }
+        return;
+    }
+
+    // Invite export offer takes priority
+    if app.invite_prompt.is_some() {
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Enter => app.export_invite(),
+            KeyCode::Char('n') | KeyCode::Esc => app.close_invite_prompt(),
+            _ => {}
+        }
+        return;
+    }
+
+    // Focus day confirmation takes priority
+    if app.focus_prompt.is_some() {
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Enter => app.confirm_focus_day(),
+            KeyCode::Char('n') | KeyCode::Esc => app.close_focus_prompt(),
+            _ => {}
+        }
+        return;
+    }
+
+    // Recurring reminder choice takes priority
+    if app.recurring_prompt.is_some() {
+        match key.code {
+            KeyCode::Char('c') | KeyCode::Enter => app.complete_recurring_occurrence(),
+            KeyCode::Char('D') => app.delete_recurring_series(),
+            KeyCode::Esc => app.close_recurring_prompt(),
+            _ => {}
+        }
+        return;
+    }
+
+    // Status panel takes priority
+    if app.health.is_some() {
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char(' ') => app.close_health(),
+            KeyCode::Char('q') => app.running = false,
+            _ => {}
+        }
+        return;
+    }
+
+    // Detail popup takes priority
+    if app.detail_item.is_some() {
+        match key.code {
+            KeyCode::Esc => app.close_detail(),
+            KeyCode::Char('e') => app.edit_detail_reminder(),
+            KeyCode::Char('a') => app.rsvp(ParticipantStatus::Accepted),
+            KeyCode::Char('d') => app.rsvp(ParticipantStatus::Declined),
+            KeyCode::Char('t') => app.rsvp(ParticipantStatus::Tentative),
+            KeyCode::Char('o') => app.open_video_link(),
+            KeyCode::Char('m') => app.open_location_in_maps(),
+            _ => {}
+        }
+        return;
+    }
+
+    // Reminder checklist takes priority
+    if app.reminder_checklist.is_some() {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => app.close_reminder_checklist(),
+            KeyCode::Down | KeyCode::Char('j') => app.reminder_checklist_down(),
+            KeyCode::Up | KeyCode::Char('k') => app.reminder_checklist_up(),
+            KeyCode::Char(' ') | KeyCode::Enter => app.toggle_checklist_reminder(),
+            _ => {}
+        }
+        return;
+    }
+
+    // Calendar visibility popup takes priority
+    if app.calendar_picker.is_some() {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('c') => app.close_calendar_picker(),
+            KeyCode::Down | KeyCode::Char('j') => app.calendar_picker_down(),
+            KeyCode::Up | KeyCode::Char('k') => app.calendar_picker_up(),
+            KeyCode::Char(' ') | KeyCode::Enter => app.toggle_calendar_visibility(),
+            _ => {}
+        }
+        return;
+    }
+
+    match app.input_mode {
+        InputMode::Form => handle_form_input(app, key.code, key.modifiers),
+        InputMode::ReminderForm => handle_reminder_form_input(app, key.code),
+        InputMode::Command => handle_command_input(app, key.code),
+        InputMode::Normal => handle_normal_input(app, key.code, key.modifiers),
+        _ => {}
+    }
+}
+
+/// `code` as written to the debug log. Characters typed into forms and the
+/// command line are event details and commands, which don't belong in a log
+/// meant for bug reports.
+fn logged_code(mode: InputMode, code: KeyCode) -> String {
+    match code {
+        KeyCode::Char(_) if mode != InputMode::Normal => "Char(redacted)".to_string(),
+        code => format!("{:?}", code),
+    }
+}
+
+fn handle_normal_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
+    if std::mem::take(&mut app.pending_z) {
+        app.clear_count();
+        match code {
+            KeyCode::Char('T') => app.toggle_time_format(),
+            KeyCode::Char('r') => app.toggle_reminders(),
+            KeyCode::Char('s') => app.pending_snapshot = Some(SnapshotFormat::Plain),
+            KeyCode::Char('S') => app.pending_snapshot = Some(SnapshotFormat::Ansi),
+            KeyCode::Char('0') => app.select_filter(None),
+            KeyCode::Char(c @ '1'..='9') => app.select_filter(c.to_digit(10).map(|n| n as usize - 1)),
+            _ => {}
+        }
+        return;
+    }
+
+    if std::mem::take(&mut app.pending_n) {
+        match code {
+            KeyCode::Char('n') | KeyCode::Enter => app.open_event_form(),
+            KeyCode::Char(c) if app.config.event_shortcut(c).is_some() => app.open_event_form_with_shortcut(c),
+            KeyCode::Esc => {}
+            // Anything else cancels and acts as usual
+            _ => handle_normal_input(app, code, modifiers),
+        }
+        return;
+    }
+
+    if let Some(digit) = match code {
+        KeyCode::Char(c) if modifiers.is_empty() => c.to_digit(10),
+        _ => None,
+    } {
+        app.count_digit(digit);
+        return;
+    }
+
+    // Motions repeat by the pending count; other keys discard it
+    let motion = matches!(
+        code,
+        KeyCode::Left
+            | KeyCode::Right
+            | KeyCode::Up
+            | KeyCode::Down
+            | KeyCode::Char('h' | 'j' | 'k' | 'l' | 'H' | 'L' | '[' | ']')
+    );
+    let count = if motion {
+        app.take_count()
+    } else {
+        app.clear_count();
+        1
+    };
+    for _ in 0..count {
+        handle_normal_key(app, code, modifiers);
+    }
+}
+
+fn handle_normal_key(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
+    // A selected week row takes the keys that act on it; motions move it
+    if app.week_row_selected && app.view_mode == ViewMode::Month {
+        match code {
+            KeyCode::Enter => return app.open_selected_week(),
+            KeyCode::Char('x') => return app.export_selected_week(),
+            KeyCode::Esc | KeyCode::Char('V') => return app.toggle_week_row(),
+            _ => {}
+        }
+    }
+    // Days with only reminders get a checklist instead of the day panel's cursor
+    if code == KeyCode::Char(' ') && app.view_mode == ViewMode::Month && app.day_has_only_reminders() {
+        return app.open_reminder_checklist();
+    }
+    if code == KeyCode::Char('n') && !app.config.new_event_shortcuts.is_empty() {
+        app.pending_n = true;
+        return;
+    }
+    if let KeyCode::Char(c) = code {
+        if !modifiers.contains(KeyModifiers::CONTROL) {
+            if let Some(action) = action::by_key(c) {
+                action.run(app);
+                return;
+            }
+        }
+    }
+    match (code, modifiers) {
+        (KeyCode::Char('c'), KeyModifiers::CONTROL) => app.running = false,
+        (KeyCode::Char('w'), _) if app.view_mode == ViewMode::Week => app.cycle_weekend_mode(),
+        (KeyCode::Char('a'), _) if app.view_mode == ViewMode::Week => app.toggle_week_lanes(),
+        (KeyCode::Char('T'), _) if app.view_mode == ViewMode::Day => app.toggle_day_timeline(),
+        (KeyCode::Char('V'), _) if app.view_mode == ViewMode::Month => app.toggle_week_row(),
+        (KeyCode::Char('<'), _) | (KeyCode::Char('>'), _) if app.view_mode == ViewMode::Month => {
+            let (width, _) = crossterm::terminal::size().unwrap_or((100, 0));
+            app.resize_month_pane(if code == KeyCode::Char('<') { -2 } else { 2 }, width);
+        }
+        (KeyCode::Char('z'), _) => app.pending_z = true,
+        (KeyCode::Char('@'), _) => app.open_command_line_with("@"),
+        (KeyCode::Char('p'), _) if app.cut_event.is_some() => app.drop_cut_event(),
+        (KeyCode::Char('p'), _) => app.toggle_pin_selected(),
+        (KeyCode::Esc, _) => app.cancel_cut(),
+        (KeyCode::Enter, _) => app.show_detail(),
+        (KeyCode::Left, _) | (KeyCode::Char('h'), _) => app.prev_day(),
+        (KeyCode::Right, _) | (KeyCode::Char('l'), _) => app.next_day(),
+        (KeyCode::Up, KeyModifiers::CONTROL) if app.shows_hour_grid() => {
+            app.scroll_hours(-1);
+        }
+        (KeyCode::Down, KeyModifiers::CONTROL) if app.shows_hour_grid() => {
+            app.scroll_hours(1);
+        }
+        // Month grid: j/k move a week, arrows still scroll the day panel
+        (KeyCode::Char('k'), _) if app.view_mode == ViewMode::Month => app.prev_week(),
+        (KeyCode::Char('j'), _) if app.view_mode == ViewMode::Month => app.next_week(),
+        (KeyCode::Up, _) | (KeyCode::Char('k'), _) => {
+            if app.view_mode == ViewMode::Day || app.view_mode == ViewMode::Month {
+                app.scroll_day_up();
+            } else {
+                app.week_cursor_up();
+            }
+        }
+        (KeyCode::Down, _) | (KeyCode::Char('j'), _) => {
+            if app.view_mode == ViewMode::Day || app.view_mode == ViewMode::Month {
+                app.scroll_day_down();
+            } else {
+                app.week_cursor_down();
+            }
+        }
+        (KeyCode::Char('H'), _) => app.prev_week(),
+        (KeyCode::Char('L'), _) => app.next_week(),
+        (KeyCode::Char('['), _) => app.prev_month(),
+        (KeyCode::Char(']'), _) => app.next_month(),
+        (KeyCode::Char(c), m) if !m.contains(KeyModifiers::CONTROL) => app.run_custom_key(c),
+        _ => {}
+    }
+}
+
+fn handle_command_input(app: &mut App, code: KeyCode) {
+    match code {
+        KeyCode::Esc => app.close_command_line(),
+        KeyCode::Enter => app.submit_command(),
+        KeyCode::Tab => app.command_complete(),
+        KeyCode::Up => app.command_history_older(),
+        KeyCode::Down => app.command_history_newer(),
+        KeyCode::Backspace => {
+            if let Some(ref mut line) = app.command_line {
+                if line.input.is_empty() {
+                    app.close_command_line();
+                } else {
+                    line.backspace();
+                }
+            }
+        }
+        KeyCode::Char(c) => {
+            if let Some(ref mut line) = app.command_line {
+                line.input_char(c);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn handle_form_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
+    let on_notes = app
+        .form_state
+        .as_ref()
+        .is_some_and(|f| f.active_field == FormField::Notes);
+    match code {
+        KeyCode::Esc => app.close_event_form(),
+        KeyCode::Char('s') if modifiers.contains(KeyModifiers::CONTROL) => app.submit_event_form(),
+        KeyCode::Enter if on_notes => app.form_input_char('\n'),
+        KeyCode::Enter => app.submit_event_form(),
+        KeyCode::Tab => app.form_tab(),
+        KeyCode::BackTab => app.form_backtab(),
+        KeyCode::Backspace => app.form_backspace(),
+        KeyCode::Char(' ') => {
+            // Space toggles all-day or cycles calendar
+            if let Some(ref form) = app.form_state {
+                match form.active_field {
+                    FormField::AllDay => {
+                        if let Some(ref mut f) = app.form_state {
+                            f.toggle_all_day();
+                        }
+                    }
+                    FormField::Calendar => {
+                        let total = app.calendars.len();
+                        if let Some(ref mut f) = app.form_state {
+                            f.next_calendar(total);
+                        }
+                    }
+                    FormField::Alarm => {
+                        if let Some(ref mut f) = app.form_state {
+                            f.next_alarm();
+                        }
+                    }
+                    _ => app.form_input_char(' '),
+                }
+            }
+        }
+        KeyCode::Char(c) => app.form_input_char(c),
+        _ => {}
+    }
+}
+
+fn handle_reminder_form_input(app: &mut App, code: KeyCode) {
+    match code {
+        KeyCode::Esc => app.close_reminder_form(),
+        KeyCode::Enter => app.submit_reminder_form(),
+        KeyCode::Tab => app.reminder_form_tab(),
+        KeyCode::BackTab => app.reminder_form_backtab(),
+        KeyCode::Backspace => app.reminder_form_backspace(),
+        KeyCode::Char(c) => app.reminder_form_input_char(c),
+        _ => {}
+    }
+}
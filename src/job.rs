@@ -0,0 +1,149 @@
+//! Bulk operations split into small steps. EventKit objects stay on the UI
+//! thread, so instead of a worker the main loop runs a few steps between
+//! frames; the status bar shows how far along the job is and Esc stops it.
+
+use std::collections::{HashSet, VecDeque};
+
+use chrono::{DateTime, Duration, Local};
+
+use crate::calendar::{CalendarEvent, NewEvent};
+
+/// Events deleted per step, each step committed on its own.
+pub const DELETE_BATCH: usize = 20;
+
+/// Events created per step.
+pub const CREATE_BATCH: usize = 10;
+
+/// Days of calendar queried per step of a scan.
+pub const SCAN_DAYS: i64 = 30;
+
+/// One unit of work.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Step {
+    Delete(Vec<String>),
+    Create(Vec<NewEvent>),
+    Query(DateTime<Local>, DateTime<Local>),
+}
+
+/// What to do with the result once every step ran.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Finish {
+    /// Report how many events were deleted.
+    Deleted,
+    /// Report how many events were created.
+    Created,
+    /// Review stale events older than N days among the scanned ones.
+    Cleanup(i64),
+    /// Review duplicates among the scanned events.
+    Duplicates,
+}
+
+#[derive(Debug, Clone)]
+pub struct Job {
+    /// "Deleting events", shown next to the progress.
+    pub label: String,
+    steps: VecDeque<Step>,
+    /// Items processed so far: events for deletions, days for scans.
+    pub done: usize,
+    pub total: usize,
+    /// Events gathered by query steps.
+    pub events: Vec<CalendarEvent>,
+    /// Identifier and start of each gathered event.
+    seen: HashSet<(String, i64)>,
+    pub finish: Finish,
+}
+
+impl Job {
+    /// Delete `ids` in batches of [`DELETE_BATCH`].
+    pub fn delete(ids: Vec<String>) -> Self {
+        let total = ids.len();
+        let steps = ids.chunks(DELETE_BATCH).map(|chunk| Step::Delete(chunk.to_vec())).collect();
+        Self {
+            label: "Deleting events".to_string(),
+            steps,
+            done: 0,
+            total,
+            events: Vec::new(),
+            seen: HashSet::new(),
+            finish: Finish::Deleted,
+        }
+    }
+
+    /// Create `events` in batches of [`CREATE_BATCH`].
+    pub fn create(events: Vec<NewEvent>) -> Self {
+        let total = events.len();
+        let steps = events.chunks(CREATE_BATCH).map(|chunk| Step::Create(chunk.to_vec())).collect();
+        Self {
+            label: "Creating events".to_string(),
+            steps,
+            done: 0,
+            total,
+            events: Vec::new(),
+            seen: HashSet::new(),
+            finish: Finish::Created,
+        }
+    }
+
+    /// Query `[start, end)` [`SCAN_DAYS`] at a time.
+    pub fn scan(label: &str, start: DateTime<Local>, end: DateTime<Local>, finish: Finish) -> Self {
+        let mut steps = VecDeque::new();
+        let mut from = start;
+        while from < end {
+            let to = (from + Duration::days(SCAN_DAYS)).min(end);
+            steps.push_back(Step::Query(from, to));
+            from = to;
+        }
+        let total = (end - start).num_days().max(0) as usize;
+        Self {
+            label: label.to_string(),
+            steps,
+            done: 0,
+            total,
+            events: Vec::new(),
+            seen: HashSet::new(),
+            finish,
+        }
+    }
+
+    pub fn next_step(&mut self) -> Option<Step> {
+        self.steps.pop_front()
+    }
+
+    /// Record a finished step.
+    pub fn advance(&mut self, step: &Step) {
+        let n = match step {
+            Step::Delete(ids) => ids.len(),
+            Step::Create(events) => events.len(),
+            Step::Query(start, end) => (*end - *start).num_days().max(0) as usize,
+        };
+        self.done = (self.done + n).min(self.total);
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Add events from a query step. An event crossing a chunk boundary is
+    /// returned by both queries and kept once.
+    pub fn collect(&mut self, events: Vec<CalendarEvent>) {
+        for ev in events {
+            if self.seen.insert((ev.id.clone(), ev.start.timestamp())) {
+                self.events.push(ev);
+            }
+        }
+    }
+
+    /// "Deleting events 20/45".
+    pub fn progress_label(&self) -> String {
+        format!("{} {}/{}", self.label, self.done, self.total)
+    }
+
+    /// Completed share of the job, 0.0 to 1.0.
+    pub fn ratio(&self) -> f64 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.done as f64 / self.total as f64
+        }
+    }
+}
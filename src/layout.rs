@@ -0,0 +1,468 @@
+//! Pure layout computations shared by the app state and views.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Datelike, Local, NaiveDate, Timelike};
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::calendar::{CalendarEvent, Reminder};
+
+/// Identifies what kind of item is at a given scroll position in the day view.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DayAction {
+    None,
+    Event(usize),
+    Reminder(usize),
+}
+
+/// Sections of the day list, in the order set by the `day_sections`
+/// setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DaySection {
+    Pinned,
+    AllDay,
+    Reminders,
+    /// Timed events, with the now marker and breaks.
+    Timed,
+}
+
+impl DaySection {
+    /// The order used when the setting is absent.
+    pub const DEFAULT_ORDER: [DaySection; 4] =
+        [DaySection::Pinned, DaySection::AllDay, DaySection::Reminders, DaySection::Timed];
+
+    /// Name of the section's heading.
+    pub fn title(self) -> &'static str {
+        match self {
+            DaySection::Pinned => "Pinned",
+            DaySection::AllDay => "All Day",
+            DaySection::Reminders => "Reminders",
+            DaySection::Timed => "Schedule",
+        }
+    }
+
+    fn provider(self) -> &'static dyn SectionProvider {
+        match self {
+            DaySection::Pinned => &PinnedSection,
+            DaySection::AllDay => &AllDaySection,
+            DaySection::Reminders => &ReminderSection,
+            DaySection::Timed => &TimedSection,
+        }
+    }
+}
+
+/// One visual row of the day list.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DayRow {
+    Header(DaySection),
+    Spacer,
+    Event(usize),
+    Reminder(usize),
+    /// Current time marker among the timed events.
+    Now,
+    /// Free minutes between two timed events.
+    Break(u32),
+}
+
+/// Everything the day list is built from.
+#[derive(Debug, Clone, Copy)]
+pub struct DayItems<'a> {
+    pub events: &'a [CalendarEvent],
+    pub reminders: &'a [Reminder],
+    pub pinned: &'a HashSet<String>,
+    /// Set when the day is today, for the now marker.
+    pub now: Option<DateTime<Local>>,
+    /// Shortest gap between timed events shown as a break.
+    pub min_break: Option<u32>,
+    /// Sections to show, in order.
+    pub sections: &'a [DaySection],
+}
+
+impl DayItems<'_> {
+    fn is_pinned(&self, ev: &CalendarEvent) -> bool {
+        self.pinned.contains(&ev.id)
+    }
+
+    fn event_rows(&self, keep: impl Fn(&CalendarEvent) -> bool) -> Vec<DayRow> {
+        self.events
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| keep(e))
+            .map(|(i, _)| DayRow::Event(i))
+            .collect()
+    }
+}
+
+/// A section of the day list: the rows it shows and what the cursor does
+/// on each of them.
+pub trait SectionProvider {
+    /// Heading shown above the section, if it has one.
+    fn header(&self) -> Option<DaySection>;
+
+    /// The section's rows; an empty section is left out entirely.
+    fn rows(&self, items: &DayItems) -> Vec<DayRow>;
+
+    /// What the cursor acts on at one of the section's rows. The cursor
+    /// skips rows with [`DayAction::None`]. By default these are the
+    /// events and reminders the rows point at.
+    fn action(&self, row: DayRow) -> DayAction {
+        match row {
+            DayRow::Event(i) => DayAction::Event(i),
+            DayRow::Reminder(i) => DayAction::Reminder(i),
+            _ => DayAction::None,
+        }
+    }
+}
+
+struct PinnedSection;
+
+impl SectionProvider for PinnedSection {
+    fn header(&self) -> Option<DaySection> {
+        Some(DaySection::Pinned)
+    }
+
+    fn rows(&self, items: &DayItems) -> Vec<DayRow> {
+        items.event_rows(|e| items.is_pinned(e))
+    }
+}
+
+struct AllDaySection;
+
+impl SectionProvider for AllDaySection {
+    fn header(&self) -> Option<DaySection> {
+        Some(DaySection::AllDay)
+    }
+
+    fn rows(&self, items: &DayItems) -> Vec<DayRow> {
+        items.event_rows(|e| e.is_all_day && !items.is_pinned(e))
+    }
+}
+
+struct ReminderSection;
+
+impl SectionProvider for ReminderSection {
+    fn header(&self) -> Option<DaySection> {
+        Some(DaySection::Reminders)
+    }
+
+    fn rows(&self, items: &DayItems) -> Vec<DayRow> {
+        (0..items.reminders.len()).map(DayRow::Reminder).collect()
+    }
+}
+
+/// With `now` set, a [`DayRow::Now`] marker goes before the first timed
+/// event that has not started yet. With `min_break` set, gaps of at least
+/// that many minutes between timed events get a [`DayRow::Break`].
+struct TimedSection;
+
+impl SectionProvider for TimedSection {
+    fn header(&self) -> Option<DaySection> {
+        None
+    }
+
+    fn rows(&self, items: &DayItems) -> Vec<DayRow> {
+        let mut timed = items.event_rows(|e| !e.is_all_day && !items.is_pinned(e));
+        if let Some(min_break) = items.min_break {
+            timed = with_breaks(items.events, timed, min_break);
+        }
+        if let (Some(now), false) = (items.now, timed.is_empty()) {
+            let pos = timed
+                .iter()
+                .position(|row| matches!(row, DayRow::Event(i) if items.events[*i].start > now))
+                .unwrap_or(timed.len());
+            timed.insert(pos, DayRow::Now);
+        }
+        timed
+    }
+}
+
+/// Rows of the day list: each of `items.sections` in order, with a spacer
+/// between non-empty sections. Pinned events stay in their own section
+/// only while it is shown.
+pub fn day_rows(items: &DayItems) -> Vec<DayRow> {
+    sectioned_rows(items).into_iter().map(|(_, row)| row).collect()
+}
+
+/// [`day_rows`] with the section each row belongs to; spacers and headers
+/// count with the section below them.
+fn sectioned_rows(items: &DayItems) -> Vec<(DaySection, DayRow)> {
+    let unpinned = HashSet::new();
+    let mut items = *items;
+    if !items.sections.contains(&DaySection::Pinned) {
+        items.pinned = &unpinned;
+    }
+
+    let mut rows = Vec::new();
+    let mut seen = Vec::new();
+    for &section in items.sections {
+        if seen.contains(&section) {
+            continue;
+        }
+        seen.push(section);
+        let provider = section.provider();
+        let own = provider.rows(&items);
+        if own.is_empty() {
+            continue;
+        }
+        if !rows.is_empty() {
+            rows.push((section, DayRow::Spacer));
+        }
+        if let Some(header) = provider.header() {
+            rows.push((section, DayRow::Header(header)));
+        }
+        rows.extend(own.into_iter().map(|row| (section, row)));
+    }
+    rows
+}
+
+/// Insert a break row before each timed event that starts at least
+/// `min_break` minutes after everything before it has ended.
+fn with_breaks(events: &[CalendarEvent], timed: Vec<DayRow>, min_break: u32) -> Vec<DayRow> {
+    let mut rows = Vec::with_capacity(timed.len());
+    let mut busy_until: Option<DateTime<Local>> = None;
+    for row in timed {
+        if let DayRow::Event(i) = row {
+            let ev = &events[i];
+            if let Some(until) = busy_until {
+                let gap = (ev.start - until).num_minutes();
+                if gap > 0 && gap >= min_break as i64 {
+                    rows.push(DayRow::Break(gap as u32));
+                }
+            }
+            busy_until = Some(busy_until.map_or(ev.end, |until| until.max(ev.end)));
+        }
+        rows.push(row);
+    }
+    rows
+}
+
+/// Total number of visual items in the day list (headers + items + spacers).
+pub fn day_list_len(items: &DayItems) -> usize {
+    day_rows(items).len()
+}
+
+/// Determine what kind of item is at the given position of the day list.
+pub fn day_action_at(items: &DayItems, scroll: usize) -> DayAction {
+    match sectioned_rows(items).get(scroll) {
+        Some(&(section, row)) => section.provider().action(row),
+        None => DayAction::None,
+    }
+}
+
+/// Number of events on each day of the given month; multi-day events count
+/// on every day they span. Days without events are absent.
+pub fn month_event_counts(events: &[CalendarEvent], year: i32, month: u32) -> HashMap<u32, usize> {
+    let mut days = HashMap::new();
+    for ev in events {
+        let mut d = ev.start.date_naive();
+        let last = ev.last_day();
+        while d <= last {
+            if d.year() == year && d.month() == month {
+                *days.entry(d.day()).or_insert(0) += 1;
+            }
+            match d.succ_opt() {
+                Some(next) => d = next,
+                None => break,
+            }
+        }
+    }
+    days
+}
+
+/// Reminders of one list among a set of reminders.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReminderListCount {
+    pub name: String,
+    pub color: Color,
+    pub count: usize,
+}
+
+/// Reminders grouped by list, largest first; ties keep the order in which
+/// the lists first appear.
+pub fn reminder_lists<'a>(reminders: impl IntoIterator<Item = &'a Reminder>) -> Vec<ReminderListCount> {
+    let mut lists: Vec<(&str, ReminderListCount)> = Vec::new();
+    for rem in reminders {
+        match lists.iter_mut().find(|(id, _)| *id == rem.calendar_id) {
+            Some((_, list)) => list.count += 1,
+            None => lists.push((
+                &rem.calendar_id,
+                ReminderListCount { name: rem.calendar_name.clone(), color: rem.calendar_color, count: 1 },
+            )),
+        }
+    }
+    let mut lists: Vec<ReminderListCount> = lists.into_iter().map(|(_, list)| list).collect();
+    lists.sort_by_key(|list| std::cmp::Reverse(list.count));
+    lists
+}
+
+/// Color of the list holding most of each day's reminders in the given
+/// month. Days without due reminders are absent.
+pub fn month_reminder_colors(reminders: &[Reminder], year: i32, month: u32) -> HashMap<u32, Color> {
+    let mut by_day: HashMap<u32, Vec<&Reminder>> = HashMap::new();
+    for rem in reminders {
+        if let Some(due) = rem.due_date.map(|d| d.date_naive()) {
+            if due.year() == year && due.month() == month {
+                by_day.entry(due.day()).or_default().push(rem);
+            }
+        }
+    }
+    by_day
+        .into_iter()
+        .filter_map(|(day, rems)| reminder_lists(rems).first().map(|list| (day, list.color)))
+        .collect()
+}
+
+/// Position of a timed event in the day timeline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimelineBlock {
+    /// Index into the events slice.
+    pub index: usize,
+    /// Minutes since midnight, clipped to the day.
+    pub start_min: u32,
+    pub end_min: u32,
+    /// Side-by-side column within its group of overlapping events.
+    pub column: usize,
+    pub columns: usize,
+}
+
+/// Timed events on `date` as minute ranges, with overlapping events spread
+/// over columns. Every event in a group of mutually overlapping events gets
+/// the same column count.
+pub fn timeline_blocks(events: &[CalendarEvent], date: NaiveDate) -> Vec<TimelineBlock> {
+    let minute_of = |t: DateTime<Local>| t.hour() * 60 + t.minute();
+    let mut blocks: Vec<TimelineBlock> = events
+        .iter()
+        .enumerate()
+        .filter(|(_, ev)| !ev.is_all_day && ev.occurs_on(date))
+        .map(|(index, ev)| {
+            let start_min = if ev.start.date_naive() < date { 0 } else { minute_of(ev.start) };
+            let end_min = if ev.end.date_naive() > date { 24 * 60 } else { minute_of(ev.end) };
+            TimelineBlock {
+                index,
+                start_min,
+                // Zero-length events still get a sliver
+                end_min: end_min.max(start_min + 1),
+                column: 0,
+                columns: 1,
+            }
+        })
+        .collect();
+    blocks.sort_by_key(|b| (b.start_min, std::cmp::Reverse(b.end_min)));
+
+    let mut group_start = 0;
+    let mut group_end = 0;
+    let mut column_ends: Vec<u32> = Vec::new();
+    for i in 0..blocks.len() {
+        if blocks[i].start_min >= group_end && i > group_start {
+            for b in &mut blocks[group_start..i] {
+                b.columns = column_ends.len();
+            }
+            group_start = i;
+            column_ends.clear();
+        }
+        let b = &mut blocks[i];
+        b.column = match column_ends.iter().position(|&end| end <= b.start_min) {
+            Some(col) => {
+                column_ends[col] = b.end_min;
+                col
+            }
+            None => {
+                column_ends.push(b.end_min);
+                column_ends.len() - 1
+            }
+        };
+        group_end = group_end.max(b.end_min);
+    }
+    let columns = column_ends.len();
+    for b in &mut blocks[group_start..] {
+        b.columns = columns;
+    }
+    blocks
+}
+
+/// Minutes of `date` covered by timed events you have not declined, with
+/// overlaps counted once.
+pub fn busy_minutes(events: &[CalendarEvent], date: NaiveDate) -> u32 {
+    let mut ranges: Vec<(u32, u32)> = timeline_blocks(events, date)
+        .into_iter()
+        .filter(|b| {
+            let ev = &events[b.index];
+            !ev.declined && ev.end > ev.start
+        })
+        .map(|b| (b.start_min, b.end_min))
+        .collect();
+    ranges.sort_unstable();
+
+    let mut total = 0;
+    let mut covered_to = 0;
+    for (start, end) in ranges {
+        let start = start.max(covered_to);
+        if end > start {
+            total += end - start;
+            covered_to = end;
+        }
+    }
+    total
+}
+
+/// "6h", "6h 30m" or "45m".
+pub fn busy_label(minutes: u32) -> String {
+    match (minutes / 60, minutes % 60) {
+        (0, m) => format!("{}m", m),
+        (h, 0) => format!("{}h", h),
+        (h, m) => format!("{}h {}m", h, m),
+    }
+}
+
+/// The last timed event with a place before `ev` on the same day, with the
+/// minutes between the two, for judging whether there is time to get there.
+/// `None` when that event is at the same place. Online locations (links)
+/// are not places to travel from.
+pub fn coming_from<'a>(events: &'a [CalendarEvent], ev: &CalendarEvent) -> Option<(&'a CalendarEvent, i64)> {
+    let place = |e: &CalendarEvent| {
+        e.location
+            .as_deref()
+            .map(str::trim)
+            .filter(|loc| !loc.is_empty() && !loc.contains("://"))
+            .map(str::to_lowercase)
+    };
+    let here = place(ev).filter(|_| !ev.is_all_day)?;
+    let (prev, there) = events
+        .iter()
+        .filter(|other| {
+            !other.is_all_day
+                && !other.declined
+                && other.end <= ev.start
+                && other.end.date_naive() == ev.start.date_naive()
+        })
+        .filter_map(|other| Some((other, place(other)?)))
+        .max_by_key(|(other, _)| other.end)?;
+    (there != here).then(|| (prev, (ev.start - prev.end).num_minutes()))
+}
+
+/// Whole minutes left in `ev` at `now`, rounded up so the last minute
+/// still counts, or `None` unless it is a timed event in progress.
+pub fn minutes_left(ev: &CalendarEvent, now: DateTime<Local>) -> Option<u32> {
+    (!ev.is_all_day && ev.start <= now && now < ev.end).then(|| ((ev.end - now).num_seconds() as u32).div_ceil(60))
+}
+
+/// The event in progress at `now` that ends first, with its minutes left.
+/// Declined events are not counted.
+pub fn ongoing(events: &[CalendarEvent], now: DateTime<Local>) -> Option<(&CalendarEvent, u32)> {
+    events
+        .iter()
+        .filter(|ev| !ev.declined)
+        .filter_map(|ev| minutes_left(ev, now).map(|left| (ev, left)))
+        .min_by_key(|(ev, _)| ev.end)
+}
+
+/// "12 min left" or "1h 30m left".
+pub fn time_left_label(minutes: u32) -> String {
+    if minutes < 60 {
+        format!("{} min left", minutes)
+    } else {
+        format!("{} left", busy_label(minutes))
+    }
+}
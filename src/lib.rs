@@ -0,0 +1,37 @@
+//! Terminal calendar for Apple Calendar and Reminders.
+//!
+//! The library holds the EventKit wrapper ([`calendar::Store`]) behind the
+//! [`calendar::Backend`] trait, the data models, application state
+//! ([`app::App`]), key handling ([`input`]) and rendering, down to single
+//! widgets like [`components::MonthView`] for embedding in other TUIs. The
+//! `calendar-tui` binary only parses arguments and drives the terminal
+//! event loop.
+
+pub mod action;
+pub mod agenda;
+pub mod alerts;
+pub mod app;
+pub mod availability;
+pub mod cache;
+pub mod calendar;
+pub mod clipboard;
+pub mod command;
+pub mod components;
+pub mod config;
+pub mod event;
+pub mod health;
+pub mod input;
+pub mod job;
+pub mod layout;
+pub mod log;
+pub mod maintenance;
+pub mod natural;
+pub mod opener;
+pub mod shift;
+pub mod snapshot;
+pub mod state;
+pub mod text;
+pub mod theme;
+pub mod toast;
+pub mod tui;
+pub mod ui;
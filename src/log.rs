@@ -0,0 +1,68 @@
+//! Debug logging for `--debug`, written to `~/.config/calendar-tui/logs`
+//! so it can be attached to bug reports.
+
+use std::fs::{self, OpenOptions};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use color_eyre::eyre::{eyre, Result};
+use tracing::Level;
+
+use crate::config;
+
+/// Size past which the log is rotated at the next launch.
+pub const MAX_LOG_BYTES: u64 = 1024 * 1024;
+
+/// Older logs kept beside the current one, as `debug.log.1` and so on.
+pub const KEPT_LOGS: usize = 3;
+
+/// Directory logs are written to (`~/.config/calendar-tui/logs`).
+pub fn logs_dir() -> Option<PathBuf> {
+    config::config_dir().map(|d| d.join("logs"))
+}
+
+/// Move `path` to `path.1`, shifting older copies up and dropping the one
+/// past `kept`, once it has grown past `max_bytes`.
+pub fn rotate(path: &Path, max_bytes: u64, kept: usize) -> io::Result<()> {
+    let len = match fs::metadata(path) {
+        Ok(meta) => meta.len(),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e),
+    };
+    if len <= max_bytes {
+        return Ok(());
+    }
+    let numbered = |n: usize| {
+        let mut name = path.as_os_str().to_owned();
+        name.push(format!(".{}", n));
+        PathBuf::from(name)
+    };
+    if kept == 0 {
+        return fs::remove_file(path);
+    }
+    for n in (1..kept).rev() {
+        let from = numbered(n);
+        if from.exists() {
+            fs::rename(from, numbered(n + 1))?;
+        }
+    }
+    fs::rename(path, numbered(1))
+}
+
+/// Start writing debug events to `debug.log`; returns its path.
+pub fn init() -> Result<PathBuf> {
+    let dir = logs_dir().ok_or_else(|| eyre!("No home directory"))?;
+    fs::create_dir_all(&dir)?;
+    let path = dir.join("debug.log");
+    rotate(&path, MAX_LOG_BYTES, KEPT_LOGS)?;
+    let file = OpenOptions::new().create(true).append(true).open(&path)?;
+    tracing_subscriber::fmt()
+        .with_writer(Mutex::new(file))
+        .with_ansi(false)
+        .with_max_level(Level::DEBUG)
+        .try_init()
+        .map_err(|e| eyre!("Starting the debug log: {}", e))?;
+    tracing::info!(version = env!("CARGO_PKG_VERSION"), "debug log started");
+    Ok(path)
+}
@@ -1,390 +1,330 @@
-mod app;
-mod calendar;
-mod components;
-mod event;
-mod theme;
-mod tui;
-
-use std::time::Duration;
-
-use app::{App, InputMode, ViewMode};
+use std::time::{Duration, Instant};
+
+use calendar_tui::app::{App, ViewMode};
+use calendar_tui::calendar::{NewEvent, TimeFormat};
+use calendar_tui::command::History;
+use calendar_tui::config::Config;
+use calendar_tui::event::Input;
+use calendar_tui::state::ViewState;
+use calendar_tui::theme::{self, Background, Theme};
+use calendar_tui::{agenda, calendar, command, event, input, tui, ui};
+use chrono::{Local, NaiveDate, NaiveTime, Timelike};
+use clap::Parser;
 use color_eyre::Result;
-use crossterm::event::{KeyCode, KeyModifiers};
-use ratatui::layout::{Constraint, Layout, Rect};
 
-fn main() -> Result<()> {
-    color_eyre::install()?;
+/// Time between frames while a spinner or progress bar moves.
+const ANIMATION_FRAME: Duration = Duration::from_millis(100);
 
-    eprintln!("Connecting to Apple Calendar...");
-    let mut app = App::new()?;
-    eprintln!("Calendar ready. Launching TUI...");
+/// Longest wait for input otherwise; store changes, alerts and toasts are
+/// checked this often.
+const IDLE_POLL: Duration = Duration::from_secs(1);
 
-    let original_hook = std::panic::take_hook();
-    std::panic::set_hook(Box::new(move |panic_info| {
-        let _ = tui::restore();
-        original_hook(panic_info);
-    }));
+/// A terminal calendar for macOS Calendar and Reminders.
+#[derive(Debug, Parser)]
+#[command(version)]
+struct Cli {
+    /// View to open in, instead of the last one used
+    #[arg(long, value_enum)]
+    view: Option<ViewMode>,
 
-    let mut terminal = tui::init()?;
-    let result = run(&mut terminal, &mut app);
-    tui::restore()?;
-    result
-}
+    /// Date to open on, e.g. 2025-01-03, tomorrow or fri
+    #[arg(long, value_parser = parse_date)]
+    date: Option<NaiveDate>,
 
-fn run(terminal: &mut tui::Tui, app: &mut App) -> Result<()> {
-    while app.running {
-        terminal.draw(|frame| {
-            let area = frame.area();
-            let w = area.width;
-
-            if !app.access_granted {
-                let msg = ratatui::widgets::Paragraph::new(
-                    "Calendar access denied.\n\n\
-                     Please grant access in:\n\
-                     System Settings > Privacy & Security > Calendars\n\n\
-                     Press 'q' to quit.",
-                )
-                .style(theme::current().header);
-                frame.render_widget(msg, area);
-                return;
-            }
+    /// Open on today instead of the last date viewed
+    #[arg(long, conflicts_with = "date")]
+    today: bool,
 
-            // Main layout: content + status bar
-            let layout = Layout::vertical([
-                Constraint::Min(1),
-                Constraint::Length(1),
-            ])
-            .split(area);
-
-            let content_area = layout[0];
-
-            // Render main view
-            match app.view_mode {
-                ViewMode::Month => render_month_layout(frame, content_area, app, w),
-                ViewMode::Week => {
-                    components::WeekView::render(
-                        frame,
-                        content_area,
-                        app.selected_date,
-                        app.today,
-                        app.week_start(),
-                        &app.week_events,
-                    );
-                }
-                ViewMode::Day => {
-                    let progress = if app.day_total_reminders > 0 {
-                        Some((app.day_completed_count, app.day_total_reminders))
-                    } else {
-                        None
-                    };
-                    components::DayView::render(
-                        frame,
-                        content_area,
-                        app.selected_date,
-                        &app.day_events,
-                        &app.day_reminders,
-                        app.day_scroll,
-                        progress,
-                    );
-                }
-            }
+    /// Built-in theme to use instead of theme.toml
+    #[arg(long, value_parser = theme::PRESETS.to_vec())]
+    theme: Option<String>,
 
-            // Render event form overlay
-            if let Some(ref form) = app.form_state {
-                components::EventForm::render(frame, area, form, &app.calendars);
-            }
+    /// Show only this calendar; repeat for more
+    #[arg(long = "calendar", value_name = "NAME")]
+    calendars: Vec<String>,
 
-            // Render detail popup overlay
-            if let Some(ref detail) = app.detail_item {
-                components::day_view::render_detail_popup(
-                    frame, area, detail, &app.day_events, &app.day_reminders,
-                );
-            }
+    /// Use sample data instead of the real calendars
+    #[arg(long, global = true)]
+    demo: bool,
 
-            // Render help overlay
-            if app.show_help {
-                render_help(frame, area);
-            }
+    /// Log store calls and key presses to ~/.config/calendar-tui/logs/debug.log
+    #[arg(long, global = true)]
+    debug: bool,
 
-            // Status bar
-            render_status_bar(frame, layout[1], app, w);
-        })?;
+    #[command(subcommand)]
+    command: Option<Subcommand>,
+}
 
-        if let Some(key) = event::next_key_event(Duration::from_millis(100))? {
-            // Clear status message on any key
-            app.status_message = None;
+#[derive(Debug, clap::Subcommand)]
+enum Subcommand {
+    /// Print the coming days' events and reminders, then exit
+    Agenda {
+        /// Number of days to show, starting today or at --date
+        #[arg(long, default_value_t = 7, value_parser = clap::value_parser!(u32).range(1..=agenda::MAX_DAYS as i64))]
+        days: u32,
+
+        /// One line per item, for scripts and status lines
+        #[arg(long, conflicts_with = "json")]
+        plain: bool,
+
+        /// A JSON array of items
+        #[arg(long)]
+        json: bool,
+
+        /// Show only this calendar; repeat for more
+        #[arg(long = "calendar", value_name = "NAME")]
+        calendars: Vec<String>,
+    },
+    /// Create an event, then exit
+    Add {
+        title: String,
+
+        /// Day of the event, e.g. 2025-02-10 or fri; defaults to today
+        #[arg(long, value_parser = parse_date)]
+        date: Option<NaiveDate>,
+
+        /// Start time, e.g. 14:00 or 2pm; without it the event is all-day
+        #[arg(long, value_parser = parse_time)]
+        from: Option<NaiveTime>,
+
+        /// End time; defaults to an hour after the start
+        #[arg(long, value_parser = parse_time, requires = "from")]
+        to: Option<NaiveTime>,
+
+        /// Calendar to add it to, instead of the default one
+        #[arg(long, value_name = "NAME")]
+        calendar: Option<String>,
+    },
+    /// List, add or complete reminders, then exit
+    Reminders {
+        #[command(subcommand)]
+        command: RemindersCommand,
+    },
+}
 
-            // Help overlay takes priority
-            if app.show_help {
-                if key.code == KeyCode::Esc || key.code == KeyCode::Char('?') {
-                    app.show_help = false;
-                }
-                continue;
-            }
+#[derive(Debug, clap::Subcommand)]
+enum RemindersCommand {
+    /// Print incomplete reminders as tab-separated id, due date, title and list
+    List,
+    /// Add a reminder and print its id
+    Add {
+        title: String,
+
+        /// Due date, e.g. 2025-02-10 or fri
+        #[arg(long, value_parser = parse_date)]
+        due: Option<NaiveDate>,
+
+        /// Reminder list to add it to, instead of the default one
+        #[arg(long, value_name = "NAME")]
+        list: Option<String>,
+    },
+    /// Mark a reminder complete
+    Done { id: String },
+}
 
-            // Detail popup takes priority
-            if app.detail_item.is_some() {
-                if key.code == KeyCode::Esc {
-                    app.close_detail();
-                }
-                continue;
-            }
+fn parse_date(s: &str) -> std::result::Result<NaiveDate, String> {
+    command::parse_date(s, Local::now().date_naive()).ok_or_else(|| format!("unrecognized date '{}'", s))
+}
 
-            match app.input_mode {
-                InputMode::Form => handle_form_input(app, key.code, key.modifiers),
-                InputMode::Normal => handle_normal_input(app, key.code, key.modifiers),
-                _ => {}
-            }
-        }
+fn parse_time(s: &str) -> std::result::Result<NaiveTime, String> {
+    TimeFormat::parse(s).ok_or_else(|| format!("unrecognized time '{}'", s))
+}
+
+fn main() -> Result<()> {
+    color_eyre::install()?;
+    let cli = Cli::parse();
+    if cli.debug {
+        calendar_tui::log::init()?;
     }
 
-    Ok(())
-}
+    if let Some(name) = &cli.theme {
+        theme::set(Theme::preset(name));
+    }
 
-fn handle_normal_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
-    match (code, modifiers) {
-        (KeyCode::Char('q'), _) | (KeyCode::Char('c'), KeyModifiers::CONTROL) => {
-            app.running = false;
-        }
-        (KeyCode::Char('1'), _) => app.view_mode = ViewMode::Month,
-        (KeyCode::Char('2'), _) => app.view_mode = ViewMode::Week,
-        (KeyCode::Char('3'), _) => app.view_mode = ViewMode::Day,
-        (KeyCode::Char('t'), _) => app.go_to_today(),
-        (KeyCode::Char('r'), _) => {
-            // Refresh reminders
-            app.refresh_reminders();
-            app.status_message = Some("Reminders refreshed".to_string());
-        }
-        (KeyCode::Char('n'), _) => app.open_event_form(),
-        (KeyCode::Char('d'), _) => app.delete_selected_event(),
-        (KeyCode::Char(' '), _) => app.toggle_day_reminder(),
-        (KeyCode::Enter, _) => app.show_detail(),
-        (KeyCode::Left, _) | (KeyCode::Char('h'), _) => app.prev_day(),
-        (KeyCode::Right, _) | (KeyCode::Char('l'), _) => app.next_day(),
-        (KeyCode::Up, _) | (KeyCode::Char('k'), _) => {
-            if app.view_mode == ViewMode::Day || app.view_mode == ViewMode::Month {
-                app.scroll_day_up();
-            } else {
-                app.prev_week();
+    match &cli.command {
+        Some(Subcommand::Agenda { days, plain, json, calendars }) => {
+            let mut app = open(cli.demo, &mut |_| {})?;
+            if !calendars.is_empty() {
+                app.show_only_calendars(calendars.clone());
             }
-        }
-        (KeyCode::Down, _) | (KeyCode::Char('j'), _) => {
-            if app.view_mode == ViewMode::Day || app.view_mode == ViewMode::Month {
-                app.scroll_day_down();
+            let agenda = app.agenda(cli.date.unwrap_or_else(|| Local::now().date_naive()), *days);
+            let out = if *json {
+                agenda.to_json()?
+            } else if *plain {
+                agenda.to_plain()
             } else {
-                app.next_week();
-            }
+                agenda.to_text()
+            };
+            print!("{}", out);
+            return Ok(());
+        }
+        Some(Subcommand::Add { title, date, from, to, calendar }) => {
+            let event = new_event(title, date.unwrap_or_else(|| Local::now().date_naive()), *from, *to);
+            open(cli.demo, &mut |_| {})?.add_event(event, calendar.as_deref())?;
+            println!("Created: {}", title);
+            return Ok(());
         }
-        (KeyCode::Char('['), _) => app.prev_month(),
-        (KeyCode::Char(']'), _) => app.next_month(),
-        (KeyCode::Char('?'), _) => app.show_help = true,
-        _ => {}
+        Some(Subcommand::Reminders { command }) => {
+            let app = open(cli.demo, &mut |_| {})?;
+            return reminders(&app, command);
+        }
+        None => {}
     }
-}
 
-fn handle_form_input(app: &mut App, code: KeyCode, _modifiers: KeyModifiers) {
-    match code {
-        KeyCode::Esc => app.close_event_form(),
-        KeyCode::Enter => app.submit_event_form(),
-        KeyCode::Tab => app.form_tab(),
-        KeyCode::BackTab => app.form_backtab(),
-        KeyCode::Backspace => app.form_backspace(),
-        KeyCode::Char(' ') => {
-            // Space toggles all-day or cycles calendar
-            if let Some(ref form) = app.form_state {
-                match form.active_field {
-                    crate::components::event_form::FormField::AllDay => {
-                        if let Some(ref mut f) = app.form_state {
-                            f.toggle_all_day();
-                        }
-                    }
-                    crate::components::event_form::FormField::Calendar => {
-                        let total = app.calendars.len();
-                        if let Some(ref mut f) = app.form_state {
-                            f.next_calendar(total);
-                        }
-                    }
-                    _ => app.form_input_char(' '),
-                }
-            }
+    // Without a chosen theme, match the default palette to the terminal
+    if cli.theme.is_none() {
+        let background = std::env::var("COLORFGBG")
+            .ok()
+            .and_then(|v| Background::from_colorfgbg(&v))
+            .or_else(|| tui::query_background().and_then(|reply| Background::from_osc11(&reply)));
+        if let Some(background) = background {
+            theme::set_background(background);
         }
-        KeyCode::Char(c) => app.form_input_char(c),
-        _ => {}
     }
+
+    let original_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |panic_info| {
+        let _ = tui::restore();
+        original_hook(panic_info);
+    }));
+
+    let mut terminal = tui::init()?;
+    let result = start(&mut terminal, cli.demo).and_then(|mut app| {
+        apply_args(&mut app, &cli);
+        run(&mut terminal, &mut app)?;
+        // Demo data shouldn't move the real calendar's place
+        if cli.demo {
+            return Ok(());
+        }
+        app.save_session()
+    });
+    tui::restore()?;
+    result
 }
 
-fn render_month_layout(frame: &mut ratatui::Frame, area: Rect, app: &App, total_width: u16) {
-    if total_width < 60 {
-        components::MonthView::render(
-            frame, area, app.selected_date, app.today, &app.days_with_events, &app.days_with_reminders,
-        );
+/// The event `add` creates: all-day without a start time, otherwise ending
+/// at `to` or an hour after the start, on the next day if that's earlier.
+fn new_event(title: &str, date: NaiveDate, from: Option<NaiveTime>, to: Option<NaiveTime>) -> NewEvent {
+    let (start_time, end_time) = match from {
+        Some(from) => (from, to.unwrap_or(from + chrono::Duration::hours(1))),
+        None => (NaiveTime::MIN, NaiveTime::MIN),
+    };
+    let end_date = if from.is_some() && end_time <= start_time {
+        date.succ_opt().unwrap_or(date)
     } else {
-        let month_w = if total_width >= 100 { 44 } else { 30 };
-        let content = Layout::horizontal([
-            Constraint::Length(month_w),
-            Constraint::Min(20),
-        ])
-        .split(area);
-
-        components::MonthView::render(
-            frame, content[0], app.selected_date, app.today, &app.days_with_events, &app.days_with_reminders,
-        );
-
-        let progress = if app.day_total_reminders > 0 {
-            Some((app.day_completed_count, app.day_total_reminders))
-        } else {
-            None
-        };
-        components::DayView::render(
-            frame,
-            content[1],
-            app.selected_date,
-            &app.day_events,
-            &app.day_reminders,
-            app.day_scroll,
-            progress,
-        );
+        date
+    };
+    NewEvent {
+        title: title.to_string(),
+        date,
+        start_time,
+        end_date,
+        end_time,
+        is_all_day: from.is_none(),
+        calendar_id: None,
+        location: None,
+        url: None,
+        notes: None,
+        alarm_minutes: None,
+        busy: false,
     }
 }
 
-fn render_status_bar(frame: &mut ratatui::Frame, area: Rect, app: &App, w: u16) {
-    use ratatui::text::{Line, Span};
-    use ratatui::widgets::Paragraph;
+fn reminders(app: &App, command: &RemindersCommand) -> Result<()> {
+    match command {
+        RemindersCommand::List => {
+            for rem in &app.reminders {
+                let due = rem.due_date.map(|d| d.date_naive().to_string()).unwrap_or_else(|| "-".to_string());
+                println!("{}\t{}\t{}\t{}", rem.id, due, rem.title, rem.calendar_name);
+            }
+        }
+        RemindersCommand::Add { title, due, list } => {
+            println!("{}", app.add_reminder(title, *due, list.as_deref())?);
+        }
+        RemindersCommand::Done { id } => match app.complete_reminder(id)? {
+            Some(next) => println!("Completed; next due {}", next.date_naive()),
+            None => println!("Completed"),
+        },
+    }
+    Ok(())
+}
 
-    let w = w as usize;
+/// Apply command-line options over the restored session.
+fn apply_args(app: &mut App, cli: &Cli) {
+    if let Some(view) = cli.view {
+        app.view_mode = view;
+    }
+    if let Some(date) = cli.date {
+        app.go_to_date(date);
+    } else if cli.today {
+        app.go_to_today();
+    }
+    if !cli.calendars.is_empty() {
+        app.show_only_calendars(cli.calendars.clone());
+    }
+}
 
-    let mode_str = match app.view_mode {
-        ViewMode::Month => "[1]Month",
-        ViewMode::Week => "[2]Week",
-        ViewMode::Day => "[3]Day",
-    };
+/// Build the app behind a loading screen.
+fn start(terminal: &mut tui::Tui, demo: bool) -> Result<App> {
+    let since = Instant::now();
+    open(demo, &mut |stage: &str| {
+        let _ = terminal.draw(|frame| ui::draw_splash(frame, stage, since));
+    })
+}
 
-    let focus_indicator = match app.input_mode {
-        InputMode::Form => " [New Event]",
-        InputMode::Normal => "",
-        _ => "",
-    };
+/// Build the app, falling back to the local calendars when EventKit can't
+/// be used. The demo starts from default settings and never saves them.
+fn open(demo: bool, progress: &mut dyn FnMut(&str)) -> Result<App> {
+    if demo {
+        let today = chrono::Local::now().date_naive();
+        let backend = Box::new(calendar::demo::demo_backend(today));
+        return App::with_backend_and_config(backend, Config::default(), ViewState::default(), History::default());
+    }
+    match App::connect(progress) {
+        Ok(app) => Ok(app),
+        Err(e) => App::local(&e.to_string()),
+    }
+}
 
-    // Show status message if present, otherwise show context-aware hints
-    let right_text = if let Some(ref msg) = app.status_message {
-        format!(" {} ", msg)
-    } else {
-        match app.view_mode {
-            ViewMode::Day | ViewMode::Month if w >= 80 => {
-                " hjkl:Nav [/]:Mon t:Today Enter:Detail Sp:Toggle n:New d:Del ?:Help q:Quit".to_string()
-            }
-            ViewMode::Day | ViewMode::Month if w >= 50 => {
-                " jk:Scroll Enter:Detail Sp:Toggle n:New q:Quit".to_string()
-            }
-            ViewMode::Week if w >= 70 => {
-                " hl:Day [/]:Mon t:Today n:New ?:Help q:Quit".to_string()
-            }
-            ViewMode::Week if w >= 50 => {
-                " arrows:Nav n:New q:Quit".to_string()
+fn run(terminal: &mut tui::Tui, app: &mut App) -> Result<()> {
+    let mut drawn_minute = None;
+    while app.running {
+        app.tick();
+        // Redraw for input and changed data, while something animates, and
+        // when the clock's minute turns for the "now" marker and timers
+        let minute = Local::now().minute();
+        if app.redraw || app.animating() || drawn_minute != Some(minute) {
+            let frame = terminal.draw(|frame| {
+                ui::draw(frame, app);
+            })?;
+            if let Some(format) = app.pending_snapshot.take() {
+                app.save_snapshot(frame.buffer, format);
             }
-            _ => " ?:Help q:Quit".to_string(),
+            app.redraw = false;
+            drawn_minute = Some(minute);
         }
-    };
-
-    let left = format!(" {}{} ", mode_str, focus_indicator);
-    let padding_len = w.saturating_sub(left.len() + right_text.len());
-    let padding = " ".repeat(padding_len);
-
-    let line = Line::from(vec![
-        Span::styled(left, theme::current().status),
-        Span::styled(padding, theme::current().status),
-        Span::styled(right_text, theme::current().status),
-    ]);
 
-    let bar = Paragraph::new(line).style(theme::current().status);
-    frame.render_widget(bar, area);
-}
+        // Keep a running job moving instead of waiting for keys; otherwise
+        // sleep until input, waking now and then to look for store changes
+        let timeout = if app.job.is_some() {
+            Duration::ZERO
+        } else if app.animating() {
+            ANIMATION_FRAME
+        } else {
+            IDLE_POLL
+        };
+        let input = event::next_input(timeout)?;
+        if input.is_some() {
+            app.redraw = true;
+        }
+        if let Some(Input::Focus(focused)) = input {
+            app.set_focused(focused);
+            continue;
+        }
+        if let Some(Input::Key(key)) = input {
+            input::handle_key(app, key);
+        }
+    }
 
-fn render_help(frame: &mut ratatui::Frame, area: Rect) {
-    use ratatui::style::{Color, Modifier, Style};
-    use ratatui::text::{Line, Span};
-    use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
-
-    let popup_w = area.width.min(52).max(30);
-    let popup_h = area.height.min(22).max(12);
-    let x = area.x + (area.width.saturating_sub(popup_w)) / 2;
-    let y = area.y + (area.height.saturating_sub(popup_h)) / 2;
-    let popup_area = Rect::new(x, y, popup_w, popup_h);
-
-    frame.render_widget(Clear, popup_area);
-
-    let block = Block::default()
-        .title(" Keybindings ")
-        .title_style(Style::default().fg(Color::Green).add_modifier(Modifier::BOLD))
-        .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Green));
-
-    let inner = block.inner(popup_area);
-    frame.render_widget(block, popup_area);
-
-    let key_style = Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD);
-    let desc_style = Style::default();
-    let section_style = Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
-
-    let lines = vec![
-        Line::from(Span::styled("Navigation", section_style)),
-        Line::from(vec![
-            Span::styled("  h/l ", key_style),
-            Span::styled("or ", theme::current().dim),
-            Span::styled("\u{2190}/\u{2192}  ", key_style),
-            Span::styled("Previous/next day", desc_style),
-        ]),
-        Line::from(vec![
-            Span::styled("  j/k ", key_style),
-            Span::styled("or ", theme::current().dim),
-            Span::styled("\u{2191}/\u{2193}  ", key_style),
-            Span::styled("Scroll day list", desc_style),
-        ]),
-        Line::from(vec![
-            Span::styled("  [/]       ", key_style),
-            Span::styled("Previous/next month", desc_style),
-        ]),
-        Line::from(vec![
-            Span::styled("  t         ", key_style),
-            Span::styled("Jump to today", desc_style),
-        ]),
-        Line::from(""),
-        Line::from(Span::styled("Views", section_style)),
-        Line::from(vec![
-            Span::styled("  1/2/3     ", key_style),
-            Span::styled("Month / Week / Day view", desc_style),
-        ]),
-        Line::from(""),
-        Line::from(Span::styled("Actions", section_style)),
-        Line::from(vec![
-            Span::styled("  Enter     ", key_style),
-            Span::styled("View event/reminder details", desc_style),
-        ]),
-        Line::from(vec![
-            Span::styled("  Space     ", key_style),
-            Span::styled("Toggle reminder completion", desc_style),
-        ]),
-        Line::from(vec![
-            Span::styled("  n         ", key_style),
-            Span::styled("Create new event", desc_style),
-        ]),
-        Line::from(vec![
-            Span::styled("  d         ", key_style),
-            Span::styled("Delete selected event", desc_style),
-        ]),
-        Line::from(vec![
-            Span::styled("  r         ", key_style),
-            Span::styled("Refresh reminders", desc_style),
-        ]),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("  q", key_style),
-            Span::styled(" / ", theme::current().dim),
-            Span::styled("Esc     ", key_style),
-            Span::styled("Quit / close popup", desc_style),
-        ]),
-    ];
-
-    let para = Paragraph::new(lines).wrap(Wrap { trim: false });
-    frame.render_widget(para, inner);
+    Ok(())
 }
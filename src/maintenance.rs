@@ -0,0 +1,93 @@
+//! Scans that look for events worth cleaning up.
+
+use std::collections::HashSet;
+
+use chrono::NaiveDate;
+
+use crate::calendar::{CalendarEvent, EventOrigin};
+
+/// Suggested events that ended more than `older_than_days` ago, and declined
+/// invitations that are already over. Each comes with a short reason.
+///
+/// Repeating events are left out: deleting by id would remove the series'
+/// first occurrence rather than the one listed.
+pub fn stale_events(
+    events: &[CalendarEvent],
+    today: NaiveDate,
+    older_than_days: i64,
+) -> Vec<(CalendarEvent, String)> {
+    let cutoff = chrono::Duration::try_days(older_than_days)
+        .and_then(|age| today.checked_sub_signed(age))
+        .unwrap_or(NaiveDate::MIN);
+    let mut seen = HashSet::new();
+    events
+        .iter()
+        .filter(|ev| ev.recurrence.is_none() && seen.insert(ev.id.as_str()))
+        .filter_map(|ev| {
+            let last = ev.last_day();
+            if ev.origin == EventOrigin::Suggestion && last < cutoff {
+                Some((ev.clone(), "suggested".to_string()))
+            } else if ev.declined && last < today {
+                Some((ev.clone(), "declined".to_string()))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Events with the same title and overlapping times in different calendars,
+/// typically left behind by an account synced twice. For each pair the later
+/// calendar's copy is proposed for deletion; the other copy is returned as the
+/// alternative so the user can swap which one goes.
+///
+/// Repeating events are left out, as in [`stale_events`].
+pub fn duplicate_events(events: &[CalendarEvent]) -> Vec<DuplicatePair> {
+    let mut seen = HashSet::new();
+    let events: Vec<&CalendarEvent> = events
+        .iter()
+        .filter(|ev| ev.recurrence.is_none() && seen.insert(ev.id.as_str()))
+        .collect();
+    let mut pairs = Vec::new();
+    let mut used = vec![false; events.len()];
+    for (i, a) in events.iter().enumerate() {
+        if used[i] {
+            continue;
+        }
+        let title = a.title.trim().to_lowercase();
+        let dup = events.iter().enumerate().skip(i + 1).find(|(j, b)| {
+            !used[*j]
+                && b.calendar_id != a.calendar_id
+                && b.title.trim().to_lowercase() == title
+                && b.start < a.end
+                && a.start < b.end
+        });
+        if let Some((j, b)) = dup {
+            used[i] = true;
+            used[j] = true;
+            let (keep, drop) = if a.calendar_name <= b.calendar_name { (a, b) } else { (b, a) };
+            pairs.push(DuplicatePair {
+                remove: ((*drop).clone(), format!("also in {}", keep.calendar_name)),
+                keep: ((*keep).clone(), format!("also in {}", drop.calendar_name)),
+            });
+        }
+    }
+    pairs
+}
+
+/// Two copies of the same event; `remove` is the one proposed for deletion.
+#[derive(Debug, Clone)]
+pub struct DuplicatePair {
+    pub remove: (CalendarEvent, String),
+    pub keep: (CalendarEvent, String),
+}
+
+/// Meetings on `date` that an all-day focus block would shadow: timed events
+/// you have not declined.
+pub fn focus_conflicts(events: &[CalendarEvent], date: NaiveDate) -> Vec<CalendarEvent> {
+    events
+        .iter()
+        .filter(|ev| !ev.is_all_day && !ev.declined && ev.occurs_on(date))
+        .cloned()
+        .collect()
+}
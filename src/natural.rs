@@ -0,0 +1,295 @@
+//! Natural-language event parsing, e.g. "Interview Thursday 3pm, 45 minutes".
+//!
+//! The parser picks out a date, a start time (or time range), a duration and
+//! a link; whatever is left of the first clause becomes the title.
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+
+use crate::calendar::TimeFormat;
+
+/// Fields recognised in a piece of free text.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParsedEvent {
+    pub title: String,
+    pub date: Option<NaiveDate>,
+    pub start: Option<NaiveTime>,
+    pub duration: Option<Duration>,
+    pub url: Option<String>,
+    /// The original text when it spans several lines, e.g. a pasted email.
+    pub notes: Option<String>,
+}
+
+impl ParsedEvent {
+    /// Start and end of a timed event, filling gaps with `default_date` and
+    /// a one hour duration. `None` without a start time, or when the end
+    /// is past the last representable date.
+    pub fn time_range(&self, default_date: NaiveDate) -> Option<(NaiveDateTime, NaiveDateTime)> {
+        let start = self.date.unwrap_or(default_date).and_time(self.start?);
+        Some((start, start.checked_add_signed(self.duration.unwrap_or(Duration::hours(1)))?))
+    }
+}
+
+/// Words dropped from the title when they sit next to a recognised part.
+const FILLERS: &[&str] = &["on", "at", "for", "from", "next", "this", "@", "-", "\u{2013}"];
+
+struct Token {
+    text: String,
+    /// The token ends its clause (trailing comma, semicolon or line break).
+    ends_clause: bool,
+    used: bool,
+}
+
+/// Parse `text` relative to `today`.
+pub fn parse(text: &str, today: NaiveDate) -> ParsedEvent {
+    let mut tokens = tokenize(text);
+    let mut parsed = ParsedEvent::default();
+
+    let mut i = 0;
+    while i < tokens.len() {
+        let word = tokens[i].text.to_lowercase();
+        let next = tokens.get(i + 1).map(|t| t.text.to_lowercase());
+
+        if parsed.url.is_none() && tokens[i].text.contains("://") {
+            parsed.url = Some(tokens[i].text.clone());
+            tokens[i].used = true;
+        } else if parsed.date.is_none() {
+            if let Some((date, len)) = parse_date(&word, next.as_deref(), today) {
+                parsed.date = Some(date);
+                mark_used(&mut tokens, i, len);
+                i += len;
+                continue;
+            }
+        }
+
+        if parsed.start.is_none() && !tokens[i].used {
+            let after_at = i > 0 && tokens[i - 1].text.eq_ignore_ascii_case("at");
+            if let Some((start, end, len)) = parse_time(&word, next.as_deref(), after_at) {
+                parsed.start = Some(start);
+                if let Some(end) = end.filter(|&end| end > start) {
+                    parsed.duration = Some(end - start);
+                }
+                mark_used(&mut tokens, i, len);
+                i += len;
+                continue;
+            }
+        }
+
+        if parsed.duration.is_none() && !tokens[i].used {
+            if let Some((duration, len)) = parse_duration(&word, next.as_deref()) {
+                parsed.duration = Some(duration);
+                mark_used(&mut tokens, i, len);
+                i += len;
+                continue;
+            }
+        }
+
+        if word == "next" {
+            // "next thursday": always the coming one, never today
+            if let Some(weekday) = next.as_deref().and_then(parse_weekday) {
+                if parsed.date.is_none() {
+                    let ahead = days_until(today.weekday(), weekday);
+                    parsed.date = Some(today + Duration::days(if ahead == 0 { 7 } else { ahead }));
+                    mark_used(&mut tokens, i, 2);
+                    i += 2;
+                    continue;
+                }
+            }
+        }
+        i += 1;
+    }
+
+    // Title: the unrecognised words of the first clause that has any
+    let mut title: Vec<&str> = Vec::new();
+    for token in &tokens {
+        if !token.used && !FILLERS.contains(&token.text.to_lowercase().as_str()) {
+            title.push(&token.text);
+        }
+        if token.ends_clause && !title.is_empty() {
+            break;
+        }
+    }
+    parsed.title = title.join(" ");
+
+    if text.trim().lines().count() > 1 {
+        parsed.notes = Some(text.trim().to_string());
+    }
+    parsed
+}
+
+fn tokenize(text: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    for line in text.lines() {
+        let start = tokens.len();
+        for raw in line.split_whitespace() {
+            let ends_clause = raw.ends_with([',', ';']);
+            let text = raw.trim_end_matches([',', ';', '.', '!', '?', ')']).trim_start_matches('(');
+            if text.is_empty() {
+                continue;
+            }
+            tokens.push(Token { text: text.to_string(), ends_clause, used: false });
+        }
+        if let Some(last) = tokens[start..].last_mut() {
+            last.ends_clause = true;
+        }
+    }
+    tokens
+}
+
+fn mark_used(tokens: &mut [Token], from: usize, len: usize) {
+    for token in tokens.iter_mut().skip(from).take(len) {
+        token.used = true;
+    }
+}
+
+/// A date starting at `word`, with the number of tokens it used.
+fn parse_date(word: &str, next: Option<&str>, today: NaiveDate) -> Option<(NaiveDate, usize)> {
+    match word {
+        "today" | "tonight" => return Some((today, 1)),
+        "tomorrow" => return Some((today.succ_opt()?, 1)),
+        _ => {}
+    }
+    if let Some(weekday) = parse_weekday(word) {
+        return Some((today + Duration::days(days_until(today.weekday(), weekday)), 1));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(word, "%Y-%m-%d") {
+        return Some((date, 1));
+    }
+    // "3/14": month first, as on macOS in the US locale; rolls to next year once past
+    if let Some((m, d)) = word.split_once('/') {
+        let date = upcoming(today, m.parse().ok()?, d.parse().ok()?)?;
+        return Some((date, 1));
+    }
+    // "mar 14" / "14 march"
+    if let (Some(month), Some(day)) = (parse_month(word), next.and_then(parse_day_number)) {
+        return Some((upcoming(today, month, day)?, 2));
+    }
+    if let (Some(day), Some(month)) = (parse_day_number(word), next.and_then(parse_month)) {
+        return Some((upcoming(today, month, day)?, 2));
+    }
+    None
+}
+
+/// The next `month`/`day` on or after `today`.
+fn upcoming(today: NaiveDate, month: u32, day: u32) -> Option<NaiveDate> {
+    let this_year = NaiveDate::from_ymd_opt(today.year(), month, day)?;
+    if this_year >= today {
+        Some(this_year)
+    } else {
+        NaiveDate::from_ymd_opt(today.year() + 1, month, day)
+    }
+}
+
+fn days_until(from: Weekday, to: Weekday) -> i64 {
+    (to.num_days_from_monday() as i64 - from.num_days_from_monday() as i64).rem_euclid(7)
+}
+
+fn parse_weekday(word: &str) -> Option<Weekday> {
+    Some(match word {
+        "monday" | "mon" => Weekday::Mon,
+        "tuesday" | "tue" | "tues" => Weekday::Tue,
+        "wednesday" | "wed" => Weekday::Wed,
+        "thursday" | "thu" | "thur" | "thurs" => Weekday::Thu,
+        "friday" | "fri" => Weekday::Fri,
+        "saturday" | "sat" => Weekday::Sat,
+        "sunday" | "sun" => Weekday::Sun,
+        _ => return None,
+    })
+}
+
+fn parse_month(word: &str) -> Option<u32> {
+    const MONTHS: [&str; 12] = [
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ];
+    if word.len() < 3 {
+        return None;
+    }
+    let idx = MONTHS.iter().position(|m| word.starts_with(m))?;
+    // Accept "mar", "march", "sept" but not arbitrary words sharing a prefix
+    let full = [
+        "january", "february", "march", "april", "may", "june", "july", "august",
+        "september", "october", "november", "december",
+    ][idx];
+    (full.starts_with(word) || word == "sept").then_some(idx as u32 + 1)
+}
+
+/// "14", "14th", "1st".
+fn parse_day_number(word: &str) -> Option<u32> {
+    let digits = word.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+    let suffix = &word[digits.len()..];
+    if !matches!(suffix, "" | "st" | "nd" | "rd" | "th") {
+        return None;
+    }
+    digits.parse().ok().filter(|d| (1..=31).contains(d))
+}
+
+/// A start time, optional end time and the number of tokens used. Handles
+/// "3pm", "3 pm", "15:00", "noon", ranges like "3-4pm", and a bare hour
+/// after "at".
+fn parse_time(
+    word: &str,
+    next: Option<&str>,
+    after_at: bool,
+) -> Option<(NaiveTime, Option<NaiveTime>, usize)> {
+    if let Some(suffix @ ("am" | "pm")) = next {
+        if let Some(time) = single_time(&format!("{}{}", word, suffix)) {
+            return Some((time, None, 2));
+        }
+        if let Some((start, end)) = time_range(word, suffix) {
+            return Some((start, Some(end), 2));
+        }
+    }
+    if let Some(time) = single_time(word) {
+        return Some((time, None, 1));
+    }
+    if let Some((start, end)) = time_range(word, "") {
+        return Some((start, Some(end), 1));
+    }
+    if after_at {
+        let hour: u32 = word.parse().ok().filter(|h| (1..=12).contains(h))?;
+        // Bare hours lean towards office hours: 8-11 morning, 12-7 afternoon
+        let hour = if (8..12).contains(&hour) { hour } else { hour % 12 + 12 };
+        return Some((NaiveTime::from_hms_opt(hour, 0, 0)?, None, 1));
+    }
+    None
+}
+
+fn single_time(word: &str) -> Option<NaiveTime> {
+    match word {
+        "noon" => NaiveTime::from_hms_opt(12, 0, 0),
+        "midnight" => NaiveTime::from_hms_opt(0, 0, 0),
+        _ => TimeFormat::parse(word),
+    }
+}
+
+/// "3-4pm", "3pm-4pm", "14:00-15:30". `suffix` is an am/pm that followed
+/// as a separate word.
+fn time_range(word: &str, suffix: &str) -> Option<(NaiveTime, NaiveTime)> {
+    let (a, b) = word.split_once(['-', '\u{2013}'])?;
+    let b = format!("{}{}", b, suffix);
+    let end = single_time(&b)?;
+    let start = single_time(a).or_else(|| {
+        // "3-4pm": the start borrows the end's am/pm
+        let meridiem = if b.ends_with("am") { "am" } else if b.ends_with("pm") { "pm" } else { return None };
+        single_time(&format!("{}{}", a, meridiem))
+    })?;
+    Some((start, end))
+}
+
+/// "45 minutes", "45min", "1h", "1.5 hours", with the number of tokens used.
+fn parse_duration(word: &str, next: Option<&str>) -> Option<(Duration, usize)> {
+    let split = word.find(|c: char| !(c.is_ascii_digit() || c == '.')).unwrap_or(word.len());
+    let (number, unit, len) = if split == word.len() {
+        (word, next?, 2)
+    } else {
+        (&word[..split], &word[split..], 1)
+    };
+    let amount: f64 = number.parse().ok().filter(|n: &f64| *n > 0.0)?;
+    let minutes = match unit {
+        "m" | "min" | "mins" | "minute" | "minutes" => amount,
+        "h" | "hr" | "hrs" | "hour" | "hours" => amount * 60.0,
+        _ => return None,
+    };
+    // Durations too long for a date are not durations
+    let minutes = Some(minutes.round()).filter(|m| m.is_finite() && *m < i64::MAX as f64)?;
+    Some((Duration::try_minutes(minutes as i64)?, len))
+}
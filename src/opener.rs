@@ -0,0 +1,43 @@
+//! Handing URLs and shell commands off to other programs.
+
+use std::process::{Command, Stdio};
+
+use color_eyre::eyre::{eyre, Result};
+
+/// Open `url` in the app registered for it, e.g. Zoom for a Zoom link.
+pub fn open_url(url: &str) -> Result<()> {
+    let output = Command::new("open").arg(url).output()?;
+    if !output.status.success() {
+        let message = String::from_utf8_lossy(&output.stderr);
+        return Err(eyre!("open failed: {}", message.trim()));
+    }
+    Ok(())
+}
+
+/// Start `command` with `sh -c` in the background, with `vars` added to
+/// its environment. Its output is discarded.
+pub fn run_shell(command: &str, vars: &[(&str, String)]) -> Result<()> {
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .envs(vars.iter().map(|(k, v)| (k, v)))
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    // Reap it when it exits
+    std::thread::spawn(move || child.wait());
+    Ok(())
+}
+
+/// Apple Maps search for `place`.
+pub fn maps_url(place: &str) -> String {
+    let mut query = String::new();
+    for byte in place.trim().bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => query.push(byte as char),
+            _ => query.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    format!("maps://?q={}", query)
+}
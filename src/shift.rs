@@ -0,0 +1,130 @@
+//! Moving many events at once with `:shift`, and copying a day or week
+//! onto others with `:template`.
+
+use chrono::{DateTime, Duration, Local, NaiveDate};
+
+use crate::calendar::time::shift_days;
+use crate::calendar::{CalendarEvent, Reschedule};
+
+const DAY_MINUTES: i64 = 24 * 60;
+
+/// Parse a delta like `+1h`, `-30m`, `+7d` or `2w` into minutes.
+pub fn parse_delta(s: &str) -> Option<i64> {
+    let s = s.trim();
+    let (sign, rest) = match s.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let unit = rest.chars().last()?;
+    let n: i64 = rest[..rest.len() - unit.len_utf8()].parse().ok()?;
+    let per_unit = match unit {
+        'm' => 1,
+        'h' => 60,
+        'd' => DAY_MINUTES,
+        'w' => 7 * DAY_MINUTES,
+        _ => return None,
+    };
+    // Deltas too large for a date are refused rather than overflowing
+    let minutes = n.checked_mul(per_unit)?.checked_mul(sign)?;
+    Duration::try_minutes(minutes)?;
+    (minutes != 0).then_some(minutes)
+}
+
+/// "+1h", "-30m", "+7d" or "+1h 30m".
+pub fn delta_label(minutes: i64) -> String {
+    let sign = if minutes < 0 { "-" } else { "+" };
+    let m = minutes.unsigned_abs();
+    let (days, hours, mins) = (m / DAY_MINUTES as u64, m % DAY_MINUTES as u64 / 60, m % 60);
+    let parts: Vec<String> = [(days, "d"), (hours, "h"), (mins, "m")]
+        .into_iter()
+        .filter(|(n, _)| *n > 0)
+        .map(|(n, unit)| format!("{}{}", n, unit))
+        .collect();
+    format!("{}{}", sign, parts.join(" "))
+}
+
+/// `time` moved by `minutes`. Whole days keep the wall-clock time across
+/// DST changes, like moving an event by days does.
+pub fn shift_time(time: DateTime<Local>, minutes: i64) -> Option<DateTime<Local>> {
+    if minutes % DAY_MINUTES == 0 {
+        shift_days(time, minutes / DAY_MINUTES)
+    } else {
+        time.checked_add_signed(Duration::try_minutes(minutes)?)
+    }
+}
+
+/// The changes that shift `events`, and the ones that undo them.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Plan {
+    pub changes: Vec<Reschedule>,
+    pub undo: Vec<Reschedule>,
+    /// Events left alone: repeating events, whose occurrences cannot be
+    /// moved one by one yet, and all-day events for deltas that are not
+    /// whole days.
+    pub skipped: usize,
+}
+
+pub fn plan(events: &[CalendarEvent], minutes: i64) -> Plan {
+    let mut plan = Plan::default();
+    for ev in events {
+        let movable = ev.recurrence.is_none() && (!ev.is_all_day || minutes % DAY_MINUTES == 0);
+        let shifted = (shift_time(ev.start, minutes), shift_time(ev.end, minutes));
+        let (true, (Some(start), Some(end))) = (movable, shifted) else {
+            plan.skipped += 1;
+            continue;
+        };
+        plan.changes.push(Reschedule { event_id: ev.id.clone(), start, end });
+        plan.undo.push(Reschedule { event_id: ev.id.clone(), start: ev.start, end: ev.end });
+    }
+    plan
+}
+
+/// Most days or weeks `:template` copies onto at once.
+pub const MAX_TEMPLATE_PERIODS: i64 = 366;
+
+/// The copies `:template` makes.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Template {
+    pub copies: Vec<CalendarEvent>,
+    /// Repeating events in the source period, which are not copied.
+    pub skipped: usize,
+}
+
+/// Previews of the copies `:template` makes: the events of the `days`-long
+/// period starting on `source`, repeated for each period from `target`
+/// through `until`. Declined events are left out. Copies keep their
+/// wall-clock times and get ids of the form `<id>@<date>`. `None` when that
+/// is more than [`MAX_TEMPLATE_PERIODS`] periods.
+pub fn template(
+    events: &[CalendarEvent],
+    source: NaiveDate,
+    days: i64,
+    target: NaiveDate,
+    until: NaiveDate,
+) -> Option<Template> {
+    if (until - target).num_days() / days >= MAX_TEMPLATE_PERIODS {
+        return None;
+    }
+    let source_end = source.checked_add_signed(Duration::try_days(days)?).unwrap_or(NaiveDate::MAX);
+    let (repeating, originals): (Vec<&CalendarEvent>, Vec<&CalendarEvent>) = events
+        .iter()
+        .filter(|ev| !ev.declined && (source..source_end).contains(&ev.start.date_naive()))
+        .partition(|ev| ev.recurrence.is_some());
+    let mut template = Template { copies: Vec::new(), skipped: repeating.len() };
+    let mut period = Some(target);
+    while let Some(date) = period.filter(|&date| date <= until) {
+        let offset = (date - source).num_days();
+        for ev in &originals {
+            let (Some(start), Some(end)) = (shift_days(ev.start, offset), shift_days(ev.end, offset)) else {
+                continue;
+            };
+            let mut copy = (*ev).clone();
+            copy.id = format!("{}@{}", ev.id, start.date_naive());
+            copy.start = start;
+            copy.end = end;
+            template.copies.push(copy);
+        }
+        period = date.checked_add_signed(Duration::try_days(days)?);
+    }
+    Some(template)
+}
@@ -0,0 +1,109 @@
+//! Saving the rendered screen as text, for sharing a plan in chat or
+//! attaching to a bug report.
+
+use std::path::PathBuf;
+
+use ratatui::buffer::Buffer;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Span;
+
+use crate::config;
+
+/// How a snapshot is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotFormat {
+    /// Characters only, trailing blanks trimmed.
+    Plain,
+    /// With ANSI color and style codes, for `cat` in a terminal.
+    Ansi,
+}
+
+/// Directory snapshots are written to (`~/.config/calendar-tui/snapshots`).
+pub fn snapshots_dir() -> Option<PathBuf> {
+    config::config_dir().map(|d| d.join("snapshots"))
+}
+
+/// The buffer as text, one line per row.
+pub fn render(buffer: &Buffer, format: SnapshotFormat) -> String {
+    let area = buffer.area;
+    let mut out = String::new();
+    for y in area.top()..area.bottom() {
+        let mut line = String::new();
+        let mut style = Style::reset();
+        let mut skip = 0;
+        for x in area.left()..area.right() {
+            // Cells covered by the previous wide character
+            if skip > 0 {
+                skip -= 1;
+                continue;
+            }
+            let cell = &buffer[(x, y)];
+            if format == SnapshotFormat::Ansi && cell.style() != style {
+                style = cell.style();
+                line.push_str(&sgr(style));
+            }
+            line.push_str(cell.symbol());
+            skip = Span::raw(cell.symbol()).width().saturating_sub(1);
+        }
+        match format {
+            SnapshotFormat::Plain => out.push_str(line.trim_end()),
+            SnapshotFormat::Ansi => {
+                out.push_str(&line);
+                out.push_str("\x1b[0m");
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Escape sequence switching to `style` from any other.
+fn sgr(style: Style) -> String {
+    let mut codes = vec!["0".to_string()];
+    for (modifier, code) in [
+        (Modifier::BOLD, "1"),
+        (Modifier::DIM, "2"),
+        (Modifier::ITALIC, "3"),
+        (Modifier::UNDERLINED, "4"),
+        (Modifier::REVERSED, "7"),
+        (Modifier::CROSSED_OUT, "9"),
+    ] {
+        if style.add_modifier.contains(modifier) {
+            codes.push(code.to_string());
+        }
+    }
+    if let Some(fg) = style.fg.and_then(|c| color_code(c, 30)) {
+        codes.push(fg);
+    }
+    if let Some(bg) = style.bg.and_then(|c| color_code(c, 40)) {
+        codes.push(bg);
+    }
+    format!("\x1b[{}m", codes.join(";"))
+}
+
+/// SGR parameters for `color`; `base` is 30 for foreground, 40 for background.
+fn color_code(color: Color, base: u8) -> Option<String> {
+    let named = |n: u8| Some((base + n).to_string());
+    let bright = |n: u8| Some((base + 60 + n).to_string());
+    match color {
+        Color::Reset => None,
+        Color::Black => named(0),
+        Color::Red => named(1),
+        Color::Green => named(2),
+        Color::Yellow => named(3),
+        Color::Blue => named(4),
+        Color::Magenta => named(5),
+        Color::Cyan => named(6),
+        Color::Gray => named(7),
+        Color::DarkGray => bright(0),
+        Color::LightRed => bright(1),
+        Color::LightGreen => bright(2),
+        Color::LightYellow => bright(3),
+        Color::LightBlue => bright(4),
+        Color::LightMagenta => bright(5),
+        Color::LightCyan => bright(6),
+        Color::White => bright(7),
+        Color::Rgb(r, g, b) => Some(format!("{};2;{};{};{}", base + 8, r, g, b)),
+        Color::Indexed(i) => Some(format!("{};5;{}", base + 8, i)),
+    }
+}
@@ -0,0 +1,75 @@
+//! View state remembered between sessions, stored in
+//! `~/.config/calendar-tui/state.toml` apart from the hand-edited config.
+
+use std::path::PathBuf;
+
+use chrono::NaiveDate;
+use color_eyre::eyre::{eyre, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::app::ViewMode;
+use crate::config;
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ViewState {
+    /// Hours the week view grid is scrolled from the configured range.
+    pub week_hour_offset: i32,
+    /// Hours the day view timeline is scrolled from the configured range.
+    pub day_hour_offset: i32,
+    /// Width of the month grid beside the day list; `None` derives it from
+    /// the terminal width.
+    pub month_width: Option<u16>,
+    /// View and date shown when the app last quit, restored on launch
+    /// unless `--today` is given.
+    pub view: Option<ViewMode>,
+    pub selected_date: Option<NaiveDate>,
+}
+
+impl ViewState {
+    pub fn load() -> Self {
+        state_path()
+            .filter(|p| p.exists())
+            .and_then(|p| std::fs::read_to_string(p).ok())
+            .and_then(|content| toml::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Save the fields changed since `base` (the state as last loaded or
+    /// saved) on top of what is on disk now, so another running instance's
+    /// changes survive. Returns the merged state.
+    pub fn save(&self, base: &ViewState) -> Result<ViewState> {
+        let path = state_path().ok_or_else(|| eyre!("No home directory"))?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let merged = self.merged_onto(base, ViewState::load());
+        config::write_atomic(&path, &toml::to_string_pretty(&merged)?)?;
+        Ok(merged)
+    }
+
+    /// `current` with every field that differs between `self` and `base`
+    /// taken from `self`.
+    pub fn merged_onto(&self, base: &ViewState, mut current: ViewState) -> ViewState {
+        if self.week_hour_offset != base.week_hour_offset {
+            current.week_hour_offset = self.week_hour_offset;
+        }
+        if self.day_hour_offset != base.day_hour_offset {
+            current.day_hour_offset = self.day_hour_offset;
+        }
+        if self.month_width != base.month_width {
+            current.month_width = self.month_width;
+        }
+        if self.view != base.view {
+            current.view = self.view;
+        }
+        if self.selected_date != base.selected_date {
+            current.selected_date = self.selected_date;
+        }
+        current
+    }
+}
+
+fn state_path() -> Option<PathBuf> {
+    config::config_dir().map(|d| d.join("state.toml"))
+}
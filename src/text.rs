@@ -0,0 +1,82 @@
+//! Fitting event titles into narrow cells.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use serde::{Deserialize, Serialize};
+
+static TRUNCATION: AtomicU8 = AtomicU8::new(0);
+
+const ELLIPSIS: char = '\u{2026}';
+
+/// How titles too long for their cell are shortened.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Truncation {
+    /// "Quarterly planning re…"
+    #[default]
+    End,
+    /// "Quarterly…w session", keeping the distinguishing tail.
+    Middle,
+    /// "Quarterly planning…", cut between words.
+    Word,
+}
+
+impl Truncation {
+    /// The strategy in effect, as set from the config at startup.
+    pub fn current() -> Self {
+        match TRUNCATION.load(Ordering::Relaxed) {
+            1 => Truncation::Middle,
+            2 => Truncation::Word,
+            _ => Truncation::End,
+        }
+    }
+
+    pub fn set_current(self) {
+        TRUNCATION.store(self as u8, Ordering::Relaxed);
+    }
+
+    /// `text` shortened to at most `width` characters, with an ellipsis
+    /// marking what was left out.
+    pub fn apply(self, text: &str, width: usize) -> String {
+        let chars: Vec<char> = text.chars().collect();
+        if chars.len() <= width {
+            return text.to_string();
+        }
+        if width == 0 {
+            return String::new();
+        }
+        let keep = width - 1;
+        let mut out: String = match self {
+            Truncation::End => chars[..keep].iter().collect(),
+            Truncation::Middle => {
+                let tail = keep / 2;
+                let head = keep - tail;
+                let mut s: String = chars[..head].iter().collect();
+                s.push(ELLIPSIS);
+                s.extend(&chars[chars.len() - tail..]);
+                return s;
+            }
+            Truncation::Word => {
+                // Cut after the last whole word, unless that drops most of the room
+                let cut = chars[..=keep].iter().rposition(|c| c.is_whitespace());
+                match cut {
+                    Some(i) if i * 2 >= keep => chars[..i].iter().collect(),
+                    _ => chars[..keep].iter().collect(),
+                }
+            }
+        };
+        out.truncate(out.trim_end().len());
+        out.push(ELLIPSIS);
+        out
+    }
+}
+
+/// Shorten `text` to `width` characters with the current [`Truncation`].
+pub fn truncate(text: &str, width: usize) -> String {
+    Truncation::current().apply(text, width)
+}
+
+/// Whether `text` needs shortening to fit `width` characters.
+pub fn overflows(text: &str, width: usize) -> bool {
+    text.chars().count() > width
+}
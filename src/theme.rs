@@ -1,19 +1,77 @@
 use std::path::PathBuf;
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock, RwLock};
 
 use ratatui::style::{Color, Modifier, Style};
 use serde::Deserialize;
 
-static THEME: OnceLock<Theme> = OnceLock::new();
+static THEME: RwLock<Option<Arc<Theme>>> = RwLock::new(None);
+static BACKGROUND: OnceLock<Background> = OnceLock::new();
 
-/// Get the active theme (loaded once on first call).
-pub fn current() -> &'static Theme {
-    THEME.get_or_init(|| Theme::load().unwrap_or_default())
+/// Names accepted by [`Theme::preset`].
+pub const PRESETS: &[&str] = &[
+    "catppuccin",
+    "default",
+    "dracula",
+    "gruvbox",
+    "light",
+    "nord",
+    "solarized-dark",
+    "solarized-light",
+    "tokyo-night",
+];
+
+/// Get the active theme (loaded from `theme.toml` on first call).
+pub fn current() -> Arc<Theme> {
+    if let Some(theme) = THEME.read().expect("theme lock").as_ref() {
+        return Arc::clone(theme);
+    }
+    let mut slot = THEME.write().expect("theme lock");
+    Arc::clone(slot.get_or_insert_with(|| Arc::new(Theme::load().unwrap_or_else(Theme::base))))
+}
+
+/// Make `theme` the active one, e.g. from `--theme` or `:theme`.
+pub fn set(theme: Theme) {
+    *THEME.write().expect("theme lock") = Some(Arc::new(theme));
+}
+
+/// Record the terminal's background, which picks the palette used when
+/// `theme.toml` names no preset. Call before the first [`current`].
+pub fn set_background(background: Background) {
+    let _ = BACKGROUND.set(background);
+}
+
+/// Whether the terminal draws on a light or a dark background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Background {
+    Dark,
+    Light,
+}
+
+impl Background {
+    /// From `COLORFGBG` as set by rxvt, Konsole and others: "fg;bg", where
+    /// a background of 7 or 15 (white) is light.
+    pub fn from_colorfgbg(value: &str) -> Option<Self> {
+        let bg: u8 = value.rsplit(';').next()?.trim().parse().ok()?;
+        Some(if matches!(bg, 7 | 15) { Self::Light } else { Self::Dark })
+    }
+
+    /// From a terminal's answer to the OSC 11 query, which holds the colour
+    /// as "rgb:rrrr/gggg/bbbb" with one to four hex digits per channel.
+    pub fn from_osc11(reply: &str) -> Option<Self> {
+        let rgb = &reply[reply.find("rgb:")? + 4..];
+        let mut channels = rgb.splitn(3, '/').map(|part| {
+            let digits: String = part.chars().take_while(char::is_ascii_hexdigit).collect();
+            let max = 16f64.powi(digits.len() as i32) - 1.0;
+            u32::from_str_radix(&digits, 16).ok().filter(|_| !digits.is_empty()).map(|v| v as f64 / max)
+        });
+        let (r, g, b) = (channels.next()??, channels.next()??, channels.next()??);
+        let luminance = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+        Some(if luminance > 0.5 { Self::Light } else { Self::Dark })
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct Theme {
-    #[allow(dead_code)]
     pub name: String,
     pub today: Style,
     pub selected: Style,
@@ -40,6 +98,14 @@ impl Default for Theme {
 }
 
 impl Theme {
+    /// The default palette for the terminal's background.
+    fn base() -> Self {
+        match BACKGROUND.get() {
+            Some(Background::Light) => Self::light(),
+            _ => Self::default(),
+        }
+    }
+
     pub fn load() -> Option<Self> {
         let path = config_path()?;
         if !path.exists() {
@@ -53,13 +119,49 @@ impl Theme {
     /// Get a built-in preset by name.
     pub fn preset(name: &str) -> Self {
         match name {
+            "catppuccin" => Self::catppuccin(),
             "dracula" => Self::dracula(),
             "gruvbox" => Self::gruvbox(),
+            "light" => Self::light(),
             "nord" => Self::nord(),
+            "solarized-dark" => Self::solarized_dark(),
+            "solarized-light" => Self::solarized_light(),
+            "tokyo-night" => Self::tokyo_night(),
             _ => Self::default(),
         }
     }
 
+    /// The default palette in terminal colors, readable on white.
+    fn light() -> Self {
+        Self {
+            name: "light".to_string(),
+            today: Style::default().fg(Color::Black).bg(Color::Yellow),
+            selected: Style::default().fg(Color::White).bg(Color::Blue),
+            header: Style::default().fg(Color::Black).add_modifier(Modifier::BOLD),
+            dim: Style::default().fg(Color::DarkGray),
+            border: Style::default().fg(Color::DarkGray),
+            status: Style::default().fg(Color::Black).bg(Color::Gray),
+            highlight: Style::default().bg(Color::Gray).add_modifier(Modifier::BOLD),
+        }
+    }
+
+    fn catppuccin() -> Self {
+        Self {
+            name: "catppuccin".to_string(),
+            today: Style::default().fg(Color::Rgb(30, 30, 46)).bg(Color::Rgb(249, 226, 175)), // yellow
+            selected: Style::default().fg(Color::Rgb(30, 30, 46)).bg(Color::Rgb(137, 180, 250)), // blue
+            header: Style::default().fg(Color::Rgb(205, 214, 244)).add_modifier(Modifier::BOLD),
+            dim: Style::default().fg(Color::Rgb(108, 112, 134)),
+            border: Style::default().fg(Color::Rgb(69, 71, 90)),
+            status: Style::default()
+                .fg(Color::Rgb(205, 214, 244))
+                .bg(Color::Rgb(49, 50, 68)),
+            highlight: Style::default()
+                .bg(Color::Rgb(69, 71, 90))
+                .add_modifier(Modifier::BOLD),
+        }
+    }
+
     fn dracula() -> Self {
         Self {
             name: "dracula".to_string(),
@@ -94,6 +196,58 @@ impl Theme {
         }
     }
 
+    fn solarized_dark() -> Self {
+        Self {
+            name: "solarized-dark".to_string(),
+            today: Style::default().fg(Color::Rgb(0, 43, 54)).bg(Color::Rgb(181, 137, 0)), // yellow
+            selected: Style::default().fg(Color::Rgb(0, 43, 54)).bg(Color::Rgb(42, 161, 152)), // cyan
+            header: Style::default().fg(Color::Rgb(147, 161, 161)).add_modifier(Modifier::BOLD),
+            dim: Style::default().fg(Color::Rgb(88, 110, 117)),
+            border: Style::default().fg(Color::Rgb(88, 110, 117)),
+            status: Style::default()
+                .fg(Color::Rgb(147, 161, 161))
+                .bg(Color::Rgb(7, 54, 66)),
+            highlight: Style::default()
+                .bg(Color::Rgb(7, 54, 66))
+                .add_modifier(Modifier::BOLD),
+        }
+    }
+
+    /// For terminals with a light background: dark text, pale bars.
+    fn solarized_light() -> Self {
+        Self {
+            name: "solarized-light".to_string(),
+            today: Style::default().fg(Color::Rgb(253, 246, 227)).bg(Color::Rgb(181, 137, 0)), // yellow
+            selected: Style::default().fg(Color::Rgb(253, 246, 227)).bg(Color::Rgb(38, 139, 210)), // blue
+            header: Style::default().fg(Color::Rgb(7, 54, 66)).add_modifier(Modifier::BOLD),
+            dim: Style::default().fg(Color::Rgb(147, 161, 161)),
+            border: Style::default().fg(Color::Rgb(147, 161, 161)),
+            status: Style::default()
+                .fg(Color::Rgb(88, 110, 117))
+                .bg(Color::Rgb(238, 232, 213)),
+            highlight: Style::default()
+                .bg(Color::Rgb(238, 232, 213))
+                .add_modifier(Modifier::BOLD),
+        }
+    }
+
+    fn tokyo_night() -> Self {
+        Self {
+            name: "tokyo-night".to_string(),
+            today: Style::default().fg(Color::Rgb(26, 27, 38)).bg(Color::Rgb(224, 175, 104)), // yellow
+            selected: Style::default().fg(Color::Rgb(26, 27, 38)).bg(Color::Rgb(122, 162, 247)), // blue
+            header: Style::default().fg(Color::Rgb(192, 202, 245)).add_modifier(Modifier::BOLD),
+            dim: Style::default().fg(Color::Rgb(86, 95, 137)),
+            border: Style::default().fg(Color::Rgb(65, 72, 104)),
+            status: Style::default()
+                .fg(Color::Rgb(192, 202, 245))
+                .bg(Color::Rgb(41, 46, 66)),
+            highlight: Style::default()
+                .bg(Color::Rgb(41, 46, 66))
+                .add_modifier(Modifier::BOLD),
+        }
+    }
+
     fn nord() -> Self {
         Self {
             name: "nord".to_string(),
@@ -148,7 +302,7 @@ impl ThemeConfig {
             .preset
             .as_deref()
             .map(Theme::preset)
-            .unwrap_or_default();
+            .unwrap_or_else(Theme::base);
 
         // Override individual colors
         if let Some(c) = self.today_fg.as_deref().and_then(parse_color) {
@@ -0,0 +1,99 @@
+//! Short-lived messages stacked above the status bar.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// How long a toast stays up.
+pub const TOAST_DURATION: Duration = Duration::from_secs(4);
+
+/// Errors stay up longer, to give time to read them.
+pub const ERROR_DURATION: Duration = Duration::from_secs(8);
+
+/// Most toasts shown at once; older ones are dropped.
+pub const MAX_TOASTS: usize = 3;
+
+/// What kind of message a toast carries, which sets its color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Toast {
+    pub text: String,
+    pub severity: Severity,
+    pub until: Instant,
+}
+
+/// Messages in the order they were raised, oldest first.
+#[derive(Debug, Clone, Default)]
+pub struct Toasts {
+    queue: VecDeque<Toast>,
+}
+
+impl Toasts {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show `text` from `now`. Repeating the newest message restarts its
+    /// timer instead of stacking a copy.
+    pub fn push_at(&mut self, severity: Severity, text: impl Into<String>, now: Instant) {
+        let text = text.into();
+        let duration = match severity {
+            Severity::Error => ERROR_DURATION,
+            _ => TOAST_DURATION,
+        };
+        if let Some(last) = self.queue.back_mut().filter(|t| t.text == text && t.severity == severity) {
+            last.until = now + duration;
+            return;
+        }
+        self.queue.push_back(Toast { text, severity, until: now + duration });
+        while self.queue.len() > MAX_TOASTS {
+            self.queue.pop_front();
+        }
+    }
+
+    pub fn push(&mut self, severity: Severity, text: impl Into<String>) {
+        self.push_at(severity, text, Instant::now());
+    }
+
+    pub fn info(&mut self, text: impl Into<String>) {
+        self.push(Severity::Info, text);
+    }
+
+    pub fn success(&mut self, text: impl Into<String>) {
+        self.push(Severity::Success, text);
+    }
+
+    pub fn warn(&mut self, text: impl Into<String>) {
+        self.push(Severity::Warning, text);
+    }
+
+    pub fn error(&mut self, text: impl Into<String>) {
+        self.push(Severity::Error, text);
+    }
+
+    /// Drop toasts whose time is up at `now`. Returns whether any were.
+    pub fn expire(&mut self, now: Instant) -> bool {
+        let before = self.queue.len();
+        self.queue.retain(|t| t.until > now);
+        self.queue.len() != before
+    }
+
+    pub fn clear(&mut self) {
+        self.queue.clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Toasts on screen, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &Toast> {
+        self.queue.iter()
+    }
+}
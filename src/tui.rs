@@ -1,6 +1,10 @@
-use std::io::{self, Stdout};
+use std::fs::{File, OpenOptions};
+use std::io::{self, IsTerminal, Read, Stdout, Write};
+use std::os::fd::AsRawFd;
+use std::time::{Duration, Instant};
 
 use crossterm::{
+    event::{DisableFocusChange, EnableFocusChange},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -8,14 +12,68 @@ use ratatui::prelude::*;
 
 pub type Tui = Terminal<CrosstermBackend<Stdout>>;
 
+/// Longest wait for the terminal to answer [`query_background`].
+const QUERY_TIMEOUT: Duration = Duration::from_millis(200);
+
 pub fn init() -> io::Result<Tui> {
-    execute!(io::stdout(), EnterAlternateScreen)?;
+    execute!(io::stdout(), EnterAlternateScreen, EnableFocusChange)?;
     enable_raw_mode()?;
     Terminal::new(CrosstermBackend::new(io::stdout()))
 }
 
 pub fn restore() -> io::Result<()> {
-    execute!(io::stdout(), LeaveAlternateScreen)?;
+    execute!(io::stdout(), DisableFocusChange, LeaveAlternateScreen)?;
     disable_raw_mode()?;
     Ok(())
 }
+
+/// Ask the terminal for its background colour (OSC 11) and return the raw
+/// answer. A device attributes request follows, which every terminal
+/// answers, so the read ends even where OSC 11 isn't supported. Call
+/// before [`init`], while nothing else reads input.
+pub fn query_background() -> Option<String> {
+    if !io::stdout().is_terminal() {
+        return None;
+    }
+    let mut tty = OpenOptions::new().read(true).write(true).open("/dev/tty").ok()?;
+    enable_raw_mode().ok()?;
+    let reply = tty
+        .write_all(b"\x1b]11;?\x07\x1b[c")
+        .and_then(|_| tty.flush())
+        .ok()
+        .and_then(|_| read_reply(&mut tty));
+    let _ = disable_raw_mode();
+    reply.map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Read the answer to [`query_background`] on this thread, waiting for each
+/// byte with `select` so nothing is left reading the terminal after
+/// [`QUERY_TIMEOUT`]. `None` when the answer doesn't finish in time.
+fn read_reply(tty: &mut File) -> Option<Vec<u8>> {
+    let deadline = Instant::now() + QUERY_TIMEOUT;
+    let fd = tty.as_raw_fd();
+    let mut reply = Vec::new();
+    let mut byte = [0u8; 1];
+    // The device attributes answer, ESC [ ? ... c, comes last
+    while !(reply.last() == Some(&b'c') && reply.windows(3).any(|w| w == b"\x1b[?")) {
+        let left = deadline.saturating_duration_since(Instant::now());
+        let mut timeout = libc::timeval {
+            tv_sec: left.as_secs() as libc::time_t,
+            tv_usec: left.subsec_micros() as libc::suseconds_t,
+        };
+        // `select` rather than `poll`, which macOS doesn't support on
+        // /dev/tty. SAFETY: `fd` is open, and `fds` and `timeout` outlive
+        // the call.
+        let ready = unsafe {
+            let mut fds: libc::fd_set = std::mem::zeroed();
+            libc::FD_ZERO(&mut fds);
+            libc::FD_SET(fd, &mut fds);
+            libc::select(fd + 1, &mut fds, std::ptr::null_mut(), std::ptr::null_mut(), &mut timeout)
+        };
+        if ready <= 0 || tty.read(&mut byte).ok()? != 1 {
+            return None;
+        }
+        reply.push(byte[0]);
+    }
+    Some(reply)
+}
@@ -0,0 +1,659 @@
+use std::time::Instant;
+
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::Frame;
+
+use crate::app::{App, InputMode, ViewMode};
+use crate::calendar::time::format_time;
+use crate::calendar::Access;
+use crate::components;
+use crate::config::{CustomAction, EventShortcut, IdleMode};
+use crate::layout;
+use crate::theme;
+
+/// Frames of the spinner shown while loading or syncing.
+const SPINNER: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// Cells in the status bar progress bar of a running job.
+const JOB_BAR_WIDTH: usize = 10;
+
+/// Spinner frame for something running since `since`.
+fn spinner(since: Instant) -> &'static str {
+    SPINNER[(since.elapsed().as_millis() / 100) as usize % SPINNER.len()]
+}
+
+/// Loading screen shown while connecting to the calendar, before the app
+/// exists. `stage` says what is being waited on.
+pub fn draw_splash(frame: &mut Frame, stage: &str, since: Instant) {
+    use ratatui::layout::Alignment;
+    use ratatui::style::{Modifier, Style};
+    use ratatui::text::Line;
+    use ratatui::widgets::Paragraph;
+
+    let area = frame.area();
+    let lines = vec![
+        Line::styled("calendar-tui", Style::default().add_modifier(Modifier::BOLD)),
+        Line::from(""),
+        Line::styled(format!("{} {}\u{2026}", spinner(since), stage), theme::current().dim),
+    ];
+    let top = area.height.saturating_sub(lines.len() as u16) / 2;
+    let text_area = Rect::new(area.x, area.y + top, area.width, area.height - top);
+    frame.render_widget(Paragraph::new(lines).alignment(Alignment::Center), text_area);
+}
+
+/// Render one frame of the whole application.
+pub fn draw(frame: &mut Frame, app: &App) {
+    let area = frame.area();
+    let w = area.width;
+
+    if !app.usable() {
+        let reason = match app.permissions.events {
+            Access::Restricted => "Calendar access is restricted by a device management profile.\n\n\
+                                   Ask your administrator to allow it.",
+            Access::NotDetermined => "Calendar access was not granted.\n\n\
+                                      Restart calendar-tui to be asked again.",
+            _ => "Calendar access denied.\n\n\
+                  Please grant access in:\n\
+                  System Settings > Privacy & Security > Calendars",
+        };
+        let msg = ratatui::widgets::Paragraph::new(format!("{}\n\nPress 'q' to quit.", reason))
+            .style(theme::current().header);
+        frame.render_widget(msg, area);
+        if let Some(ref health) = app.health {
+            components::HealthPanel::render(frame, area, health);
+        }
+        return;
+    }
+
+    if let Some(ev) = app.alert_queue.front() {
+        let waiting = app.alert_queue.len() - 1;
+        components::StartAlert::render(frame, area, ev, app.config.snooze_minutes.max(1), waiting);
+        return;
+    }
+
+    if app.idle && app.config.idle_mode == IdleMode::Blank {
+        let msg = ratatui::widgets::Paragraph::new("Press any key")
+            .style(theme::current().dim)
+            .alignment(ratatui::layout::Alignment::Center);
+        let y = area.y + area.height / 2;
+        frame.render_widget(msg, Rect::new(area.x, y, area.width, 1.min(area.height)));
+        return;
+    }
+
+    // Main layout: content + optional banner + status bar
+    let banner = app.banner_text();
+    let banner_h = if banner.is_some() { 1 } else { 0 };
+    let layout = Layout::vertical([
+        Constraint::Min(1),
+        Constraint::Length(banner_h),
+        Constraint::Length(1),
+    ])
+    .split(area);
+
+    let content_area = layout[0];
+    if let Some(banner) = banner {
+        let style = ratatui::style::Style::default()
+            .fg(ratatui::style::Color::Black)
+            .bg(ratatui::style::Color::Yellow);
+        frame.render_widget(ratatui::widgets::Paragraph::new(format!(" {}", banner)).style(style), layout[1]);
+    }
+
+    // Render main view
+    match app.view_mode {
+        ViewMode::Month => render_month_layout(frame, content_area, app, w),
+        ViewMode::Week => {
+            components::WeekView::render(
+                frame,
+                content_area,
+                app.selected_date,
+                app.today,
+                app.week_start(),
+                &app.week_events,
+                app.selected_event_index().and_then(|i| app.day_events.get(i)),
+                app.grid_hours(),
+                app.config.weekend_mode(),
+                &app.week_lanes(),
+                app.week_hidden,
+                app.config.show_week_numbers,
+                app.config.busy_limit(),
+            );
+        }
+        ViewMode::Day => {
+            let progress = if app.day_total_reminders > 0 {
+                Some((app.day_completed_count, app.day_total_reminders))
+            } else {
+                None
+            };
+            if app.config.day_timeline {
+                components::DayTimeline::render(
+                    frame,
+                    content_area,
+                    app.selected_date,
+                    &app.day_events,
+                    app.selected_event_index().and_then(|i| app.day_events.get(i)),
+                    app.grid_hours(),
+                    app.day_hidden(),
+                    app.config.busy_limit(),
+                );
+            } else {
+                components::DayView::render(
+                    frame,
+                    content_area,
+                    app.selected_date,
+                    &app.day_items(),
+                    app.day_scroll,
+                    progress,
+                    app.day_hidden(),
+                    None,
+                    app.config.busy_limit(),
+                );
+            }
+        }
+    }
+
+    // Render event form overlay
+    if let Some(ref form) = app.form_state {
+        components::EventForm::render(frame, area, form, &app.calendars);
+    }
+
+    // Render reminder edit form overlay
+    if let Some(ref form) = app.reminder_form {
+        components::ReminderForm::render(frame, area, form, &app.reminder_calendars);
+    }
+
+    // Render detail popup overlay
+    if let Some(ref detail) = app.detail_item {
+        let left = match detail {
+            layout::DayAction::Event(idx) => {
+                app.day_events.get(*idx).and_then(|ev| app.minutes_left(ev, chrono::Local::now()))
+            }
+            _ => None,
+        };
+        components::day_view::render_detail_popup(
+            frame, area, detail, &app.day_events, &app.day_reminders, left,
+        );
+    }
+
+    // Render status panel
+    if let Some(ref health) = app.health {
+        components::HealthPanel::render(frame, area, health);
+    }
+
+    // Render the day's reminder checklist
+    if let Some(selected) = app.reminder_checklist {
+        components::ReminderChecklist::render(frame, area, app.selected_date, &app.checklist_reminders(), selected);
+    }
+
+    // Render calendar visibility popup
+    if let Some(selected) = app.calendar_picker {
+        components::CalendarList::render(
+            frame, area, &app.calendars, &app.hidden_calendars, selected,
+        );
+    }
+
+    // Render bulk review
+    if let Some(ref review) = app.event_review {
+        components::EventReview::render(frame, area, review);
+    }
+
+    // Render recurring reminder choice
+    if app.recurring_prompt.is_some() {
+        components::Prompt::render(
+            frame,
+            area,
+            "Recurring Reminder",
+            "This reminder repeats.",
+            &[
+                ("c", "Complete this occurrence"),
+                ("D", "Delete the whole series"),
+                ("Esc", "Cancel"),
+            ],
+        );
+    }
+
+    if let Some(ref ev) = app.delete_prompt {
+        let when = format!("{} {}", ev.start.format("%a %b %d"), ev.duration_display());
+        components::Prompt::render(
+            frame,
+            area,
+            "Delete Event",
+            &format!("{}\n{}", ev.title, when),
+            &[("y", "Delete"), ("n", "Keep")],
+        );
+    }
+
+    if let Some(ref invite) = app.invite_prompt {
+        let n = invite.attendees.len();
+        components::Prompt::render(
+            frame,
+            area,
+            "Send Invite",
+            &format!(
+                "Export {} as an .ics invite for {} attendee{}?",
+                invite.event.title,
+                n,
+                if n == 1 { "" } else { "s" }
+            ),
+            &[("y", "Export and copy the path"), ("Esc", "Skip")],
+        );
+    }
+
+    if let Some(ref focus) = app.focus_prompt {
+        let mut message = format!(
+            "Block {} on {} as busy.",
+            focus.date.format("%a %b %d"),
+            focus.calendar.title
+        );
+        if !focus.conflicts.is_empty() {
+            message.push_str("\nIt would shadow:");
+            for ev in &focus.conflicts {
+                message.push_str(&format!("\n  {} {}", format_time(ev.start), ev.title));
+            }
+        }
+        components::Prompt::render(
+            frame,
+            area,
+            "Focus Day",
+            &message,
+            &[("y", "Create the focus day"), ("Esc", "Cancel")],
+        );
+    }
+
+    // Render help overlay
+    if app.show_help {
+        render_help(frame, area, &app.config.actions, &app.config.new_event_shortcuts);
+    }
+
+    if let Some(ref error) = app.error {
+        components::ErrorPopup::render(frame, area, error);
+    }
+
+    components::ToastStack::render(frame, content_area, &app.toasts);
+
+    // Status bar
+    render_status_bar(frame, layout[2], app, w);
+}
+
+fn render_month_layout(frame: &mut Frame, area: Rect, app: &App, total_width: u16) {
+    if total_width < 60 {
+        components::MonthView::render(
+            frame, area, app.selected_date, app.today, &app.day_event_counts, &app.day_reminder_colors,
+            &app.month_events, app.week_row_selected, app.config.show_week_numbers, app.config.busy_limit(),
+        );
+    } else {
+        let month_w = app.month_pane_width(total_width);
+        let content = Layout::horizontal([
+            Constraint::Length(month_w),
+            Constraint::Min(20),
+        ])
+        .split(area);
+
+        components::MonthView::render(
+            frame, content[0], app.selected_date, app.today, &app.day_event_counts, &app.day_reminder_colors,
+            &app.month_events, app.week_row_selected, app.config.show_week_numbers, app.config.busy_limit(),
+        );
+
+        let progress = if app.day_total_reminders > 0 {
+            Some((app.day_completed_count, app.day_total_reminders))
+        } else {
+            None
+        };
+        let (week_event_count, week_reminder_count) = app.week_summary();
+        let footer = format!(
+            "This week: {} event{} \u{00b7} {} reminder{}",
+            week_event_count,
+            if week_event_count == 1 { "" } else { "s" },
+            week_reminder_count,
+            if week_reminder_count == 1 { "" } else { "s" },
+        );
+        components::DayView::render(
+            frame,
+            content[1],
+            app.selected_date,
+            &app.day_items(),
+            app.day_scroll,
+            progress,
+            app.day_hidden(),
+            Some(&footer),
+            app.config.busy_limit(),
+        );
+    }
+}
+
+fn render_status_bar(frame: &mut Frame, area: Rect, app: &App, w: u16) {
+    use ratatui::text::{Line, Span};
+    use ratatui::widgets::Paragraph;
+
+    if let Some(ref line) = app.command_line {
+        components::CommandLine::render(frame, area, line);
+        return;
+    }
+
+    let w = w as usize;
+
+    let mode_str = match app.view_mode {
+        ViewMode::Month => "[1]Month",
+        ViewMode::Week => "[2]Week",
+        ViewMode::Day => "[3]Day",
+    };
+
+    let focus_indicator = match app.input_mode {
+        InputMode::Form => " [New Event]",
+        InputMode::ReminderForm => " [Edit Reminder]",
+        InputMode::Command => "",
+        InputMode::Normal => "",
+        _ => "",
+    };
+
+    // Context-aware hints; messages show as toasts above the bar
+    let right_text = if app.idle {
+        " Idle \u{2014} press any key ".to_string()
+    } else if app.week_row_selected && app.view_mode == ViewMode::Month {
+        " Week: Enter:Open x:Export Esc:Cancel".to_string()
+    } else {
+        match app.view_mode {
+            ViewMode::Month if w >= 80 => {
+                " hjkl:Date \u{2191}\u{2193}:Scroll [/]:Mon t:Today Enter:Detail Sp:Toggle n:New ?:Help q:Quit".to_string()
+            }
+            ViewMode::Month if w >= 50 => {
+                " hjkl:Date \u{2191}\u{2193}:Scroll Enter:Detail n:New q:Quit".to_string()
+            }
+            ViewMode::Day if w >= 80 => {
+                " hjkl:Nav [/]:Mon t:Today Enter:Detail Sp:Toggle n:New d:Del ?:Help q:Quit".to_string()
+            }
+            ViewMode::Day if w >= 50 => {
+                " jk:Scroll Enter:Detail Sp:Toggle n:New q:Quit".to_string()
+            }
+            ViewMode::Week if w >= 70 => {
+                " hl:Day jk:Event HL:Week Enter:Detail n:New d:Del ?:Help q:Quit".to_string()
+            }
+            ViewMode::Week if w >= 50 => {
+                " arrows:Nav n:New q:Quit".to_string()
+            }
+            _ => " ?:Help q:Quit".to_string(),
+        }
+    };
+
+    let filter_indicator = app
+        .active_filter_name()
+        .map(|name| format!(" [Filter: {}]", name))
+        .unwrap_or_default();
+    let reminders_indicator = if app.show_reminders { "" } else { " [No reminders]" };
+    let count_indicator = match (app.pending_count, app.pending_z) {
+        _ if app.pending_n => {
+            let keys: Vec<String> = app
+                .config
+                .new_event_shortcuts
+                .iter()
+                .map(|s| format!("{}:{}", s.key, s.calendar))
+                .collect();
+            format!(" n [{} n:Any]", keys.join(" "))
+        }
+        (_, true) => " z".to_string(),
+        (Some(n), false) => format!(" {}", n),
+        (None, false) => String::new(),
+    };
+    let meeting_indicator = layout::ongoing(&app.today_events, chrono::Local::now())
+        .filter(|_| !app.idle)
+        .map(|(ev, left)| format!(" [{} \u{00b7} {}]", ev.title, layout::time_left_label(left)))
+        .unwrap_or_default();
+    let cut_indicator = app
+        .cut_event
+        .as_ref()
+        .map(|ev| format!(" [Moving: {}]", ev.title))
+        .unwrap_or_default();
+    let gaps_indicator = if app.data_gaps.is_empty() { "" } else { " [Possibly incomplete :status]" };
+    let sync_indicator = match (app.syncing_since, app.synced_at) {
+        (Some(since), _) => format!(" {} Syncing", spinner(since)),
+        (None, Some(at)) if w >= 80 => format!(" Synced {}", format_time(at)),
+        (None, _) => String::new(),
+    };
+    let job_indicator = app
+        .job
+        .as_ref()
+        .map(|job| {
+            let filled = (job.ratio() * JOB_BAR_WIDTH as f64).round() as usize;
+            format!(
+                " {} {}{} Esc:Cancel",
+                job.progress_label(),
+                "\u{2588}".repeat(filled),
+                "\u{2591}".repeat(JOB_BAR_WIDTH.saturating_sub(filled))
+            )
+        })
+        .unwrap_or_default();
+    let left = format!(
+        " {}{}{}{}{}{}{}{}{}{} ",
+        mode_str, focus_indicator, meeting_indicator, filter_indicator, reminders_indicator, cut_indicator,
+        count_indicator, gaps_indicator, sync_indicator, job_indicator
+    );
+    let padding_len = w.saturating_sub(left.len() + right_text.len());
+    let padding = " ".repeat(padding_len);
+
+    let line = Line::from(vec![
+        Span::styled(left, theme::current().status),
+        Span::styled(padding, theme::current().status),
+        Span::styled(right_text, theme::current().status),
+    ]);
+
+    let mut bar = Paragraph::new(line).style(theme::current().status);
+    if app.flashing() {
+        bar = bar.style(ratatui::style::Modifier::REVERSED);
+    }
+    frame.render_widget(bar, area);
+}
+
+fn render_help(frame: &mut Frame, area: Rect, custom: &[CustomAction], shortcuts: &[EventShortcut]) {
+    use ratatui::style::{Color, Modifier, Style};
+    use ratatui::text::{Line, Span};
+    use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+
+    let popup_w = area.width.min(52).max(30);
+    let shortcut_rows = if shortcuts.is_empty() { 0 } else { shortcuts.len() as u16 + 2 };
+    let popup_h = area.height.clamp(12, 50 + custom.len() as u16 + 2 + shortcut_rows);
+    let x = area.x + (area.width.saturating_sub(popup_w)) / 2;
+    let y = area.y + (area.height.saturating_sub(popup_h)) / 2;
+    let popup_area = Rect::new(x, y, popup_w, popup_h);
+
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(" Keybindings ")
+        .title_style(Style::default().fg(Color::Green).add_modifier(Modifier::BOLD))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Green));
+
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let key_style = Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+    let desc_style = Style::default();
+    let section_style = Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+
+    let mut lines = vec![
+        Line::from(Span::styled("Navigation", section_style)),
+        Line::from(vec![
+            Span::styled("  h/l ", key_style),
+            Span::styled("or ", theme::current().dim),
+            Span::styled("\u{2190}/\u{2192}  ", key_style),
+            Span::styled("Previous/next day", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  j/k ", key_style),
+            Span::styled("or ", theme::current().dim),
+            Span::styled("\u{2191}/\u{2193}  ", key_style),
+            Span::styled("Scroll day list / week events", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  j/k       ", key_style),
+            Span::styled("Next/previous week (month view)", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  H/L       ", key_style),
+            Span::styled("Previous/next week", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  [/]       ", key_style),
+            Span::styled("Previous/next month", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  t         ", key_style),
+            Span::styled("Jump to today", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  5l, 3]    ", key_style),
+            Span::styled("Repeat a motion N times", desc_style),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled("Views", section_style)),
+        Line::from(vec![
+            Span::styled("  1/2/3     ", key_style),
+            Span::styled("Month / Week / Day view", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  C-\u{2191}/\u{2193}     ", key_style),
+            Span::styled("Scroll hours (week view, timeline)", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  @14       ", key_style),
+            Span::styled("Jump to an hour of the day", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  w         ", key_style),
+            Span::styled("Weekend columns (week view)", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  a         ", key_style),
+            Span::styled("Lanes per account (week view)", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  T         ", key_style),
+            Span::styled("Timeline layout (day view)", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  V         ", key_style),
+            Span::styled("Select week (month view): Enter/x", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  < / >     ", key_style),
+            Span::styled("Narrow/widen month grid", desc_style),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled("Actions", section_style)),
+        Line::from(vec![
+            Span::styled("  Enter     ", key_style),
+            Span::styled("View event/reminder details", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  e         ", key_style),
+            Span::styled("Edit reminder (in details)", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  Space     ", key_style),
+            Span::styled("Toggle reminder; checklist on days with only reminders", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  zr        ", key_style),
+            Span::styled("Hide/show reminders", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  z1-9 / z0 ", key_style),
+            Span::styled("Apply a saved filter / clear it", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  zs / zS   ", key_style),
+            Span::styled("Save the screen as text / with colors", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  n         ", key_style),
+            Span::styled("Create new event", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  N         ", key_style),
+            Span::styled("New event an hour from now", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  d         ", key_style),
+            Span::styled("Delete selected event", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  y / D     ", key_style),
+            Span::styled("Yank event / copy it to this day", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  = / -     ", key_style),
+            Span::styled("Nudge event 15m later/earlier", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  + / _     ", key_style),
+            Span::styled("Nudge event an hour later/earlier", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  x / p     ", key_style),
+            Span::styled("Pick up event / move it to this day", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  p         ", key_style),
+            Span::styled("Pin/unpin event to top of day", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  zT        ", key_style),
+            Span::styled("Switch 24h/12h times", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  r         ", key_style),
+            Span::styled("Refresh this view", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  R         ", key_style),
+            Span::styled("Sync all accounts and reload", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  c         ", key_style),
+            Span::styled("Show/hide calendars", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  u         ", key_style),
+            Span::styled("Undo the last :shift", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  :         ", key_style),
+            Span::styled("Command line (Tab completes)", desc_style),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("  q", key_style),
+            Span::styled(" / ", theme::current().dim),
+            Span::styled("Esc     ", key_style),
+            Span::styled("Quit / close popup", desc_style),
+        ]),
+    ];
+    if !custom.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled("Custom actions", section_style)));
+        for action in custom {
+            let key = match action.key {
+                Some(c) => c.to_string(),
+                None => format!(":{}", action.name),
+            };
+            let desc = if action.description.is_empty() { &action.command } else { &action.description };
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {:<10}", key), key_style),
+                Span::styled(desc.clone(), desc_style),
+            ]));
+        }
+    }
+
+    if !shortcuts.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled("New event shortcuts", section_style)));
+        for shortcut in shortcuts {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  n{:<9}", shortcut.key), key_style),
+                Span::styled(format!("New event in {}", shortcut.calendar), desc_style),
+            ]));
+        }
+    }
+
+    let para = Paragraph::new(lines).wrap(Wrap { trim: false });
+    frame.render_widget(para, inner);
+}
@@ -0,0 +1,60 @@
+use calendar_tui::agenda::{Agenda, ItemKind};
+use calendar_tui::calendar::{CalendarEvent, Reminder};
+use chrono::{Local, NaiveDate, TimeZone};
+
+fn date(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, 3, day).unwrap()
+}
+
+fn event(title: &str, day: u32, h1: u32, end_day: u32, h2: u32) -> CalendarEvent {
+    let start = Local.with_ymd_and_hms(2026, 3, day, h1, 0, 0).unwrap();
+    let end = Local.with_ymd_and_hms(2026, 3, end_day, h2, 0, 0).unwrap();
+    CalendarEvent::builder(title, title, start, end).build()
+}
+
+#[test]
+fn items_by_day_then_time_with_due_reminders_last() {
+    let events = vec![event("Lunch", 9, 12, 9, 13), event("Standup", 9, 9, 9, 10), event("Trip", 10, 18, 11, 10)];
+    let reminders = vec![
+        Reminder::builder("r1", "Pay rent").due_date(Local.with_ymd_and_hms(2026, 3, 9, 0, 0, 0).unwrap()).build(),
+        Reminder::builder("r2", "Done already")
+            .due_date(Local.with_ymd_and_hms(2026, 3, 9, 0, 0, 0).unwrap())
+            .completed(true)
+            .build(),
+        Reminder::builder("r3", "Undated").build(),
+    ];
+
+    let agenda = Agenda::build(date(9), 3, &events, &reminders);
+    let titles: Vec<(NaiveDate, &str)> = agenda.items.iter().map(|i| (i.date, i.title.as_str())).collect();
+    assert_eq!(
+        titles,
+        vec![
+            (date(9), "Standup"),
+            (date(9), "Lunch"),
+            (date(9), "Pay rent"),
+            (date(10), "Trip"),
+            (date(11), "Trip"),
+        ]
+    );
+    assert_eq!(agenda.items[2].kind, ItemKind::Reminder);
+    assert_eq!(agenda.items[2].time, "Due");
+
+    let plain = agenda.to_plain();
+    assert_eq!(plain.lines().count(), 5);
+    assert!(plain.lines().all(|l| l.starts_with("2026-03-")));
+}
+
+#[test]
+fn empty_agenda_says_so() {
+    let agenda = Agenda::build(date(9), 7, &[], &[]);
+    assert_eq!(agenda.to_text(), "Nothing scheduled\n");
+    assert_eq!(agenda.to_plain(), "");
+    assert_eq!(agenda.to_json().unwrap().trim(), "[]");
+}
+
+#[test]
+fn long_and_late_ranges_stop_in_time() {
+    assert!(Agenda::build(NaiveDate::MAX, 10, &[], &[]).items.is_empty());
+    let events = vec![event("Standup", 9, 9, 9, 10)];
+    assert_eq!(Agenda::build(date(1), u32::MAX, &events, &[]).items.len(), 1);
+}
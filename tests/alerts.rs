@@ -0,0 +1,48 @@
+use calendar_tui::alerts::Alerts;
+use calendar_tui::calendar::CalendarEvent;
+use chrono::{DateTime, Duration, Local, TimeZone};
+
+fn at(hour: u32, min: u32) -> DateTime<Local> {
+    Local.with_ymd_and_hms(2026, 3, 10, hour, min, 0).unwrap()
+}
+
+fn event(id: &str, hour: u32, min: u32) -> CalendarEvent {
+    let start = at(hour, min);
+    CalendarEvent::builder(id, id, start, start + Duration::minutes(30)).build()
+}
+
+fn ids(events: &[CalendarEvent]) -> Vec<&str> {
+    events.iter().map(|ev| ev.id.as_str()).collect()
+}
+
+#[test]
+fn alerts_events_starting_since_the_last_check() {
+    let mut alerts = Alerts::new(at(9, 0));
+    let events = vec![event("early", 8, 30), event("standup", 9, 5), event("later", 10, 0)];
+    assert!(alerts.due(&events, at(9, 1)).is_empty());
+    assert_eq!(ids(&alerts.due(&events, at(9, 5))), vec!["standup"]);
+    // Already alerted
+    assert!(alerts.due(&events, at(9, 6)).is_empty());
+    assert_eq!(ids(&alerts.due(&events, at(10, 0))), vec!["later"]);
+}
+
+#[test]
+fn skips_all_day_and_declined_events() {
+    let mut alerts = Alerts::new(at(8, 59));
+    let all_day = CalendarEvent::builder("holiday", "Holiday", at(9, 0), at(9, 0) + Duration::days(1))
+        .all_day(true)
+        .build();
+    let declined = CalendarEvent::builder("sync", "Sync", at(9, 0), at(9, 30)).declined(true).build();
+    assert!(alerts.due(&[all_day, declined], at(9, 1)).is_empty());
+}
+
+#[test]
+fn snoozed_alerts_return_when_due() {
+    let mut alerts = Alerts::new(at(9, 0));
+    let events = vec![event("review", 9, 1)];
+    let due = alerts.due(&events, at(9, 1));
+    alerts.snooze(due[0].clone(), at(9, 6));
+    assert!(alerts.due(&events, at(9, 5)).is_empty());
+    assert_eq!(ids(&alerts.due(&events, at(9, 6))), vec!["review"]);
+    assert!(alerts.due(&events, at(9, 7)).is_empty());
+}
@@ -0,0 +1,60 @@
+use calendar_tui::app::App;
+use calendar_tui::calendar::{CalendarInfo, FaultyBackend, MemoryBackend};
+use calendar_tui::command::History;
+use calendar_tui::components::event_form::EventFormState;
+use calendar_tui::config::Config;
+use calendar_tui::state::ViewState;
+use chrono::{Local, NaiveTime};
+
+fn app() -> (App, std::sync::Arc<calendar_tui::calendar::Faults>) {
+    let work = CalendarInfo::builder("work", "Work").build();
+    let backend = FaultyBackend::new(MemoryBackend::new().with_calendars(vec![work], Vec::new()));
+    let faults = backend.faults();
+    let mut app = App::with_backend_and_config(Box::new(backend), Config::default(), ViewState::default(), History::default())
+        .unwrap();
+    app.close_health();
+    (app, faults)
+}
+
+fn fill_form(app: &mut App) {
+    let today = Local::now().date_naive();
+    let mut form = EventFormState::with_times(
+        today.and_time(NaiveTime::from_hms_opt(9, 0, 0).unwrap()),
+        today.and_time(NaiveTime::from_hms_opt(10, 0, 0).unwrap()),
+    );
+    form.title = "Planning".to_string();
+    app.form_state = Some(form);
+}
+
+#[test]
+fn failed_save_shows_the_error_and_keeps_the_form_for_a_retry() {
+    let (mut app, faults) = app();
+    faults.fail_every(1);
+    fill_form(&mut app);
+    app.submit_event_form();
+    let error = app.error.as_ref().expect("error popup");
+    assert_eq!(error.title, "Creating event");
+    assert!(error.text().contains("Injected failure"));
+    assert!(app.form_state.is_some());
+
+    app.close_error();
+    faults.fail_every(0);
+    app.submit_event_form();
+    assert!(app.error.is_none());
+    assert!(app.form_state.is_none());
+    assert!(app.day_events.iter().any(|e| e.title == "Planning"));
+}
+
+#[test]
+fn revoked_access_is_noticed_without_a_restart() {
+    let (mut app, faults) = app();
+    assert!(app.usable());
+    faults.revoke();
+    app.tick();
+    assert!(!app.usable());
+    assert!(app.day_events.is_empty());
+
+    faults.restore();
+    app.tick();
+    assert!(app.usable());
+}
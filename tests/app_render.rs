@@ -0,0 +1,123 @@
+use calendar_tui::app::{App, ViewMode};
+use calendar_tui::calendar::{CalendarEvent, CalendarInfo, MemoryBackend, Reminder, Zone};
+use calendar_tui::command::History;
+use calendar_tui::config::{Config, Filter};
+use calendar_tui::input;
+use calendar_tui::snapshot::{render, SnapshotFormat};
+use calendar_tui::state::ViewState;
+use calendar_tui::ui;
+use chrono::{Duration, Local, NaiveDate, TimeZone};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+
+fn date(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 3, day).unwrap()
+}
+
+/// An app over fixed in-memory data, on Monday 10 March 2025.
+fn app(view: ViewMode) -> App {
+    let work = CalendarInfo::builder("work", "Work").build();
+    let home = CalendarInfo::builder("home", "Home").build();
+    let backend = MemoryBackend::new().with_calendars(vec![work], vec![home]);
+    let at = |day, hour, minute| Local.with_ymd_and_hms(2025, 3, day, hour, minute, 0).unwrap();
+    backend.add_event(
+        CalendarEvent::builder("standup", "Standup", at(10, 9, 0), at(10, 9, 30))
+            .calendar("work", "Work", ratatui::style::Color::Blue)
+            .build(),
+    );
+    backend.add_event(
+        CalendarEvent::builder("offsite", "Offsite", at(12, 0, 0), at(13, 0, 0))
+            .all_day(true)
+            .calendar("work", "Work", ratatui::style::Color::Blue)
+            .build(),
+    );
+    backend.add_reminder(
+        Reminder::builder("rent", "Pay rent")
+            .due_date(at(10, 0, 0))
+            .calendar("home", "Home", ratatui::style::Color::Green)
+            .build(),
+    );
+
+    let mut app = App::with_backend_and_config(Box::new(backend), Config::default(), ViewState::default(), History::default())
+        .unwrap();
+    app.close_health();
+    app.view_mode = view;
+    app.go_to_date(date(10));
+    app
+}
+
+fn screen(app: &App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+    terminal.draw(|frame| ui::draw(frame, app)).unwrap();
+    render(terminal.backend().buffer(), SnapshotFormat::Plain)
+}
+
+#[test]
+fn month_view_shows_the_month_and_the_selected_day() {
+    let text = screen(&app(ViewMode::Month));
+    assert!(text.contains("March 2025"), "{}", text);
+    assert!(text.contains("Standup"), "{}", text);
+    assert!(text.contains("Pay rent"), "{}", text);
+}
+
+#[test]
+fn week_view_shows_timed_and_all_day_events() {
+    let text = screen(&app(ViewMode::Week));
+    assert!(text.contains("Standup"), "{}", text);
+    assert!(text.contains("Offsite"), "{}", text);
+}
+
+#[test]
+fn day_view_shows_only_the_day() {
+    let text = screen(&app(ViewMode::Day));
+    assert!(text.contains("Standup"), "{}", text);
+    assert!(!text.contains("Offsite"), "{}", text);
+}
+
+#[test]
+fn keys_move_the_selection() {
+    let mut app = app(ViewMode::Day);
+    input::handle_key(&mut app, KeyEvent::new(KeyCode::Char('l'), KeyModifiers::NONE));
+    input::handle_key(&mut app, KeyEvent::new(KeyCode::Char('l'), KeyModifiers::NONE));
+    assert_eq!(app.selected_date, date(12));
+    let text = screen(&app);
+    assert!(text.contains("Offsite"), "{}", text);
+    assert!(!text.contains("Standup"), "{}", text);
+}
+
+#[test]
+fn z_and_a_digit_pick_a_saved_filter() {
+    let mut app = app(ViewMode::Day);
+    for (name, calendar) in [("Work", "Work"), ("Home", "Home")] {
+        let calendars = vec![calendar.to_string()];
+        app.config.filters.push(Filter { name: name.to_string(), calendars, ..Filter::default() });
+    }
+    let press = |app: &mut App, c| input::handle_key(app, KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+
+    press(&mut app, 'z');
+    press(&mut app, '2');
+    assert_eq!(app.active_filter_name(), Some("Home"));
+    assert_eq!(app.view_mode, ViewMode::Day);
+    let text = screen(&app);
+    assert!(!text.contains("Standup"), "{}", text);
+
+    press(&mut app, 'z');
+    press(&mut app, '0');
+    assert_eq!(app.active_filter_name(), None);
+    assert!(screen(&app).contains("Standup"));
+}
+
+#[test]
+fn time_left_comes_from_the_real_times_while_travelling() {
+    let mut app = app(ViewMode::Day);
+    let now = Local::now();
+    let meeting = CalendarEvent::builder("call", "Call", now - Duration::minutes(10), now + Duration::minutes(30)).build();
+    let zone = Zone::named("Pacific/Kiritimati").unwrap();
+    let mut shown = meeting.clone();
+    zone.shift_event(&mut shown);
+    app.today_events = vec![meeting];
+    app.travel = Some(zone);
+
+    assert_eq!(app.minutes_left(&shown, now), Some(30));
+}
@@ -0,0 +1,49 @@
+use calendar_tui::availability::{self, OfficeHours};
+use calendar_tui::calendar::{CalendarEvent, TimeFormat};
+use chrono::{Local, NaiveDate, NaiveTime, TimeZone, Weekday};
+
+fn meeting(id: &str, date: NaiveDate, from: u32, to: u32) -> CalendarEvent {
+    let at = |h: u32| Local.from_local_datetime(&date.and_hms_opt(h, 0, 0).unwrap()).unwrap();
+    CalendarEvent::builder(id, "Meeting", at(from), at(to)).build()
+}
+
+fn time(h: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(h, 0, 0).unwrap()
+}
+
+#[test]
+fn free_slots_skip_meetings() {
+    let date = NaiveDate::from_ymd_opt(2026, 3, 10).unwrap();
+    let events = vec![meeting("a", date, 10, 11), meeting("b", date, 10, 12), meeting("c", date, 15, 18)];
+    assert_eq!(availability::free_slots(&events, date, (9, 17)), vec![(540, 600), (720, 900)]);
+}
+
+#[test]
+fn office_hours_are_free_every_week() {
+    // Monday 2026-03-09; Tuesdays are booked 9-14, one Thursday is busy in the afternoon
+    let monday = NaiveDate::from_ymd_opt(2026, 3, 9).unwrap();
+    let mut events = Vec::new();
+    for week in 0..5 {
+        let day = |d: i64| monday + chrono::Duration::days(week * 7 + d);
+        events.push(meeting("mon", day(0), 9, 17));
+        events.push(meeting("tue", day(1), 9, 14));
+        events.push(meeting("wed", day(2), 9, 17));
+        events.push(meeting("fri", day(4), 9, 17));
+        if week == 2 {
+            events.push(meeting("thu", day(3), 12, 17));
+        }
+    }
+
+    let slots = availability::office_hours(&events, monday, 4, (9, 17), 60);
+    assert_eq!(
+        slots,
+        vec![
+            OfficeHours { weekday: Weekday::Tue, start: time(14), end: time(17) },
+            OfficeHours { weekday: Weekday::Thu, start: time(9), end: time(12) },
+        ]
+    );
+
+    TimeFormat::H12.set_current();
+    assert_eq!(availability::summary(&slots), "Office hours: Tue 2\u{2013}5pm, Thu 9am\u{2013}12pm");
+    TimeFormat::H24.set_current();
+}
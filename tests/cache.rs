@@ -0,0 +1,25 @@
+use calendar_tui::cache::MonthCache;
+
+#[test]
+fn drops_the_least_recently_used_month() {
+    let mut cache = MonthCache::new(2);
+    cache.insert(2025, 1, "january");
+    cache.insert(2025, 2, "february");
+    assert_eq!(cache.get(2025, 1), Some("january"));
+    cache.insert(2025, 3, "march");
+    assert_eq!(cache.get(2025, 2), None);
+    assert_eq!(cache.get(2025, 1), Some("january"));
+    assert_eq!(cache.get(2025, 3), Some("march"));
+}
+
+#[test]
+fn same_month_of_another_year_is_a_different_entry() {
+    let mut cache = MonthCache::default();
+    cache.insert(2025, 6, 1);
+    cache.insert(2026, 6, 2);
+    cache.insert(2025, 6, 3);
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.get(2025, 6), Some(3));
+    cache.clear();
+    assert!(cache.is_empty());
+}
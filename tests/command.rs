@@ -0,0 +1,79 @@
+use calendar_tui::app::ViewMode;
+use calendar_tui::command::{parse, parse_date, Command, History};
+use chrono::NaiveDate;
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+#[test]
+fn dates_parse_in_every_form() {
+    let today = date(2026, 3, 10);
+    assert_eq!(parse_date("today", today), Some(today));
+    assert_eq!(parse_date("tomorrow", today), Some(date(2026, 3, 11)));
+    assert_eq!(parse_date("yesterday", today), Some(date(2026, 3, 9)));
+    assert_eq!(parse_date("+3", today), Some(date(2026, 3, 13)));
+    assert_eq!(parse_date("-10", today), Some(date(2026, 2, 28)));
+    assert_eq!(parse_date("2025-01-03", today), Some(date(2025, 1, 3)));
+    assert_eq!(parse_date("12-25", today), Some(date(2026, 12, 25)));
+    assert_eq!(parse_date("soon", today), None);
+}
+
+#[test]
+fn huge_offsets_are_refused() {
+    let today = date(2026, 3, 10);
+    assert_eq!(parse_date("+99999999999", today), None);
+    assert_eq!(parse_date("-99999999999", today), None);
+    assert_eq!(parse_date("+9223372036854775807", today), None);
+    assert!(parse("goto +99999999999", today).is_err());
+}
+
+#[test]
+fn commands_parse_with_aliases() {
+    let today = date(2026, 3, 10);
+    assert_eq!(parse("q", today).unwrap(), Command::Quit);
+    assert_eq!(parse("g tomorrow", today).unwrap(), Command::Goto(date(2026, 3, 11)));
+    assert_eq!(parse("view w", today).unwrap(), Command::View(ViewMode::Week));
+    assert_eq!(parse("filter off", today).unwrap(), Command::Filter(None));
+    assert!(parse("hide", today).is_err());
+    assert!(parse("nonsense", today).is_err());
+}
+
+#[test]
+fn office_hours_weeks_are_capped() {
+    let today = date(2026, 3, 10);
+    assert_eq!(parse("oh", today).unwrap(), Command::OfficeHours(4));
+    assert_eq!(parse("officehours 52", today).unwrap(), Command::OfficeHours(52));
+    assert!(parse("officehours 0", today).is_err());
+    assert!(parse("officehours 53", today).is_err());
+    assert!(parse("officehours 4000000000", today).is_err());
+}
+
+#[test]
+fn history_steps_through_entries() {
+    let mut history = History::default();
+    history.push("goto today");
+    history.push("goto today");
+    history.push("  view week ");
+    history.push("");
+
+    assert_eq!(history.older(), Some("view week"));
+    assert_eq!(history.older(), Some("goto today"));
+    assert_eq!(history.older(), Some("goto today"));
+    assert_eq!(history.newer(), Some("view week"));
+    assert_eq!(history.newer(), None);
+    assert_eq!(history.older(), Some("view week"));
+}
+
+#[test]
+fn pushing_resets_browsing() {
+    let mut history = History::default();
+    history.push("today");
+    history.push("quit");
+    assert_eq!(history.older(), Some("quit"));
+    assert_eq!(history.older(), Some("today"));
+
+    history.push("view day");
+    assert_eq!(history.older(), Some("view day"));
+    assert_eq!(history.newer(), None);
+}
@@ -0,0 +1,36 @@
+use calendar_tui::calendar::CalendarEvent;
+use chrono::{Local, NaiveDate, NaiveTime, TimeZone};
+
+fn date(d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, 3, d).unwrap()
+}
+
+#[test]
+fn copy_keeps_times_and_details() {
+    let start = Local.with_ymd_and_hms(2026, 3, 9, 14, 30, 0).unwrap();
+    let end = Local.with_ymd_and_hms(2026, 3, 9, 15, 15, 0).unwrap();
+    let ev = CalendarEvent::builder("a", "Review", start, end)
+        .location("Room 2")
+        .notes("Bring the deck")
+        .build();
+
+    let copy = ev.copy_to(date(12));
+    assert_eq!(copy.date, date(12));
+    assert_eq!(copy.end_date, date(12));
+    assert_eq!(copy.start_time, NaiveTime::from_hms_opt(14, 30, 0).unwrap());
+    assert_eq!(copy.end_time, NaiveTime::from_hms_opt(15, 15, 0).unwrap());
+    assert_eq!(copy.calendar_id.as_deref(), Some(ev.calendar_id.as_str()));
+    assert_eq!(copy.location.as_deref(), Some("Room 2"));
+    assert_eq!(copy.notes.as_deref(), Some("Bring the deck"));
+}
+
+#[test]
+fn copy_of_multi_day_event_keeps_its_length() {
+    let start = Local.with_ymd_and_hms(2026, 3, 9, 0, 0, 0).unwrap();
+    let end = Local.with_ymd_and_hms(2026, 3, 11, 0, 0, 0).unwrap();
+    let ev = CalendarEvent::builder("trip", "Trip", start, end).all_day(true).build();
+
+    let copy = ev.copy_to(date(20));
+    assert!(copy.is_all_day);
+    assert_eq!((copy.date, copy.end_date), (date(20), date(21)));
+}
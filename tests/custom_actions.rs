@@ -0,0 +1,78 @@
+use calendar_tui::calendar::{CalendarEvent, Reminder};
+use calendar_tui::config::{Config, CustomAction};
+use chrono::{Duration, Local, TimeZone};
+
+fn var<'a>(vars: &'a [(&str, String)], name: &str) -> &'a str {
+    vars.iter().find(|(k, _)| *k == name).map(|(_, v)| v.as_str()).unwrap_or_else(|| panic!("no {}", name))
+}
+
+#[test]
+fn actions_are_read_from_config() {
+    let config: Config = toml::from_str(
+        r#"
+        [[actions]]
+        name = "zoom-notes"
+        key = "Z"
+        command = "open \"obsidian://new?name=$CAL_TITLE\""
+
+        [[actions]]
+        name = "log"
+        command = "echo $CAL_ID >> ~/cal.log"
+        "#,
+    )
+    .unwrap();
+    assert_eq!(config.actions.len(), 2);
+    assert_eq!(config.actions[0].key, Some('Z'));
+    assert_eq!(
+        config.actions[1],
+        CustomAction {
+            name: "log".to_string(),
+            command: "echo $CAL_ID >> ~/cal.log".to_string(),
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn events_expose_their_fields() {
+    let start = Local.with_ymd_and_hms(2026, 3, 10, 9, 0, 0).unwrap();
+    let event = CalendarEvent::builder("e1", "Standup", start, start + Duration::minutes(15))
+        .location("Room 4")
+        .build();
+    let vars = event.env_vars();
+    assert_eq!(var(&vars, "CAL_KIND"), "event");
+    assert_eq!(var(&vars, "CAL_TITLE"), "Standup");
+    assert_eq!(var(&vars, "CAL_START"), start.to_rfc3339());
+    assert_eq!(var(&vars, "CAL_ALL_DAY"), "0");
+    assert_eq!(var(&vars, "CAL_LOCATION"), "Room 4");
+    assert_eq!(var(&vars, "CAL_URL"), "");
+}
+
+#[test]
+fn reminders_expose_their_fields() {
+    let reminder = Reminder::builder("r1", "Pay rent").completed(true).build();
+    let vars = reminder.env_vars();
+    assert_eq!(var(&vars, "CAL_KIND"), "reminder");
+    assert_eq!(var(&vars, "CAL_DUE"), "");
+    assert_eq!(var(&vars, "CAL_COMPLETED"), "1");
+}
+
+#[test]
+fn new_event_shortcuts_are_found_by_key() {
+    let config: Config = toml::from_str(
+        r#"
+        [[new_event_shortcuts]]
+        key = "w"
+        calendar = "Work"
+        alert_minutes = 10
+
+        [[new_event_shortcuts]]
+        key = "p"
+        calendar = "Personal"
+        "#,
+    )
+    .unwrap();
+    assert_eq!(config.event_shortcut('w').map(|s| s.alert_minutes), Some(Some(10)));
+    assert_eq!(config.event_shortcut('p').map(|s| s.calendar.as_str()), Some("Personal"));
+    assert!(config.event_shortcut('x').is_none());
+}
@@ -0,0 +1,104 @@
+use std::collections::HashSet;
+
+use calendar_tui::calendar::{CalendarEvent, Reminder};
+use calendar_tui::config::Config;
+use calendar_tui::layout::{day_action_at, day_rows, DayAction, DayItems, DayRow, DaySection};
+use chrono::{DateTime, Local, TimeZone};
+
+fn event(id: &str, h1: u32, h2: u32) -> CalendarEvent {
+    let start = Local.with_ymd_and_hms(2026, 3, 9, h1, 0, 0).unwrap();
+    let end = Local.with_ymd_and_hms(2026, 3, 9, h2, 0, 0).unwrap();
+    CalendarEvent::builder(id, id, start, end).build()
+}
+
+fn rows(
+    events: &[CalendarEvent],
+    pinned: &HashSet<String>,
+    now: Option<DateTime<Local>>,
+    min_break: Option<u32>,
+) -> Vec<DayRow> {
+    day_rows(&DayItems { events, reminders: &[], pinned, now, min_break, sections: &DaySection::DEFAULT_ORDER })
+}
+
+#[test]
+fn now_marker_goes_before_the_next_event() {
+    let events = vec![event("a", 9, 10), event("b", 11, 12), event("c", 14, 15)];
+    let pinned = HashSet::new();
+    let at = |h| Some(Local.with_ymd_and_hms(2026, 3, 9, h, 30, 0).unwrap());
+
+    assert_eq!(
+        rows(&events, &pinned, at(11), None),
+        vec![DayRow::Event(0), DayRow::Event(1), DayRow::Now, DayRow::Event(2)]
+    );
+    assert_eq!(rows(&events, &pinned, at(8), None)[0], DayRow::Now);
+    assert_eq!(rows(&events, &pinned, at(20), None).last(), Some(&DayRow::Now));
+    assert!(!rows(&events, &pinned, None, None).contains(&DayRow::Now));
+    assert!(rows(&[], &pinned, at(11), None).is_empty());
+}
+
+#[test]
+fn breaks_between_timed_events() {
+    // 9-10, 10-11 back to back, then 13-15 overlapping 14-16, then 16-17
+    let events = vec![
+        event("a", 9, 10),
+        event("b", 10, 11),
+        event("c", 13, 15),
+        event("d", 14, 16),
+        event("e", 16, 17),
+    ];
+    let pinned = HashSet::new();
+    assert_eq!(
+        rows(&events, &pinned, None, Some(15)),
+        vec![
+            DayRow::Event(0),
+            DayRow::Event(1),
+            DayRow::Break(120),
+            DayRow::Event(2),
+            DayRow::Event(3),
+            DayRow::Event(4),
+        ]
+    );
+    assert!(!rows(&events, &pinned, None, Some(180)).contains(&DayRow::Break(120)));
+    assert!(!rows(&events, &pinned, None, None).contains(&DayRow::Break(120)));
+}
+
+#[test]
+fn sections_follow_the_configured_order() {
+    let mut all_day = event("holiday", 0, 0);
+    all_day.is_all_day = true;
+    let events = vec![event("standup", 9, 10), all_day, event("review", 14, 15)];
+    let reminders = vec![Reminder::builder("r", "Pay rent").build()];
+    let pinned: HashSet<String> = ["review".to_string()].into();
+    let none = DayItems { events: &events, reminders: &reminders, pinned: &pinned, now: None, min_break: None, sections: &[] };
+
+    assert_eq!(
+        day_rows(&DayItems { sections: &[DaySection::Timed, DaySection::Reminders, DaySection::Pinned], ..none }),
+        vec![
+            DayRow::Event(0),
+            DayRow::Spacer,
+            DayRow::Header(DaySection::Reminders),
+            DayRow::Reminder(0),
+            DayRow::Spacer,
+            DayRow::Header(DaySection::Pinned),
+            DayRow::Event(2),
+        ]
+    );
+    // Without the pinned section, pinned events stay with the others
+    assert_eq!(day_rows(&DayItems { sections: &[DaySection::Timed], ..none }), vec![DayRow::Event(0), DayRow::Event(2)]);
+    assert!(day_rows(&none).is_empty());
+
+    let ordered = DayItems { sections: &[DaySection::Reminders, DaySection::Timed], ..none };
+    assert_eq!(day_action_at(&ordered, 0), DayAction::None);
+    assert_eq!(day_action_at(&ordered, 1), DayAction::Reminder(0));
+    assert_eq!(day_action_at(&ordered, 3), DayAction::Event(0));
+}
+
+#[test]
+fn unknown_sections_are_skipped_with_a_warning() {
+    let config = Config::parse("day_sections = [\"timed\", \"weather\"]\nweek_lanes = true").unwrap();
+    assert_eq!(config.day_sections, vec![DaySection::Timed]);
+    assert!(config.week_lanes);
+    assert_eq!(config.warnings.len(), 1);
+
+    assert!(Config::parse("day_sections = \"timed\"").is_none());
+}
@@ -0,0 +1,49 @@
+use calendar_tui::components::error_popup::ErrorState;
+use color_eyre::eyre::{eyre, WrapErr};
+use ratatui::layout::Rect;
+
+#[test]
+fn lists_the_causes_under_the_error() {
+    let err = Err::<(), _>(eyre!("The calendar is read-only.\nAsk its owner for access."))
+        .wrap_err("Couldn't save Standup")
+        .unwrap_err();
+    let state = ErrorState::new("Creating event", &err);
+    assert_eq!(
+        state.lines,
+        vec![
+            "Couldn't save Standup",
+            "",
+            "Caused by:",
+            "  The calendar is read-only.",
+            "  Ask its owner for access.",
+        ]
+    );
+    assert!(state.text().starts_with("Couldn't save Standup\n\nCaused by:"));
+}
+
+#[test]
+fn scrolling_stays_within_the_text() {
+    let mut state = ErrorState::new("Deleting event", &eyre!("one\ntwo"));
+    state.up();
+    assert_eq!(state.scroll, 0);
+    for _ in 0..5 {
+        state.down(Rect::new(0, 0, 80, 5));
+    }
+    assert_eq!(state.scroll, 1);
+
+    // Both lines fit, so there is nothing to scroll
+    let mut state = ErrorState::new("Deleting event", &eyre!("one\ntwo"));
+    state.down(Rect::new(0, 0, 80, 24));
+    assert_eq!(state.scroll, 0);
+}
+
+#[test]
+fn scrolling_counts_wrapped_rows() {
+    // One long line wraps to 26 rows of "word" in a 30 wide popup
+    let mut state = ErrorState::new("Syncing", &eyre!("{}", ["word"; 130].join(" ")));
+    let area = Rect::new(0, 0, 30, 10);
+    for _ in 0..40 {
+        state.down(area);
+    }
+    assert_eq!(state.scroll, 26 - 6);
+}
@@ -0,0 +1,82 @@
+use std::time::{Duration, Instant};
+
+use calendar_tui::calendar::backend::month_range;
+use calendar_tui::calendar::{Access, Backend, CalendarInfo, FaultyBackend, MemoryBackend, NewEvent};
+use chrono::{NaiveDate, NaiveTime};
+
+fn backend() -> FaultyBackend<MemoryBackend> {
+    let work = CalendarInfo::builder("work", "Work").build();
+    FaultyBackend::new(MemoryBackend::new().with_calendars(vec![work], Vec::new()))
+}
+
+fn standup() -> NewEvent {
+    let date = NaiveDate::from_ymd_opt(2026, 3, 10).unwrap();
+    NewEvent {
+        title: "Standup".to_string(),
+        date,
+        start_time: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+        end_date: date,
+        end_time: NaiveTime::from_hms_opt(9, 15, 0).unwrap(),
+        is_all_day: false,
+        calendar_id: None,
+        location: None,
+        url: None,
+        notes: None,
+        alarm_minutes: None,
+        busy: false,
+    }
+}
+
+#[test]
+fn calls_wait_out_the_latency() {
+    let backend = backend();
+    backend.faults().set_latency(Duration::from_millis(30));
+    let started = Instant::now();
+    backend.calendars();
+    assert!(started.elapsed() >= Duration::from_millis(30));
+
+    let mut waited = 0;
+    assert!(backend.request_access_waiting(&mut || waited += 1).unwrap());
+    assert!(waited > 0, "a slow prompt keeps reporting progress");
+}
+
+#[test]
+fn every_nth_write_fails() {
+    let backend = backend();
+    backend.faults().fail_every(2);
+    assert!(backend.create_event(&standup()).is_ok());
+    let err = backend.create_event(&standup()).unwrap_err();
+    assert_eq!(err.to_string(), "Injected failure on write 2");
+    assert!(backend.create_event(&standup()).is_ok());
+    assert_eq!(backend.faults().writes(), 3);
+
+    // Failed writes leave the data alone
+    let (start, end) = month_range(2026, 3);
+    assert_eq!(backend.events_in_range(start, end).len(), 2);
+}
+
+#[test]
+fn revoking_access_mid_session_hides_everything_until_restored() {
+    let backend = backend();
+    backend.create_event(&standup()).unwrap();
+    let (start, end) = month_range(2026, 3);
+    let faults = backend.faults();
+
+    faults.revoke();
+    assert!(backend.take_changes());
+    assert!(!backend.take_changes());
+    assert_eq!(backend.permissions().events, Access::Denied);
+    assert!(backend.events_in_range(start, end).is_empty());
+    assert!(backend.calendars().is_empty());
+    assert!(backend.create_event(&standup()).is_err());
+
+    let query = backend.query_events(start, end);
+    assert!(query.events.is_empty());
+    assert_eq!(query.gaps, vec!["Calendar access was revoked"]);
+
+    faults.restore();
+    assert!(backend.take_changes());
+    assert!(backend.query_events(start, end).gaps.is_empty());
+    assert_eq!(backend.permissions().events, Access::Full);
+    assert_eq!(backend.events_in_range(start, end).len(), 1);
+}
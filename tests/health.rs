@@ -0,0 +1,58 @@
+use std::collections::HashSet;
+
+use calendar_tui::calendar::{Access, CalendarInfo, Permissions};
+use calendar_tui::health::{access_banner, Health, SourceCount};
+use chrono::Local;
+
+fn calendar(id: &str, source: &str) -> CalendarInfo {
+    CalendarInfo::builder(id, id).source(source).build()
+}
+
+#[test]
+fn counts_calendars_and_lists_per_source() {
+    let calendars = vec![calendar("home", "iCloud"), calendar("work", "Exchange"), calendar("gym", "iCloud")];
+    let lists = vec![calendar("todo", "iCloud")];
+    let hidden: HashSet<String> = ["gym".to_string()].into();
+    let full = Permissions { events: Access::Full, reminders: Access::Full };
+    let health = Health::check(full, &calendars, &lists, &hidden, Some(Local::now()));
+
+    assert_eq!(
+        health.sources,
+        vec![
+            SourceCount { source: "Exchange".to_string(), calendars: 1, hidden: 0, reminder_lists: 0 },
+            SourceCount { source: "iCloud".to_string(), calendars: 2, hidden: 1, reminder_lists: 1 },
+        ]
+    );
+    assert!(health.issues().is_empty());
+}
+
+#[test]
+fn gaps_in_the_loaded_events_are_reported() {
+    let full = Permissions { events: Access::Full, reminders: Access::Full };
+    let mut health = Health::check(full, &[calendar("work", "iCloud")], &[], &HashSet::new(), Some(Local::now()));
+    health.gaps = vec!["Calendar access was revoked".to_string()];
+    assert_eq!(health.issues(), vec!["Possibly incomplete: Calendar access was revoked"]);
+}
+
+#[test]
+fn partial_permissions_are_reported() {
+    let calendars = vec![calendar("work", "Exchange")];
+    let hidden: HashSet<String> = ["work".to_string()].into();
+    let partial = Permissions { events: Access::Full, reminders: Access::Denied };
+    let issues = Health::check(partial, &calendars, &[], &hidden, None).issues();
+
+    assert_eq!(issues.len(), 3);
+    assert!(issues[0].starts_with("Reminders access denied"));
+    assert!(issues[1].contains("Every Exchange calendar is hidden"));
+    assert!(issues[2].contains("not loaded"));
+}
+
+#[test]
+fn partial_access_gets_a_banner() {
+    let perms = |events, reminders| Permissions { events, reminders };
+    assert_eq!(access_banner(perms(Access::Full, Access::Full)), None);
+    assert!(access_banner(perms(Access::WriteOnly, Access::Full)).unwrap().starts_with("Calendar access is add-only"));
+    assert!(access_banner(perms(Access::Full, Access::Denied)).unwrap().starts_with("Reminders access denied"));
+    // Unreadable events get the access screen, not a banner
+    assert_eq!(access_banner(perms(Access::Denied, Access::Denied)), None);
+}
@@ -0,0 +1,232 @@
+use calendar_tui::calendar::recurrence::Recurrence;
+use calendar_tui::calendar::{ics, CalendarEvent, NewEvent, Participant, ParticipantStatus};
+use chrono::{Local, NaiveDate, NaiveTime, TimeZone, Timelike, Utc};
+
+const SAMPLE: &str = "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+X-WR-CALNAME:Team\r
+BEGIN:VEVENT\r
+UID:abc@example.com\r
+SUMMARY:Planning\\, Q3\r
+DTSTART:20260310T090000\r
+DTEND:20260310T103000\r
+LOCATION:Room 4\r
+DESCRIPTION:Agenda:\\nbudget\r
+  and hiring\r
+RRULE:FREQ=WEEKLY;BYDAY=TU\r
+ORGANIZER;CN=Ana Lima:mailto:ana@example.com\r
+ATTENDEE;CN=\"Bo, Jr\";PARTSTAT=ACCEPTED:mailto:bo@example.com\r
+ATTENDEE;PARTSTAT=DECLINED:mailto:cy@example.com\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+SUMMARY:Offsite\r
+DTSTART;VALUE=DATE:20260312\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+SUMMARY:Call\r
+DTSTART:20260311T140000Z\r
+DURATION:PT45M\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+
+#[test]
+fn reads_calendar_name_and_events() {
+    let cal = ics::parse(SAMPLE).unwrap();
+    assert_eq!(cal.name.as_deref(), Some("Team"));
+    assert_eq!(cal.events.len(), 3);
+}
+
+#[test]
+fn timed_event_fields() {
+    let ev = &ics::parse(SAMPLE).unwrap().events[0];
+    assert_eq!(ev.id, "abc@example.com");
+    assert_eq!(ev.title, "Planning, Q3");
+    assert_eq!(ev.start, Local.with_ymd_and_hms(2026, 3, 10, 9, 0, 0).unwrap());
+    assert_eq!(ev.end, Local.with_ymd_and_hms(2026, 3, 10, 10, 30, 0).unwrap());
+    assert_eq!(ev.location.as_deref(), Some("Room 4"));
+    assert_eq!(ev.notes.as_deref(), Some("Agenda:\nbudget and hiring"));
+    assert!(ev.recurrence.is_some());
+}
+
+#[test]
+fn organizer_and_attendee_replies() {
+    let ev = &ics::parse(SAMPLE).unwrap().events[0];
+    assert_eq!(ev.organizer.as_ref().map(|o| o.name.as_str()), Some("Ana Lima"));
+    let replies: Vec<(&str, ParticipantStatus)> = ev.attendees.iter().map(|a| (a.name.as_str(), a.status)).collect();
+    assert_eq!(
+        replies,
+        vec![("Bo, Jr", ParticipantStatus::Accepted), ("cy@example.com", ParticipantStatus::Declined)]
+    );
+}
+
+#[test]
+fn date_only_event_is_all_day() {
+    let ev = &ics::parse(SAMPLE).unwrap().events[1];
+    assert!(ev.is_all_day);
+    assert_eq!(ev.start.date_naive(), NaiveDate::from_ymd_opt(2026, 3, 12).unwrap());
+    assert_eq!(ev.last_day(), NaiveDate::from_ymd_opt(2026, 3, 12).unwrap());
+}
+
+#[test]
+fn utc_time_and_duration() {
+    let ev = &ics::parse(SAMPLE).unwrap().events[2];
+    let start = Utc.with_ymd_and_hms(2026, 3, 11, 14, 0, 0).unwrap().with_timezone(&Local);
+    assert_eq!(ev.start, start);
+    assert_eq!((ev.end - ev.start).num_minutes(), 45);
+    assert_eq!(ev.start.minute(), 0);
+}
+
+#[test]
+fn malformed_line_is_an_error() {
+    assert!(ics::parse("BEGIN:VCALENDAR\nnonsense\n").is_err());
+}
+
+#[test]
+fn invite_round_trips_with_attendees() {
+    let date = NaiveDate::from_ymd_opt(2026, 3, 10).unwrap();
+    let event = NewEvent {
+        title: "Design review, round 2".to_string(),
+        date,
+        start_time: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+        end_date: date,
+        end_time: NaiveTime::from_hms_opt(10, 0, 0).unwrap(),
+        is_all_day: false,
+        calendar_id: None,
+        location: None,
+        url: None,
+        notes: Some("Bring the mockups; ".repeat(8)),
+        alarm_minutes: None,
+        busy: false,
+    };
+    let attendees = vec!["ana@example.com".to_string(), "bo@example.com".to_string()];
+    let stamp = Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap();
+    let text = ics::invite(&event, "uid-1", Some("me@example.com"), &attendees, stamp);
+
+    assert!(text.contains("METHOD:REQUEST\r\n"));
+    assert!(text.contains("ORGANIZER:mailto:me@example.com\r\n"));
+    assert_eq!(text.matches("ATTENDEE;").count(), 2);
+    assert!(text.lines().all(|l| l.trim_end_matches('\r').len() <= 75));
+
+    let parsed = ics::parse(&text).unwrap();
+    let ev = &parsed.events[0];
+    assert_eq!(ev.title, "Design review, round 2");
+    assert_eq!(ev.organizer.as_ref().map(|o| o.name.as_str()), Some("me@example.com"));
+    assert_eq!(ev.attendees.len(), 2);
+    assert_eq!(ev.attendees[1].email.as_deref(), Some("bo@example.com"));
+    assert_eq!(ev.attendees[1].status, ParticipantStatus::Pending);
+    assert_eq!(ev.start.naive_local(), date.and_hms_opt(9, 0, 0).unwrap());
+    assert_eq!(ev.notes.as_deref(), event.notes.as_deref());
+}
+
+#[test]
+fn reply_carries_uid_and_new_status() {
+    let ev = &ics::parse(SAMPLE).unwrap().events[0];
+    let me = &ev.attendees[0];
+    let stamp = Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap();
+    let text = ics::reply(ev, me, ParticipantStatus::Tentative, stamp).unwrap();
+
+    assert!(text.contains("METHOD:REPLY\r\n"));
+    assert!(text.contains("UID:abc@example.com\r\n"));
+    assert!(text.contains("ORGANIZER:mailto:ana@example.com\r\n"));
+    let parsed = &ics::parse(&text).unwrap().events[0];
+    assert_eq!(parsed.attendees.len(), 1);
+    assert_eq!(parsed.attendees[0].name, "Bo, Jr");
+    assert_eq!(parsed.attendees[0].status, ParticipantStatus::Tentative);
+
+    let no_uid = &ics::parse(SAMPLE).unwrap().events[1];
+    assert!(ics::reply(no_uid, me, ParticipantStatus::Accepted, stamp).is_err());
+}
+
+fn participant(name: &str, is_me: bool) -> Participant {
+    Participant {
+        name: name.to_string(),
+        email: Some(format!("{}@example.com", name)),
+        status: ParticipantStatus::Pending,
+        is_me,
+    }
+}
+
+/// An all-day event as EventKit gives it, ending a second before midnight.
+fn eventkit_all_day() -> CalendarEvent {
+    let start = Local.with_ymd_and_hms(2026, 3, 12, 0, 0, 0).unwrap();
+    let end = Local.with_ymd_and_hms(2026, 3, 13, 23, 59, 59).unwrap();
+    CalendarEvent::builder("offsite", "Offsite", start, end).all_day(true).uid("offsite@example.com").build()
+}
+
+#[test]
+fn reply_to_an_all_day_event_ends_the_day_after() {
+    let mut ev = eventkit_all_day();
+    ev.organizer = Some(participant("ana", false));
+    let me = participant("bo", true);
+    let stamp = Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap();
+    let text = ics::reply(&ev, &me, ParticipantStatus::Accepted, stamp).unwrap();
+
+    assert!(text.contains("DTSTART;VALUE=DATE:20260312\r\n"));
+    assert!(text.contains("DTEND;VALUE=DATE:20260314\r\n"));
+    assert!(!text.contains("RECURRENCE-ID"));
+}
+
+#[test]
+fn reply_to_an_occurrence_names_it() {
+    let start = Local.with_ymd_and_hms(2026, 3, 17, 10, 0, 0).unwrap();
+    let scheduled = Local.with_ymd_and_hms(2026, 3, 17, 9, 0, 0).unwrap();
+    let ev = CalendarEvent::builder("weekly", "1:1", start, start + chrono::Duration::minutes(30))
+        .recurrence(Recurrence::parse("RRULE:FREQ=WEEKLY").unwrap())
+        .occurrence_start(scheduled)
+        .organizer(participant("ana", false))
+        .uid("weekly@example.com")
+        .build();
+    let stamp = Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap();
+    let text = ics::reply(&ev, &participant("bo", true), ParticipantStatus::Declined, stamp).unwrap();
+
+    let id = scheduled.with_timezone(&Utc).format("%Y%m%dT%H%M%SZ");
+    assert!(text.contains(&format!("RECURRENCE-ID:{}\r\n", id)), "{}", text);
+}
+
+#[test]
+fn export_round_trips_events() {
+    let events = ics::parse(SAMPLE).unwrap().events;
+    let stamp = Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap();
+    let text = ics::export(&events, stamp);
+
+    assert!(text.contains("METHOD:PUBLISH\r\n"));
+    assert!(text.contains("UID:abc@example.com\r\n"));
+    let parsed = ics::parse(&text).unwrap().events;
+    assert_eq!(parsed.len(), 3);
+    assert_eq!(parsed[0].title, "Planning, Q3");
+    assert_eq!(parsed[0].start, events[0].start);
+    assert!(parsed[1].is_all_day);
+    assert_eq!(parsed[1].start.date_naive(), events[1].start.date_naive());
+    assert_eq!(parsed[2].end, events[2].end);
+}
+
+#[test]
+fn export_writes_eventkit_all_day_events_and_occurrences() {
+    let weekly = Recurrence::parse("RRULE:FREQ=WEEKLY").unwrap();
+    let occurrence = |day| {
+        let start = Local.with_ymd_and_hms(2026, 3, day, 9, 0, 0).unwrap();
+        CalendarEvent::builder("weekly", "1:1", start, start + chrono::Duration::minutes(30))
+            .recurrence(weekly.clone())
+            .occurrence_start(start)
+            .uid("weekly@example.com")
+            .build()
+    };
+    let events = vec![eventkit_all_day(), occurrence(10), occurrence(17)];
+    let stamp = Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap();
+    let text = ics::export(&events, stamp);
+
+    assert!(text.contains("DTEND;VALUE=DATE:20260314\r\n"), "{}", text);
+    let parsed = ics::parse(&text).unwrap().events;
+    assert_eq!(parsed[0].last_day(), NaiveDate::from_ymd_opt(2026, 3, 13).unwrap());
+
+    let ids: Vec<String> = [10, 17]
+        .iter()
+        .map(|&day| {
+            let start = Local.with_ymd_and_hms(2026, 3, day, 9, 0, 0).unwrap();
+            format!("RECURRENCE-ID:{}\r\n", start.with_timezone(&Utc).format("%Y%m%dT%H%M%SZ"))
+        })
+        .collect();
+    assert!(ids.iter().all(|id| text.contains(id.as_str())), "{}", text);
+    assert_eq!(text.matches("RECURRENCE-ID").count(), 2);
+}
@@ -0,0 +1,59 @@
+use calendar_tui::calendar::CalendarEvent;
+use calendar_tui::job::{Finish, Job, Step, CREATE_BATCH, DELETE_BATCH, SCAN_DAYS};
+use chrono::{Duration, Local, TimeZone};
+
+#[test]
+fn deletion_runs_in_batches() {
+    let ids: Vec<String> = (0..DELETE_BATCH + 5).map(|i| i.to_string()).collect();
+    let mut job = Job::delete(ids);
+    let mut sizes = Vec::new();
+    while let Some(step) = job.next_step() {
+        if let Step::Delete(batch) = &step {
+            sizes.push(batch.len());
+        }
+        job.advance(&step);
+    }
+    assert_eq!(sizes, vec![DELETE_BATCH, 5]);
+    assert!(job.is_finished());
+    assert_eq!(job.progress_label(), format!("Deleting events {0}/{0}", DELETE_BATCH + 5));
+}
+
+#[test]
+fn scan_covers_the_range_once() {
+    let start = Local.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+    let end = start + Duration::days(SCAN_DAYS * 2 + 10);
+    let mut job = Job::scan("Scanning", start, end, Finish::Duplicates);
+    let mut ranges = Vec::new();
+    let (from, to) = (start + Duration::days(SCAN_DAYS - 1), start + Duration::days(SCAN_DAYS + 1));
+    let overlapping = CalendarEvent::builder("a", "Offsite", from, to).build();
+    while let Some(step) = job.next_step() {
+        if let Step::Query(from, to) = step {
+            ranges.push((from, to));
+            job.collect(vec![overlapping.clone()]);
+        }
+        job.advance(&step);
+        assert!(job.ratio() <= 1.0);
+    }
+    assert_eq!(ranges.len(), 3);
+    assert_eq!(ranges.first().unwrap().0, start);
+    assert_eq!(ranges.last().unwrap().1, end);
+    assert_eq!(job.events.len(), 1);
+    assert_eq!(job.done, job.total);
+}
+
+#[test]
+fn creation_runs_in_batches() {
+    let date = chrono::NaiveDate::from_ymd_opt(2026, 3, 10).unwrap();
+    let start = Local.with_ymd_and_hms(2026, 3, 9, 9, 0, 0).unwrap();
+    let ev = CalendarEvent::builder("a", "Lecture", start, start + Duration::hours(1)).build();
+    let mut job = Job::create(vec![ev.copy_to(date); CREATE_BATCH + 1]);
+    let mut sizes = Vec::new();
+    while let Some(step) = job.next_step() {
+        if let Step::Create(batch) = &step {
+            sizes.push(batch.len());
+        }
+        job.advance(&step);
+    }
+    assert_eq!(sizes, vec![CREATE_BATCH, 1]);
+    assert_eq!(job.finish, Finish::Created);
+}
@@ -0,0 +1,36 @@
+use std::fs;
+use std::path::PathBuf;
+
+use calendar_tui::log::rotate;
+
+fn scratch(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("calendar-tui-log-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn small_or_missing_logs_stay_put() {
+    let dir = scratch("small");
+    let log = dir.join("debug.log");
+    rotate(&log, 10, 2).unwrap();
+    fs::write(&log, "short").unwrap();
+    rotate(&log, 10, 2).unwrap();
+    assert_eq!(fs::read_to_string(&log).unwrap(), "short");
+    assert!(!dir.join("debug.log.1").exists());
+}
+
+#[test]
+fn large_logs_shift_and_the_oldest_is_dropped() {
+    let dir = scratch("large");
+    let log = dir.join("debug.log");
+    for run in ["first run", "second run", "third run"] {
+        fs::write(&log, run).unwrap();
+        rotate(&log, 4, 2).unwrap();
+    }
+    assert!(!log.exists());
+    assert_eq!(fs::read_to_string(dir.join("debug.log.1")).unwrap(), "third run");
+    assert_eq!(fs::read_to_string(dir.join("debug.log.2")).unwrap(), "second run");
+    assert!(!dir.join("debug.log.3").exists());
+}
@@ -0,0 +1,49 @@
+use calendar_tui::calendar::recurrence::Recurrence;
+use calendar_tui::calendar::{CalendarEvent, CalendarEventBuilder};
+use calendar_tui::maintenance::{duplicate_events, stale_events};
+use chrono::{Local, NaiveDate, TimeZone};
+
+fn declined(id: &str, day: u32) -> CalendarEventBuilder {
+    let start = Local.with_ymd_and_hms(2026, 3, day, 9, 0, 0).unwrap();
+    CalendarEvent::builder(id, id, start, start + chrono::Duration::hours(1)).declined(true)
+}
+
+#[test]
+fn cleanup_skips_repeating_events_and_lists_each_event_once() {
+    let today = NaiveDate::from_ymd_opt(2026, 3, 20).unwrap();
+    let weekly = Recurrence::parse("RRULE:FREQ=WEEKLY").unwrap();
+    let events = vec![
+        declined("once", 2).build(),
+        declined("once", 2).build(),
+        declined("weekly", 3).recurrence(weekly).build(),
+    ];
+    let stale: Vec<String> = stale_events(&events, today, 30).into_iter().map(|(ev, _)| ev.id).collect();
+    assert_eq!(stale, vec!["once".to_string()]);
+}
+
+#[test]
+fn huge_ages_do_not_overflow() {
+    let today = NaiveDate::from_ymd_opt(2026, 3, 20).unwrap();
+    assert_eq!(stale_events(&[declined("once", 2).build()], today, i64::MAX).len(), 1);
+}
+
+#[test]
+fn duplicates_skip_repeating_events_and_repeated_ids() {
+    let start = Local.with_ymd_and_hms(2026, 3, 2, 9, 0, 0).unwrap();
+    let event = |id: &str, calendar: &str| {
+        CalendarEvent::builder(id, "Standup", start, start + chrono::Duration::hours(1))
+            .calendar(calendar, calendar, ratatui::style::Color::Blue)
+    };
+    let weekly = Recurrence::parse("RRULE:FREQ=WEEKLY").unwrap();
+    let repeating = vec![
+        event("w1", "Work").recurrence(weekly.clone()).build(),
+        event("w2", "Home").recurrence(weekly).build(),
+    ];
+    assert!(duplicate_events(&repeating).is_empty());
+
+    let same_id = vec![event("a", "Work").build(), event("a", "Work").build(), event("b", "Home").build()];
+    let pairs = duplicate_events(&same_id);
+    assert_eq!(pairs.len(), 1);
+    assert_eq!(pairs[0].remove.0.id, "a");
+    assert_eq!(pairs[0].keep.0.id, "b");
+}
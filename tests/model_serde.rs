@@ -0,0 +1,69 @@
+use calendar_tui::calendar::{CalendarEvent, CalendarInfo, Reminder};
+use chrono::{Local, TimeZone};
+use ratatui::style::Color;
+
+fn roundtrip<T>(value: &T) -> T
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+{
+    let json = serde_json::to_string(value).expect("serialize");
+    serde_json::from_str(&json).expect("deserialize")
+}
+
+#[test]
+fn calendar_event_roundtrip() {
+    let start = Local.with_ymd_and_hms(2025, 3, 14, 9, 30, 0).unwrap();
+    let end = Local.with_ymd_and_hms(2025, 3, 14, 10, 15, 0).unwrap();
+    let event = CalendarEvent::builder("ev-1", "Standup", start, end)
+        .calendar("cal-1", "Work", Color::Rgb(255, 149, 0))
+        .location("Room 4")
+        .notes("Bring notes\nand coffee")
+        .build();
+
+    assert_eq!(roundtrip(&event), event);
+}
+
+#[test]
+fn all_day_birthday_roundtrip() {
+    let start = Local.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+    let event = CalendarEvent::builder("ev-2", "Ada's Birthday", start, start)
+        .all_day(true)
+        .origin_year(1990)
+        .build();
+
+    let back = roundtrip(&event);
+    assert_eq!(back, event);
+    assert_eq!(back.anniversary_label().as_deref(), Some("turns 35"));
+}
+
+#[test]
+fn reminder_roundtrip() {
+    let due = Local.with_ymd_and_hms(2025, 3, 20, 0, 0, 0).unwrap();
+    let reminder = Reminder::builder("rem-1", "Pay rent")
+        .due_date(due)
+        .calendar("list-1", "Home", Color::Green)
+        .priority(1)
+        .build();
+    assert_eq!(roundtrip(&reminder), reminder);
+
+    let undated = Reminder::builder("rem-2", "Someday").completed(true).build();
+    assert_eq!(roundtrip(&undated), undated);
+}
+
+#[test]
+fn calendar_info_roundtrip() {
+    let info = CalendarInfo::builder("cal-1", "Work")
+        .color(Color::Rgb(0, 122, 255))
+        .source("iCloud")
+        .build();
+    assert_eq!(roundtrip(&info), info);
+}
+
+#[test]
+fn colors_serialize_as_strings() {
+    let info = CalendarInfo::builder("cal-1", "Work")
+        .color(Color::Rgb(0, 122, 255))
+        .build();
+    let json = serde_json::to_value(&info).unwrap();
+    assert_eq!(json["color"], "#007AFF");
+}
@@ -0,0 +1,51 @@
+use calendar_tui::calendar::{CalendarEvent, Reminder};
+use calendar_tui::layout::{month_event_counts, month_reminder_colors};
+use chrono::{Local, TimeZone};
+use ratatui::style::Color;
+
+fn event(id: &str, (m1, d1): (u32, u32), (m2, d2): (u32, u32)) -> CalendarEvent {
+    let start = Local.with_ymd_and_hms(2026, m1, d1, 9, 0, 0).unwrap();
+    let end = Local.with_ymd_and_hms(2026, m2, d2, 10, 0, 0).unwrap();
+    CalendarEvent::builder(id, id, start, end).build()
+}
+
+#[test]
+fn counts_events_per_day() {
+    let events = vec![
+        event("a", (3, 9), (3, 9)),
+        event("b", (3, 9), (3, 9)),
+        event("c", (3, 10), (3, 10)),
+    ];
+    let counts = month_event_counts(&events, 2026, 3);
+    assert_eq!(counts.get(&9), Some(&2));
+    assert_eq!(counts.get(&10), Some(&1));
+    assert_eq!(counts.get(&11), None);
+}
+
+#[test]
+fn multi_day_events_count_on_each_day_of_the_month() {
+    let events = vec![event("trip", (2, 27), (3, 2))];
+    let counts = month_event_counts(&events, 2026, 3);
+    assert_eq!(counts.len(), 2);
+    assert_eq!((counts[&1], counts[&2]), (1, 1));
+}
+
+#[test]
+fn reminder_marker_takes_the_main_list_color() {
+    let due = |d: u32| Local.with_ymd_and_hms(2026, 3, d, 9, 0, 0).unwrap();
+    let reminder = |id: &str, d: u32, list: &str, color: Color| {
+        Reminder::builder(id, id).due_date(due(d)).calendar(list, list, color).build()
+    };
+    let reminders = vec![
+        reminder("a", 9, "home", Color::Green),
+        reminder("b", 9, "work", Color::Blue),
+        reminder("c", 9, "work", Color::Blue),
+        reminder("d", 10, "home", Color::Green),
+        reminder("e", 10, "work", Color::Blue),
+    ];
+    let colors = month_reminder_colors(&reminders, 2026, 3);
+    assert_eq!(colors.get(&9), Some(&Color::Blue));
+    // A tie goes to the list seen first
+    assert_eq!(colors.get(&10), Some(&Color::Green));
+    assert_eq!(colors.get(&11), None);
+}
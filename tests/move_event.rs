@@ -0,0 +1,40 @@
+use calendar_tui::calendar::{Backend, CalendarEvent, MemoryBackend};
+use chrono::{Local, TimeZone, Timelike};
+
+#[test]
+fn move_keeps_times_of_day() {
+    let start = Local.with_ymd_and_hms(2026, 3, 9, 14, 30, 0).unwrap();
+    let end = Local.with_ymd_and_hms(2026, 3, 9, 15, 15, 0).unwrap();
+    let backend = MemoryBackend::new();
+    backend.add_event(CalendarEvent::builder("a", "Review", start, end).build());
+
+    backend.move_event("a", 3).unwrap();
+    let moved = &backend.events_in_range(start, start + chrono::Duration::days(7))[0];
+    assert_eq!(moved.start.date_naive(), start.date_naive() + chrono::Duration::days(3));
+    assert_eq!((moved.start.hour(), moved.start.minute()), (14, 30));
+    assert_eq!(moved.end - moved.start, end - start);
+
+    assert!(backend.move_event("missing", 1).is_err());
+}
+
+#[test]
+fn read_only_backend_refuses_moves() {
+    let start = Local.with_ymd_and_hms(2026, 3, 9, 9, 0, 0).unwrap();
+    let backend = MemoryBackend::new().read_only();
+    backend.add_event(CalendarEvent::builder("a", "Standup", start, start).build());
+    assert!(backend.move_event("a", 1).is_err());
+}
+
+#[test]
+fn reschedule_sets_new_times() {
+    let start = Local.with_ymd_and_hms(2026, 3, 9, 9, 0, 0).unwrap();
+    let end = Local.with_ymd_and_hms(2026, 3, 9, 9, 30, 0).unwrap();
+    let backend = MemoryBackend::new();
+    backend.add_event(CalendarEvent::builder("a", "Standup", start, end).build());
+
+    let later = chrono::Duration::minutes(15);
+    backend.reschedule_event("a", start + later, end + later).unwrap();
+    let ev = &backend.events_in_range(start, start + chrono::Duration::days(1))[0];
+    assert_eq!((ev.start.hour(), ev.start.minute()), (9, 15));
+    assert_eq!((ev.end.hour(), ev.end.minute()), (9, 45));
+}
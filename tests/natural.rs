@@ -0,0 +1,109 @@
+use calendar_tui::natural::parse;
+use chrono::{Duration, NaiveDate, NaiveTime};
+
+// A Monday
+fn today() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, 3, 9).unwrap()
+}
+
+fn date(m: u32, d: u32) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(2026, m, d)
+}
+
+fn time(h: u32, m: u32) -> Option<NaiveTime> {
+    NaiveTime::from_hms_opt(h, m, 0)
+}
+
+#[test]
+fn email_style_line() {
+    let p = parse("Interview Thursday 3pm, 45 minutes, Zoom link https://zoom.us/j/123", today());
+    assert_eq!(p.title, "Interview");
+    assert_eq!(p.date, date(3, 12));
+    assert_eq!(p.start, time(15, 0));
+    assert_eq!(p.duration, Some(Duration::minutes(45)));
+    assert_eq!(p.url.as_deref(), Some("https://zoom.us/j/123"));
+    assert_eq!(p.notes, None);
+}
+
+#[test]
+fn relative_dates() {
+    assert_eq!(parse("Lunch today", today()).date, date(3, 9));
+    assert_eq!(parse("Lunch tomorrow", today()).date, date(3, 10));
+    assert_eq!(parse("Lunch monday", today()).date, date(3, 9));
+    assert_eq!(parse("Lunch next monday", today()).date, date(3, 16));
+    assert_eq!(parse("Lunch next fri", today()).date, date(3, 13));
+}
+
+#[test]
+fn absolute_dates() {
+    assert_eq!(parse("Trip 2026-05-01", today()).date, date(5, 1));
+    assert_eq!(parse("Trip Mar 14", today()).date, date(3, 14));
+    assert_eq!(parse("Trip 14th March", today()).date, date(3, 14));
+    assert_eq!(parse("Trip 4/2", today()).date, date(4, 2));
+    // Already past this year
+    assert_eq!(parse("Trip Jan 5", today()).date, NaiveDate::from_ymd_opt(2027, 1, 5));
+}
+
+#[test]
+fn times() {
+    assert_eq!(parse("Call 3 pm", today()).start, time(15, 0));
+    assert_eq!(parse("Call 9:30am", today()).start, time(9, 30));
+    assert_eq!(parse("Call 14:15", today()).start, time(14, 15));
+    assert_eq!(parse("Call at noon", today()).start, time(12, 0));
+    assert_eq!(parse("Call at 4", today()).start, time(16, 0));
+    assert_eq!(parse("Call at 9", today()).start, time(9, 0));
+}
+
+#[test]
+fn time_ranges() {
+    let p = parse("Workshop Friday 3-4:30pm", today());
+    assert_eq!(p.start, time(15, 0));
+    assert_eq!(p.duration, Some(Duration::minutes(90)));
+    assert_eq!(p.title, "Workshop");
+
+    let p = parse("Review 10:00-11:00", today());
+    assert_eq!(p.start, time(10, 0));
+    assert_eq!(p.duration, Some(Duration::hours(1)));
+}
+
+#[test]
+fn durations() {
+    assert_eq!(parse("Focus 2 hours", today()).duration, Some(Duration::hours(2)));
+    assert_eq!(parse("Focus 1.5h", today()).duration, Some(Duration::minutes(90)));
+    assert_eq!(parse("Focus for 30min", today()).duration, Some(Duration::minutes(30)));
+}
+
+#[test]
+fn huge_durations_do_not_overflow() {
+    assert_eq!(parse("Focus 9am for 99999999999999 hours", today()).duration, None);
+    let p = parse("Focus 9am for 9000000000 hours", today());
+    assert!(p.duration.is_some());
+    assert!(p.time_range(today()).is_none());
+}
+
+#[test]
+fn time_range_defaults() {
+    let p = parse("Standup 9am", today());
+    let (start, end) = p.time_range(date(3, 20).unwrap()).unwrap();
+    assert_eq!(start, date(3, 20).unwrap().and_time(time(9, 0).unwrap()));
+    assert_eq!(end - start, Duration::hours(1));
+    assert!(parse("Standup tomorrow", today()).time_range(today()).is_none());
+}
+
+#[test]
+fn multi_line_text_becomes_notes() {
+    let text = "Design sync on Wednesday at 2pm\nAgenda: roadmap, hiring\n";
+    let p = parse(text, today());
+    assert_eq!(p.title, "Design sync");
+    assert_eq!(p.date, date(3, 11));
+    assert_eq!(p.start, time(14, 0));
+    assert_eq!(p.notes.as_deref(), Some(text.trim()));
+}
+
+#[test]
+fn plain_text_is_all_title() {
+    let p = parse("Buy a present", today());
+    assert_eq!(p.title, "Buy a present");
+    assert_eq!(p.date, None);
+    assert_eq!(p.start, None);
+}
@@ -0,0 +1,46 @@
+use std::time::{Duration, Instant};
+
+use calendar_tui::calendar::backend::month_range;
+use calendar_tui::calendar::prefetch::{Fetched, Prefetcher, Request};
+use calendar_tui::calendar::{CalendarEvent, EventQuery};
+
+fn request(month: u32, generation: u64) -> Request {
+    let (start, end) = month_range(2026, month);
+    Request { year: 2026, month, start, end, generation }
+}
+
+fn wait(prefetcher: &Prefetcher) -> Fetched {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        if let Some(fetched) = prefetcher.try_recv() {
+            return fetched;
+        }
+        assert!(Instant::now() < deadline, "no answer from the worker");
+        std::thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+fn answers_requests_in_order_on_the_worker() {
+    let prefetcher = Prefetcher::spawn(|| {
+        Some(|request: &Request| EventQuery {
+            events: vec![CalendarEvent::builder(format!("m{}", request.month), "Review", request.start, request.start).build()],
+            gaps: vec![format!("Month {} was cut short", request.month)],
+        })
+    });
+    prefetcher.request(request(3, 1));
+    prefetcher.request(request(5, 2));
+    let first = wait(&prefetcher);
+    assert_eq!(first.request, request(3, 1));
+    assert_eq!(first.query.events[0].id, "m3");
+    assert_eq!(first.query.gaps, ["Month 3 was cut short"]);
+    assert_eq!(wait(&prefetcher).request.generation, 2);
+}
+
+#[test]
+fn a_worker_that_fails_to_open_never_answers() {
+    let prefetcher = Prefetcher::spawn(|| None::<fn(&Request) -> EventQuery>);
+    prefetcher.request(request(3, 1));
+    std::thread::sleep(Duration::from_millis(20));
+    assert!(prefetcher.try_recv().is_none());
+}
@@ -0,0 +1,29 @@
+use calendar_tui::calendar::backend::day_range;
+use calendar_tui::calendar::{Backend, CalendarInfo, MemoryBackend, NewEvent};
+use chrono::{NaiveDate, NaiveTime};
+
+#[test]
+fn day_queries_reach_the_last_second() {
+    let work = CalendarInfo::builder("work", "Work").build();
+    let backend = MemoryBackend::new().with_calendars(vec![work], Vec::new());
+    let date = NaiveDate::from_ymd_opt(2026, 3, 10).unwrap();
+    backend
+        .create_event(&NewEvent {
+            title: "Deploy".to_string(),
+            date,
+            start_time: NaiveTime::from_hms_milli_opt(23, 59, 59, 500).unwrap(),
+            end_date: date.succ_opt().unwrap(),
+            end_time: NaiveTime::from_hms_opt(0, 0, 0).unwrap(),
+            is_all_day: false,
+            calendar_id: None,
+            location: None,
+            url: None,
+            notes: None,
+            alarm_minutes: None,
+            busy: false,
+        })
+        .unwrap();
+    let (start, end) = day_range(date);
+    assert_eq!(backend.events_in_range(start, end).len(), 1);
+    assert_eq!(end, day_range(date.succ_opt().unwrap()).0);
+}
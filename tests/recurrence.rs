@@ -0,0 +1,130 @@
+use calendar_tui::calendar::recurrence::{DayOfWeek, Frequency, Recurrence};
+use chrono::{NaiveDate, Weekday};
+
+fn describe(rule: &str) -> String {
+    Recurrence::parse(rule).expect("parse").describe()
+}
+
+#[test]
+fn simple_frequencies() {
+    assert_eq!(describe("FREQ=DAILY"), "every day");
+    assert_eq!(describe("FREQ=WEEKLY"), "every week");
+    assert_eq!(describe("FREQ=MONTHLY"), "every month");
+    assert_eq!(describe("FREQ=YEARLY"), "every year");
+}
+
+#[test]
+fn intervals() {
+    assert_eq!(describe("FREQ=DAILY;INTERVAL=3"), "every 3 days");
+    assert_eq!(describe("FREQ=WEEKLY;INTERVAL=2"), "every other week");
+    assert_eq!(describe("FREQ=WEEKLY;INTERVAL=3"), "every 3 weeks");
+    assert_eq!(describe("FREQ=MONTHLY;INTERVAL=6"), "every 6 months");
+    assert_eq!(describe("FREQ=YEARLY;INTERVAL=1"), "every year");
+}
+
+#[test]
+fn weekly_days() {
+    assert_eq!(describe("FREQ=WEEKLY;BYDAY=TU"), "every week on Tue");
+    assert_eq!(describe("FREQ=WEEKLY;BYDAY=TU,TH"), "every week on Tue and Thu");
+    assert_eq!(describe("FREQ=WEEKLY;BYDAY=MO,WE,FR"), "every week on Mon, Wed and Fri");
+    assert_eq!(
+        describe("RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=TU,TH;COUNT=10"),
+        "every other week on Tue and Thu, 10 times"
+    );
+}
+
+#[test]
+fn weekdays_shortcut() {
+    assert_eq!(describe("FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR"), "every weekday");
+    // Only for plain weekly rules
+    assert_eq!(
+        describe("FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,TU,WE,TH,FR"),
+        "every other week on Mon, Tue, Wed, Thu and Fri"
+    );
+    assert_eq!(
+        describe("FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,SA"),
+        "every week on Mon, Tue, Wed, Thu and Sat"
+    );
+}
+
+#[test]
+fn monthly_by_position() {
+    assert_eq!(describe("FREQ=MONTHLY;BYDAY=2TU"), "every month on the 2nd Tue");
+    assert_eq!(describe("FREQ=MONTHLY;BYDAY=-1FR"), "every month on the last Fri");
+    assert_eq!(describe("FREQ=MONTHLY;BYDAY=-2MO"), "every month on the 2nd to last Mon");
+    assert_eq!(describe("FREQ=MONTHLY;BYDAY=+1SU"), "every month on the 1st Sun");
+    assert_eq!(
+        describe("FREQ=MONTHLY;BYDAY=TH;BYSETPOS=3"),
+        "every month on the 3rd Thu"
+    );
+}
+
+#[test]
+fn monthly_by_day() {
+    assert_eq!(describe("FREQ=MONTHLY;BYMONTHDAY=15"), "every month on the 15th");
+    assert_eq!(describe("FREQ=MONTHLY;BYMONTHDAY=1,15"), "every month on the 1st and 15th");
+    assert_eq!(describe("FREQ=MONTHLY;BYMONTHDAY=-1"), "every month on the last day");
+    assert_eq!(describe("FREQ=MONTHLY;BYMONTHDAY=22"), "every month on the 22nd");
+    assert_eq!(describe("FREQ=MONTHLY;BYMONTHDAY=11"), "every month on the 11th");
+}
+
+#[test]
+fn yearly_months() {
+    assert_eq!(describe("FREQ=YEARLY;BYMONTH=1,7"), "every year in Jan and Jul");
+    assert_eq!(
+        describe("FREQ=YEARLY;BYMONTH=11;BYDAY=4TH"),
+        "every year on the 4th Thu in Nov"
+    );
+}
+
+#[test]
+fn endings() {
+    assert_eq!(describe("FREQ=DAILY;COUNT=1"), "every day, once");
+    assert_eq!(describe("FREQ=DAILY;COUNT=5"), "every day, 5 times");
+    assert_eq!(describe("FREQ=WEEKLY;UNTIL=20260301"), "every week, until Mar 1, 2026");
+    assert_eq!(
+        describe("FREQ=WEEKLY;BYDAY=MO;UNTIL=20261231T235959Z"),
+        "every week on Mon, until Dec 31, 2026"
+    );
+}
+
+#[test]
+fn parse_fields() {
+    let rec = Recurrence::parse("RRULE:FREQ=MONTHLY;INTERVAL=2;BYDAY=-1FR;UNTIL=20270115;WKST=MO").unwrap();
+    assert_eq!(rec.frequency, Frequency::Monthly);
+    assert_eq!(rec.interval, 2);
+    assert_eq!(rec.by_day, vec![DayOfWeek { weekday: Weekday::Fri, nth: Some(-1) }]);
+    assert_eq!(rec.until, NaiveDate::from_ymd_opt(2027, 1, 15));
+    assert_eq!(rec.count, None);
+}
+
+#[test]
+fn parse_is_case_insensitive() {
+    assert_eq!(describe("freq=weekly;byday=mo"), "every week on Mon");
+}
+
+#[test]
+fn parse_errors() {
+    assert!(Recurrence::parse("").is_err());
+    assert!(Recurrence::parse("INTERVAL=2").is_err());
+    assert!(Recurrence::parse("FREQ=HOURLY").is_err());
+    assert!(Recurrence::parse("FREQ=DAILY;INTERVAL=0").is_err());
+    assert!(Recurrence::parse("FREQ=WEEKLY;BYDAY=XX").is_err());
+    assert!(Recurrence::parse("FREQ=WEEKLY;BYDAY=0MO").is_err());
+    assert!(Recurrence::parse("FREQ=MONTHLY;BYDAY=1\u{c9}").is_err());
+    assert!(Recurrence::parse("FREQ=WEEKLY;BYDAY=\u{c9}M").is_err());
+    assert!(Recurrence::parse("FREQ=MONTHLY;BYMONTHDAY=32").is_err());
+    assert!(Recurrence::parse("FREQ=YEARLY;BYMONTH=13").is_err());
+    assert!(Recurrence::parse("FREQ=DAILY;UNTIL=tomorrow").is_err());
+    assert!(Recurrence::parse("FREQ=DAILY;COUNT").is_err());
+}
+
+#[test]
+fn built_rule_matches_parsed() {
+    let mut rec = Recurrence::new(Frequency::Weekly);
+    rec.by_day = vec![
+        DayOfWeek { weekday: Weekday::Tue, nth: None },
+        DayOfWeek { weekday: Weekday::Thu, nth: None },
+    ];
+    assert_eq!(rec, Recurrence::parse("FREQ=WEEKLY;BYDAY=TU,TH").unwrap());
+}
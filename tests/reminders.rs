@@ -0,0 +1,46 @@
+use calendar_tui::calendar::{Backend, CalendarInfo, MemoryBackend};
+use chrono::{NaiveDate, TimeZone, Timelike};
+
+#[test]
+fn created_reminders_are_incomplete_in_the_chosen_list() {
+    let lists = vec![CalendarInfo::builder("home", "Home").build(), CalendarInfo::builder("work", "Work").build()];
+    let backend = MemoryBackend::new().with_calendars(Vec::new(), lists);
+    let due = NaiveDate::from_ymd_opt(2026, 3, 9).unwrap();
+
+    let id = backend.create_reminder("Pay rent", Some(due), None).unwrap();
+    backend.create_reminder("File report", None, Some("work")).unwrap();
+
+    let reminders = backend.fetch_incomplete_reminders();
+    assert_eq!(reminders.len(), 2);
+    assert_eq!(reminders[0].id, id);
+    assert_eq!(reminders[0].calendar_name, "Home");
+    assert_eq!(reminders[0].due_date.map(|d| d.date_naive()), Some(due));
+    assert_eq!(reminders[1].calendar_name, "Work");
+    assert_eq!(reminders[1].due_date, None);
+
+    assert!(backend.toggle_reminder(&id).unwrap());
+    assert_eq!(backend.fetch_incomplete_reminders().len(), 1);
+}
+
+#[test]
+fn creating_needs_a_list() {
+    let backend = MemoryBackend::new();
+    assert!(backend.create_reminder("Pay rent", None, None).is_err());
+    let read_only = MemoryBackend::new()
+        .with_calendars(Vec::new(), vec![CalendarInfo::builder("home", "Home").build()])
+        .read_only();
+    assert!(read_only.create_reminder("Pay rent", None, None).is_err());
+}
+
+#[test]
+fn editing_keeps_the_due_time() {
+    let backend = MemoryBackend::new();
+    let due = chrono::Local.with_ymd_and_hms(2026, 3, 9, 17, 30, 0).unwrap();
+    backend.add_reminder(calendar_tui::calendar::Reminder::builder("r", "Call bank").due_date(due).build());
+
+    let moved = NaiveDate::from_ymd_opt(2026, 3, 12).unwrap();
+    backend.update_reminder("r", "Call the bank", Some(moved), 0, None).unwrap();
+    let due = backend.fetch_incomplete_reminders()[0].due_date.unwrap();
+    assert_eq!(due.date_naive(), moved);
+    assert_eq!((due.hour(), due.minute()), (17, 30));
+}
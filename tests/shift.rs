@@ -0,0 +1,110 @@
+use calendar_tui::calendar::{Backend, CalendarEvent, MemoryBackend, Recurrence};
+use calendar_tui::shift::{delta_label, parse_delta, plan, template, MAX_TEMPLATE_PERIODS};
+use chrono::{DateTime, Local, NaiveDate, TimeZone, Timelike};
+
+fn at(day: u32, hour: u32) -> DateTime<Local> {
+    Local.with_ymd_and_hms(2026, 3, day, hour, 0, 0).unwrap()
+}
+
+#[test]
+fn deltas_parse_with_units() {
+    assert_eq!(parse_delta("+1h"), Some(60));
+    assert_eq!(parse_delta("-30m"), Some(-30));
+    assert_eq!(parse_delta("7d"), Some(7 * 24 * 60));
+    assert_eq!(parse_delta("+1w"), Some(7 * 24 * 60));
+    assert_eq!(parse_delta("+3"), None);
+    assert_eq!(parse_delta("0h"), None);
+    assert_eq!(delta_label(90), "+1h 30m");
+    assert_eq!(delta_label(-7 * 24 * 60), "-7d");
+}
+
+#[test]
+fn plan_skips_what_it_cannot_move() {
+    let timed = CalendarEvent::builder("a", "Review", at(10, 9), at(10, 10)).build();
+    let all_day = CalendarEvent::builder("b", "Offsite", at(10, 0), at(11, 0)).all_day(true).build();
+    let weekly = CalendarEvent::builder("c", "1:1", at(10, 14), at(10, 15))
+        .recurrence(Recurrence::parse("RRULE:FREQ=WEEKLY").unwrap())
+        .build();
+    let events = vec![timed, all_day, weekly];
+
+    let hourly = plan(&events, 60);
+    assert_eq!(hourly.changes.len(), 1);
+    assert_eq!(hourly.changes[0].start, at(10, 10));
+    assert_eq!(hourly.undo[0].start, at(10, 9));
+    assert_eq!(hourly.skipped, 2);
+
+    let daily = plan(&events, 24 * 60);
+    assert_eq!(daily.changes.len(), 2);
+    assert_eq!(daily.changes[1].start, at(11, 0));
+}
+
+#[test]
+fn huge_deltas_are_refused() {
+    assert_eq!(parse_delta("+9999999999999999w"), None);
+    assert_eq!(parse_delta("-9223372036854775807m"), None);
+
+    let timed = CalendarEvent::builder("a", "Review", at(10, 9), at(10, 10)).build();
+    let far = plan(&[timed], parse_delta("+99999999d").unwrap());
+    assert!(far.changes.is_empty());
+    assert_eq!(far.skipped, 1);
+}
+
+#[test]
+fn batch_applies_all_or_nothing() {
+    let backend = MemoryBackend::new();
+    backend.add_event(CalendarEvent::builder("a", "Review", at(10, 9), at(10, 10)).build());
+    backend.add_event(CalendarEvent::builder("b", "Lunch", at(10, 12), at(10, 13)).build());
+    let events = backend.events_in_range(at(10, 0), at(11, 0));
+
+    let mut broken = plan(&events, 60);
+    broken.changes[1].event_id = "missing".to_string();
+    assert!(backend.reschedule_events(&broken.changes).is_err());
+    assert_eq!(backend.events_in_range(at(10, 0), at(11, 0))[0].start.hour(), 9);
+
+    let shift = plan(&events, 60);
+    backend.reschedule_events(&shift.changes).unwrap();
+    let moved = backend.events_in_range(at(10, 0), at(11, 0));
+    assert_eq!(moved.iter().map(|e| e.start.hour()).collect::<Vec<_>>(), vec![10, 13]);
+
+    backend.reschedule_events(&shift.undo).unwrap();
+    assert_eq!(backend.events_in_range(at(10, 0), at(11, 0))[1].end, at(10, 13));
+}
+
+#[test]
+fn template_repeats_a_day_over_a_range() {
+    let lecture = CalendarEvent::builder("a", "Lecture", at(10, 9), at(10, 11)).build();
+    let declined = CalendarEvent::builder("b", "Seminar", at(10, 14), at(10, 15)).declined(true).build();
+    let other_day = CalendarEvent::builder("c", "Lab", at(11, 9), at(11, 12)).build();
+    let events = vec![lecture, declined, other_day];
+    let day = |d| at(d, 0).date_naive();
+
+    let copies = template(&events, day(10), 1, day(17), day(19)).unwrap().copies;
+    let starts: Vec<_> = copies.iter().map(|ev| ev.start).collect();
+    assert_eq!(starts, vec![at(17, 9), at(18, 9), at(19, 9)]);
+    assert_eq!(copies[0].id, "a@2026-03-17");
+    assert_eq!(copies[2].end, at(19, 11));
+
+    // A week template carries every day of the week along
+    let copies = template(&events, day(8), 7, day(15), day(15)).unwrap().copies;
+    let starts: Vec<_> = copies.iter().map(|ev| ev.start).collect();
+    assert_eq!(starts, vec![at(17, 9), at(18, 9)]);
+}
+
+#[test]
+fn template_skips_repeating_events_and_long_ranges() {
+    let lecture = CalendarEvent::builder("a", "Lecture", at(10, 9), at(10, 11)).build();
+    let weekly = CalendarEvent::builder("b", "1:1", at(10, 14), at(10, 15))
+        .recurrence(Recurrence::parse("RRULE:FREQ=WEEKLY").unwrap())
+        .build();
+    let events = vec![lecture, weekly];
+    let day = |d| at(d, 0).date_naive();
+
+    let copied = template(&events, day(10), 1, day(17), day(17)).unwrap();
+    assert_eq!(copied.copies.len(), 1);
+    assert_eq!(copied.skipped, 1);
+
+    let far = day(17) + chrono::Duration::days(MAX_TEMPLATE_PERIODS);
+    assert!(template(&events, day(10), 1, day(17), far).is_none());
+    assert!(template(&events, day(10), 1, day(17), far - chrono::Duration::days(1)).is_some());
+    assert!(template(&events, day(10), 1, day(17), NaiveDate::MAX).is_none());
+}
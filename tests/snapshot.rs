@@ -0,0 +1,24 @@
+use calendar_tui::snapshot::{render, SnapshotFormat};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+
+fn sample() -> Buffer {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 2));
+    buffer.set_string(0, 0, "Mon 📹 Sync", Style::default());
+    buffer.set_string(0, 1, "Lunch", Style::default().fg(Color::Red));
+    buffer
+}
+
+#[test]
+fn plain_text_skips_wide_character_cells() {
+    assert_eq!(render(&sample(), SnapshotFormat::Plain), "Mon 📹 Sync\nLunch\n");
+}
+
+#[test]
+fn ansi_carries_colors() {
+    let text = render(&sample(), SnapshotFormat::Ansi);
+    let lines: Vec<&str> = text.lines().collect();
+    assert!(lines[1].starts_with("\x1b[0;31mLunch\x1b[0m"));
+    assert!(lines.iter().all(|l| l.ends_with("\x1b[0m")));
+}
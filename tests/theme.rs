@@ -0,0 +1,34 @@
+use calendar_tui::theme::{self, Background, Theme, PRESETS};
+
+#[test]
+fn every_listed_preset_exists() {
+    for name in PRESETS {
+        assert_eq!(Theme::preset(name).name, *name);
+    }
+}
+
+#[test]
+fn the_active_theme_can_change_while_running() {
+    theme::set(Theme::preset("nord"));
+    let before = theme::current();
+    theme::set(Theme::preset("solarized-light"));
+    assert_eq!(before.name, "nord");
+    assert_eq!(theme::current().name, "solarized-light");
+}
+
+#[test]
+fn background_from_colorfgbg() {
+    assert_eq!(Background::from_colorfgbg("0;15"), Some(Background::Light));
+    assert_eq!(Background::from_colorfgbg("0;default;7"), Some(Background::Light));
+    assert_eq!(Background::from_colorfgbg("15;0"), Some(Background::Dark));
+    assert_eq!(Background::from_colorfgbg("default"), None);
+}
+
+#[test]
+fn background_from_osc11_reply() {
+    assert_eq!(Background::from_osc11("\x1b]11;rgb:ffff/ffff/ffff\x07\x1b[?62c"), Some(Background::Light));
+    assert_eq!(Background::from_osc11("\x1b]11;rgb:fd/f6/e3\x1b\\"), Some(Background::Light));
+    assert_eq!(Background::from_osc11("\x1b]11;rgb:1e1e/1e1e/2e2e\x07"), Some(Background::Dark));
+    // Terminals without OSC 11 answer only the device attributes request
+    assert_eq!(Background::from_osc11("\x1b[?1;2c"), None);
+}
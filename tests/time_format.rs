@@ -0,0 +1,75 @@
+use calendar_tui::calendar::TimeFormat;
+use chrono::{NaiveDate, NaiveTime};
+
+fn t(h: u32, m: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(h, m, 0).unwrap()
+}
+
+#[test]
+fn formats_both_clocks() {
+    assert_eq!(TimeFormat::H24.format(t(13, 30)), "13:30");
+    assert_eq!(TimeFormat::H24.format(t(9, 5)), "09:05");
+    assert_eq!(TimeFormat::H12.format(t(13, 30)), "1:30 PM");
+    assert_eq!(TimeFormat::H12.format(t(0, 15)), "12:15 AM");
+    assert_eq!(TimeFormat::H12.format(t(12, 0)), "12:00 PM");
+}
+
+#[test]
+fn hour_labels() {
+    assert_eq!(TimeFormat::H24.hour_label(9, false), "9:00");
+    assert_eq!(TimeFormat::H24.hour_label(17, true), "17");
+    assert_eq!(TimeFormat::H12.hour_label(0, false), "12 AM");
+    assert_eq!(TimeFormat::H12.hour_label(12, false), "12 PM");
+    assert_eq!(TimeFormat::H12.hour_label(17, true), "5p");
+}
+
+#[test]
+fn parses_either_clock() {
+    assert_eq!(TimeFormat::parse("14:30"), Some(t(14, 30)));
+    assert_eq!(TimeFormat::parse("2:30 PM"), Some(t(14, 30)));
+    assert_eq!(TimeFormat::parse("2:30pm"), Some(t(14, 30)));
+    assert_eq!(TimeFormat::parse("9am"), Some(t(9, 0)));
+    assert_eq!(TimeFormat::parse("12 AM"), Some(t(0, 0)));
+    assert_eq!(TimeFormat::parse("12:45 pm"), Some(t(12, 45)));
+    assert_eq!(TimeFormat::parse("13pm"), None);
+    assert_eq!(TimeFormat::parse("2:3pm"), None);
+    assert_eq!(TimeFormat::parse("noon"), None);
+}
+
+#[test]
+fn parses_hours() {
+    assert_eq!(TimeFormat::parse_hour("14"), Some(14));
+    assert_eq!(TimeFormat::parse_hour(" 0 "), Some(0));
+    assert_eq!(TimeFormat::parse_hour("2pm"), Some(14));
+    assert_eq!(TimeFormat::parse_hour("9:45"), Some(9));
+    assert_eq!(TimeFormat::parse_hour("24"), None);
+    assert_eq!(TimeFormat::parse_hour("soon"), None);
+}
+
+#[test]
+fn snap_up_rounds_to_the_next_step() {
+    use calendar_tui::calendar::time::snap_up;
+    let at = |h, m, s| NaiveDate::from_ymd_opt(2026, 3, 14).unwrap().and_hms_opt(h, m, s).unwrap();
+
+    assert_eq!(snap_up(at(9, 7, 0), 15), at(9, 15, 0));
+    assert_eq!(snap_up(at(9, 15, 0), 15), at(9, 15, 0));
+    assert_eq!(snap_up(at(9, 15, 1), 15), at(9, 30, 0));
+    assert_eq!(snap_up(at(9, 1, 0), 5), at(9, 5, 0));
+    assert_eq!(snap_up(at(9, 31, 0), 30), at(10, 0, 0));
+    assert_eq!(
+        snap_up(at(23, 50, 0), 30),
+        NaiveDate::from_ymd_opt(2026, 3, 15).unwrap().and_hms_opt(0, 0, 0).unwrap()
+    );
+}
+
+#[test]
+fn hour_label_styles() {
+    use calendar_tui::calendar::HourLabels;
+    assert_eq!(HourLabels::H24.label(14, false), "14:00");
+    assert_eq!(HourLabels::H12.label(14, false), "2 PM");
+    assert_eq!(HourLabels::H12.label(14, true), "2p");
+    // Compact ignores the width; Auto follows the 24-hour default here
+    assert_eq!(HourLabels::Compact.label(14, false), "14");
+    assert_eq!(HourLabels::Auto.label(14, false), "14:00");
+    assert_eq!(HourLabels::Auto.label(14, true), "14");
+}
@@ -0,0 +1,125 @@
+use calendar_tui::calendar::CalendarEvent;
+use calendar_tui::layout::{coming_from, ongoing, time_left_label, timeline_blocks};
+use chrono::{Local, NaiveDate, TimeZone};
+
+fn event(id: &str, (h1, m1): (u32, u32), (h2, m2): (u32, u32)) -> CalendarEvent {
+    let start = Local.with_ymd_and_hms(2026, 3, 9, h1, m1, 0).unwrap();
+    let end = Local.with_ymd_and_hms(2026, 3, 9, h2, m2, 0).unwrap();
+    CalendarEvent::builder(id, id, start, end).build()
+}
+
+fn date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, 3, 9).unwrap()
+}
+
+#[test]
+fn separate_events_get_full_width() {
+    let events = vec![event("a", (9, 0), (10, 0)), event("b", (10, 0), (11, 30))];
+    let blocks = timeline_blocks(&events, date());
+    assert_eq!(blocks.len(), 2);
+    assert!(blocks.iter().all(|b| b.column == 0 && b.columns == 1));
+    assert_eq!((blocks[1].start_min, blocks[1].end_min), (600, 690));
+}
+
+#[test]
+fn overlapping_events_share_columns() {
+    let events = vec![
+        event("a", (9, 0), (11, 0)),
+        event("b", (9, 30), (10, 0)),
+        event("c", (10, 0), (10, 30)),
+        event("d", (13, 0), (14, 0)),
+    ];
+    let blocks = timeline_blocks(&events, date());
+    let by_index = |i: usize| blocks.iter().find(|b| b.index == i).unwrap();
+    assert_eq!((by_index(0).column, by_index(0).columns), (0, 2));
+    assert_eq!((by_index(1).column, by_index(1).columns), (1, 2));
+    // Reuses the column freed by "b"
+    assert_eq!((by_index(2).column, by_index(2).columns), (1, 2));
+    assert_eq!((by_index(3).column, by_index(3).columns), (0, 1));
+}
+
+#[test]
+fn overnight_and_all_day_events() {
+    let start = Local.with_ymd_and_hms(2026, 3, 8, 22, 0, 0).unwrap();
+    let end = Local.with_ymd_and_hms(2026, 3, 9, 2, 0, 0).unwrap();
+    let overnight = CalendarEvent::builder("n", "Flight", start, end).build();
+    let all_day = CalendarEvent::builder(
+        "h",
+        "Holiday",
+        Local.with_ymd_and_hms(2026, 3, 9, 0, 0, 0).unwrap(),
+        Local.with_ymd_and_hms(2026, 3, 10, 0, 0, 0).unwrap(),
+    )
+    .all_day(true)
+    .build();
+    let blocks = timeline_blocks(&[overnight, all_day], date());
+    assert_eq!(blocks.len(), 1);
+    assert_eq!((blocks[0].start_min, blocks[0].end_min), (0, 120));
+}
+
+#[test]
+fn busy_minutes_count_overlaps_once() {
+    use calendar_tui::layout::{busy_label, busy_minutes};
+
+    let events = vec![
+        event("a", (9, 0), (10, 0)),
+        event("b", (9, 30), (11, 0)),
+        event("c", (13, 0), (13, 45)),
+    ];
+    assert_eq!(busy_minutes(&events, date()), 165);
+    assert_eq!(busy_label(165), "2h 45m");
+    assert_eq!(busy_label(120), "2h");
+    assert_eq!(busy_label(45), "45m");
+}
+
+#[test]
+fn declined_events_are_not_busy() {
+    use calendar_tui::layout::busy_minutes;
+
+    let start = Local.with_ymd_and_hms(2026, 3, 9, 9, 0, 0).unwrap();
+    let end = Local.with_ymd_and_hms(2026, 3, 9, 17, 0, 0).unwrap();
+    let declined = CalendarEvent::builder("d", "Offsite", start, end).declined(true).build();
+    assert_eq!(busy_minutes(&[declined], date()), 0);
+}
+
+#[test]
+fn coming_from_the_last_place() {
+    let at = |id: &str, from, to, place: &str| {
+        let base = event(id, from, to);
+        let mut builder = CalendarEvent::builder(id, id, base.start, base.end);
+        if !place.is_empty() {
+            builder = builder.location(place);
+        }
+        builder.build()
+    };
+    let events = vec![
+        at("breakfast", (8, 0), (9, 0), "Cafe"),
+        at("standup", (9, 0), (9, 30), "https://meet.google.com/abc-defg-hij"),
+        at("review", (10, 0), (11, 0), "Office"),
+        at("lunch", (11, 20), (12, 0), "Cafe"),
+        at("sync", (12, 0), (12, 30), "cafe"),
+    ];
+    let (prev, gap) = coming_from(&events, &events[3]).unwrap();
+    assert_eq!((prev.id.as_str(), gap), ("review", 20));
+    // Already there: same place as the event before, ignoring case
+    assert!(coming_from(&events, &events[4]).is_none());
+    assert!(coming_from(&events, &events[0]).is_none());
+}
+
+#[test]
+fn ongoing_picks_the_event_ending_first() {
+    let at = |h, m, s| Local.with_ymd_and_hms(2026, 3, 9, h, m, s).unwrap();
+    let declined = CalendarEvent::builder("declined", "declined", at(9, 0, 0), at(9, 20, 0)).declined(true).build();
+    let events = vec![event("workshop", (9, 0), (12, 0)), event("standup", (9, 30), (9, 45)), declined];
+
+    let (ev, left) = ongoing(&events, at(9, 10, 0)).unwrap();
+    assert_eq!((ev.id.as_str(), left), ("workshop", 170));
+    let (ev, left) = ongoing(&events, at(9, 33, 30)).unwrap();
+    assert_eq!((ev.id.as_str(), left), ("standup", 12));
+    assert!(ongoing(&events, at(12, 0, 0)).is_none());
+}
+
+#[test]
+fn time_left_reads_naturally() {
+    assert_eq!(time_left_label(12), "12 min left");
+    assert_eq!(time_left_label(90), "1h 30m left");
+}
@@ -0,0 +1,43 @@
+use std::time::{Duration, Instant};
+
+use calendar_tui::toast::{Severity, Toasts, ERROR_DURATION, MAX_TOASTS, TOAST_DURATION};
+
+fn texts(toasts: &Toasts) -> Vec<&str> {
+    toasts.iter().map(|t| t.text.as_str()).collect()
+}
+
+#[test]
+fn toasts_expire_after_their_duration() {
+    let now = Instant::now();
+    let mut toasts = Toasts::new();
+    toasts.push_at(Severity::Success, "Created: Standup", now);
+    toasts.push_at(Severity::Error, "Error: calendar is read-only", now);
+    toasts.expire(now + TOAST_DURATION - Duration::from_millis(1));
+    assert_eq!(texts(&toasts).len(), 2);
+    toasts.expire(now + TOAST_DURATION);
+    assert_eq!(texts(&toasts), vec!["Error: calendar is read-only"]);
+    toasts.expire(now + ERROR_DURATION);
+    assert!(toasts.is_empty());
+}
+
+#[test]
+fn repeated_message_restarts_instead_of_stacking() {
+    let now = Instant::now();
+    let mut toasts = Toasts::new();
+    toasts.push_at(Severity::Info, "Refreshed", now);
+    toasts.push_at(Severity::Info, "Refreshed", now + Duration::from_secs(3));
+    assert_eq!(texts(&toasts), vec!["Refreshed"]);
+    toasts.expire(now + TOAST_DURATION);
+    assert_eq!(texts(&toasts), vec!["Refreshed"]);
+}
+
+#[test]
+fn keeps_only_the_newest() {
+    let now = Instant::now();
+    let mut toasts = Toasts::new();
+    for i in 0..MAX_TOASTS + 2 {
+        toasts.push_at(Severity::Info, format!("message {}", i), now);
+    }
+    assert_eq!(toasts.iter().count(), MAX_TOASTS);
+    assert_eq!(texts(&toasts).last(), Some(&format!("message {}", MAX_TOASTS + 1).as_str()));
+}
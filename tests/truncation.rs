@@ -0,0 +1,27 @@
+use calendar_tui::text::Truncation;
+
+const TITLE: &str = "Quarterly planning review session";
+
+#[test]
+fn short_titles_are_untouched() {
+    for mode in [Truncation::End, Truncation::Middle, Truncation::Word] {
+        assert_eq!(mode.apply("Standup", 7), "Standup");
+    }
+}
+
+#[test]
+fn strategies_keep_the_width() {
+    assert_eq!(Truncation::End.apply(TITLE, 16), "Quarterly plann\u{2026}");
+    assert_eq!(Truncation::Middle.apply(TITLE, 20), "Quarterly \u{2026}w session");
+    assert_eq!(Truncation::Word.apply(TITLE, 20), "Quarterly planning\u{2026}");
+    for mode in [Truncation::End, Truncation::Middle, Truncation::Word] {
+        for width in 0..TITLE.len() {
+            assert!(mode.apply(TITLE, width).chars().count() <= width);
+        }
+    }
+}
+
+#[test]
+fn word_mode_falls_back_on_long_words() {
+    assert_eq!(Truncation::Word.apply("A supercalifragilistic day", 12), "A supercali\u{2026}");
+}
@@ -0,0 +1,36 @@
+use calendar_tui::calendar::video::find_link;
+use calendar_tui::calendar::{CalendarEvent, VideoService};
+use chrono::{Local, TimeZone};
+
+#[test]
+fn recognizes_each_service() {
+    let cases = [
+        ("Join: https://acme.zoom.us/j/123456789?pwd=abc.", VideoService::Zoom),
+        ("<https://meet.google.com/abc-defg-hij>", VideoService::Meet),
+        ("https://teams.microsoft.com/l/meetup-join/19%3ameeting", VideoService::Teams),
+        ("Webex (https://acme.webex.com/acme/j.php?MTID=m1)", VideoService::Webex),
+    ];
+    for (text, service) in cases {
+        assert_eq!(find_link(text).map(|l| l.service), Some(service), "{}", text);
+    }
+    let zoom = find_link("Join: https://acme.zoom.us/j/123456789?pwd=abc.").unwrap();
+    assert_eq!(zoom.url, "https://acme.zoom.us/j/123456789?pwd=abc");
+}
+
+#[test]
+fn ignores_other_links() {
+    assert_eq!(find_link("Agenda at https://zoom.us/pricing"), None);
+    assert_eq!(find_link("https://notzoom.us/j/1 and https://example.com"), None);
+    assert_eq!(find_link("meet.google.com/abc-defg-hij without a scheme"), None);
+}
+
+#[test]
+fn event_link_comes_from_url_location_or_notes() {
+    let start = Local.with_ymd_and_hms(2026, 3, 10, 9, 0, 0).unwrap();
+    let ev = CalendarEvent::builder("1", "Sync", start, start)
+        .location("Room 4")
+        .notes("Dial in: https://meet.google.com/abc-defg-hij")
+        .build();
+    assert_eq!(ev.video_link().map(|l| l.service), Some(VideoService::Meet));
+    assert_eq!(CalendarEvent::builder("2", "Lunch", start, start).build().video_link(), None);
+}
@@ -0,0 +1,42 @@
+use calendar_tui::app::ViewMode;
+use calendar_tui::state::ViewState;
+use chrono::NaiveDate;
+
+#[test]
+fn keeps_changes_made_by_another_instance() {
+    let base = ViewState::default();
+    let ours = ViewState { week_hour_offset: 2, ..base.clone() };
+    let on_disk = ViewState { month_width: Some(40), ..base.clone() };
+
+    let merged = ours.merged_onto(&base, on_disk);
+    assert_eq!(merged.week_hour_offset, 2);
+    assert_eq!(merged.month_width, Some(40));
+}
+
+#[test]
+fn our_change_wins_for_the_same_field() {
+    let base = ViewState { day_hour_offset: 1, ..ViewState::default() };
+    let ours = ViewState { day_hour_offset: -3, ..base.clone() };
+    let on_disk = ViewState { day_hour_offset: 5, ..base.clone() };
+
+    assert_eq!(ours.merged_onto(&base, on_disk).day_hour_offset, -3);
+}
+
+#[test]
+fn unchanged_fields_follow_the_file() {
+    let base = ViewState::default();
+    let on_disk = ViewState { week_hour_offset: 4, ..base.clone() };
+    assert_eq!(base.merged_onto(&base, on_disk.clone()), on_disk);
+}
+
+#[test]
+fn remembers_the_view_and_date() {
+    let state = ViewState {
+        view: Some(ViewMode::Week),
+        selected_date: NaiveDate::from_ymd_opt(2026, 3, 10),
+        ..ViewState::default()
+    };
+    let text = toml::to_string_pretty(&state).unwrap();
+    assert!(text.contains("view = \"week\""));
+    assert_eq!(toml::from_str::<ViewState>(&text).unwrap(), state);
+}